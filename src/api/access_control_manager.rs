@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::model::Flow;
+use crate::model::PropertyInstanceGetter;
+use crate::model::ReactiveEntityInstance;
+use crate::model::ReactiveFlow;
+
+/// The permissions which can be granted to a role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Permission {
+    /// Grants all permissions.
+    #[serde(rename = "*")]
+    All,
+
+    /// Query components, entity types and relation types.
+    #[serde(rename = "types:read")]
+    TypesRead,
+
+    /// Create and delete components, entity types and relation types.
+    #[serde(rename = "types:write")]
+    TypesWrite,
+
+    /// Query and subscribe entity instances and relation instances.
+    #[serde(rename = "instances:read")]
+    InstancesRead,
+
+    /// Create, update, tick and delete entity instances and relation instances.
+    #[serde(rename = "instances:write")]
+    InstancesWrite,
+
+    /// Query flows.
+    #[serde(rename = "flows:read")]
+    FlowsRead,
+
+    /// Create, modify and import flows.
+    #[serde(rename = "flows:write")]
+    FlowsWrite,
+
+    /// Query plugins.
+    #[serde(rename = "plugins:read")]
+    PluginsRead,
+
+    /// Activate, deactivate and reload plugins.
+    #[serde(rename = "plugins:write")]
+    PluginsWrite,
+
+    /// Create and restore snapshots.
+    #[serde(rename = "snapshots:write")]
+    SnapshotsWrite,
+}
+
+impl Permission {
+    /// Returns true, if the permission grants read access only.
+    pub fn is_read(&self) -> bool {
+        matches!(self, Permission::TypesRead | Permission::InstancesRead | Permission::FlowsRead | Permission::PluginsRead)
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(permission)) => write!(f, "{}", permission),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessDenied {
+    pub permission: Permission,
+    pub resource: Option<String>,
+}
+
+impl fmt::Display for AccessDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.resource {
+            Some(resource) => write!(f, "Access denied: Missing permission {} on {}", self.permission, resource),
+            None => write!(f, "Access denied: Missing permission {}", self.permission),
+        }
+    }
+}
+
+/// Returns the resources of an instance or a flow: the type name and the label (if any).
+pub fn get_resources(type_name: &str, label: Option<&Value>) -> Vec<String> {
+    let mut resources = vec![type_name.to_string()];
+    if let Some(label) = label.and_then(|label| label.as_str()) {
+        resources.push(label.to_string());
+    }
+    resources
+}
+
+/// Returns the type name and the label (if any) of the given entity instance.
+pub fn entity_instance_resources(entity_instance: &ReactiveEntityInstance) -> Vec<String> {
+    get_resources(&entity_instance.type_name, entity_instance.get("label").as_ref())
+}
+
+/// Returns the type name and the label (if any) of the given flow.
+pub fn flow_resources(flow: &ReactiveFlow) -> Vec<String> {
+    get_resources(&flow.type_name, flow.get("label").as_ref())
+}
+
+/// Returns the type name, the current label and the new label (if any) of the given entity
+/// instance which is about to be updated with the given properties.
+pub fn updated_entity_instance_resources(entity_instance: &ReactiveEntityInstance, properties: &HashMap<String, Value>) -> Vec<String> {
+    let mut resources = entity_instance_resources(entity_instance);
+    if let Some(label) = properties.get("label").and_then(|label| label.as_str()) {
+        if !resources.iter().any(|resource| resource == label) {
+            resources.push(label.to_string());
+        }
+    }
+    resources
+}
+
+/// Returns the type names and the labels (if any) of the entity instances and the type names of
+/// the relation instances which are contained in the given flow description.
+pub fn flow_instance_resources(flow: &Flow) -> Vec<String> {
+    let mut resources: Vec<String> = Vec::new();
+    let entity_instance_resources = flow
+        .entity_instances
+        .iter()
+        .flat_map(|entity_instance| get_resources(&entity_instance.type_name, entity_instance.properties.get("label")));
+    let relation_instance_resources = flow.relation_instances.iter().map(|relation_instance| relation_instance.type_name.clone());
+    for resource in entity_instance_resources.chain(relation_instance_resources) {
+        if !resources.contains(&resource) {
+            resources.push(resource);
+        }
+    }
+    resources
+}
+
+#[async_trait]
+pub trait AccessControlManager: Send + Sync + Lifecycle {
+    /// Returns true, if the access control is enabled. Otherwise everything is permitted.
+    fn is_enabled(&self) -> bool;
+
+    /// Returns true, if one of the roles of the given identity grants the given permission on
+    /// all of the given resources. The system identity is permitted to do anything.
+    ///
+    /// Resources are the names of types and the labels of instances and flows. If no resources
+    /// are given, only the permission itself is checked.
+    fn is_permitted(&self, identity: &Identity, permission: Permission, resources: &[String]) -> bool;
+
+    /// Returns an error if the given identity is not permitted to access the given resources.
+    fn check(&self, identity: &Identity, permission: Permission, resources: &[String]) -> Result<(), AccessDenied> {
+        if self.is_permitted(identity, permission, resources) {
+            return Ok(());
+        }
+        Err(AccessDenied {
+            permission,
+            resource: if resources.is_empty() { None } else { Some(resources.join(", ")) },
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::api::Lifecycle;
+
+pub const ANONYMOUS: &str = "anonymous";
+
+pub const SYSTEM: &str = "system";
+
+/// The authenticated caller of the GraphQL API or the REST API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    /// The name of the caller.
+    pub name: String,
+
+    /// The roles of the caller.
+    pub roles: Vec<String>,
+
+    /// True for the identity of internal callers. Cannot be obtained by authentication.
+    system: bool,
+}
+
+impl Identity {
+    pub fn new(name: String, roles: Vec<String>) -> Self {
+        Identity { name, roles, system: false }
+    }
+
+    /// The identity of internal callers, for example the GraphQL queries of plugins. The system
+    /// identity is permitted to do anything.
+    pub fn system() -> Self {
+        Identity {
+            name: String::from(SYSTEM),
+            roles: Vec::new(),
+            system: true,
+        }
+    }
+
+    pub fn is_system(&self) -> bool {
+        self.system
+    }
+
+    /// The identity of unauthenticated callers if the authentication is disabled.
+    pub fn anonymous() -> Self {
+        Identity::new(String::from(ANONYMOUS), Vec::new())
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.name == ANONYMOUS
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    /// No credentials have been sent.
+    MissingCredentials,
+
+    /// The credentials are neither a known API token nor a valid JSON web token.
+    InvalidCredentials(String),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            AuthError::MissingCredentials => write!(f, "Missing credentials"),
+            AuthError::InvalidCredentials(message) => write!(f, "Invalid credentials: {}", message),
+        }
+    }
+}
+
+/// Returns the token of the given value of an `Authorization` header.
+pub fn get_bearer_token(authorization: &str) -> String {
+    let authorization = authorization.trim();
+    match authorization.strip_prefix("Bearer ") {
+        Some(token) => token.trim().to_string(),
+        None => authorization.to_string(),
+    }
+}
+
+#[async_trait]
+pub trait AuthManager: Send + Sync + Lifecycle {
+    /// Returns true, if the callers have to authenticate.
+    fn is_enabled(&self) -> bool;
+
+    /// Authenticates the given credentials, which are either a static API token or a JSON web
+    /// token. Returns the anonymous identity if the authentication is disabled.
+    fn authenticate(&self, credentials: Option<String>) -> Result<Identity, AuthError>;
+}
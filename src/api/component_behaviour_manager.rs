@@ -43,4 +43,7 @@ pub trait ComponentBehaviourManager: Send + Sync {
 
     /// Registers a component behaviour provider.
     fn add_provider(&self, behaviour_provider: Arc<dyn ComponentBehaviourProvider>);
+
+    /// Unregisters a component behaviour provider.
+    fn remove_provider(&self, behaviour_provider: Arc<dyn ComponentBehaviourProvider>);
 }
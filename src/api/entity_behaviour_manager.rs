@@ -21,4 +21,7 @@ pub trait EntityBehaviourManager: Send + Sync {
 
     /// Registers a entity behaviour provider.
     fn add_provider(&self, behaviour_provider: Arc<dyn EntityBehaviourProvider>);
+
+    /// Unregisters a entity behaviour provider.
+    fn remove_provider(&self, behaviour_provider: Arc<dyn EntityBehaviourProvider>);
 }
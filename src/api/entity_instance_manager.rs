@@ -57,6 +57,9 @@ pub trait EntityInstanceManager: Send + Sync {
     /// Returns the entity instance with the given UUID or None.
     fn get(&self, id: Uuid) -> Option<EntityInstance>;
 
+    /// Returns all entity instances stored in the graph database.
+    fn get_all(&self) -> Vec<EntityInstance>;
+
     /// Creates an entity instance of the given type and initialize the properties with the given
     /// values.
     fn create(&self, type_name: String, properties: HashMap<String, Value>) -> Result<Uuid, EntityInstanceCreationError>;
@@ -28,6 +28,10 @@ impl From<serde_json::Error> for EntityTypeImportError {
 pub trait EntityTypeManager: Send + Sync + Lifecycle {
     fn register(&self, entity_type: EntityType) -> EntityType;
 
+    /// Replaces the entity type with the same name. Registers the entity type, if no entity type
+    /// with the same name exists.
+    fn replace(&self, entity_type: EntityType) -> EntityType;
+
     /// Returns all entity types.
     fn get_entity_types(&self) -> Vec<EntityType>;
 
@@ -6,10 +6,14 @@ use indradb::{Vertex, VertexProperties};
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::api::PropertyValidationError;
+
 #[derive(Debug)]
 pub enum EntityVertexCreationError {
     UuidTaken(Uuid),
     EntityTypeMissing(String),
+    MissingRequiredProperty(String),
+    InvalidProperties(PropertyValidationError),
     GraphDatabaseError(indradb::Error),
 }
 
@@ -22,6 +26,8 @@ impl fmt::Display for EntityVertexCreationError {
             EntityVertexCreationError::EntityTypeMissing(entity_type) => {
                 write!(f, "Entity type {} does not exist!", entity_type.clone())
             }
+            EntityVertexCreationError::MissingRequiredProperty(property_name) => write!(f, "Missing required property {}!", property_name),
+            EntityVertexCreationError::InvalidProperties(error) => write!(f, "{}", error),
             EntityVertexCreationError::GraphDatabaseError(error) => write!(f, "Failed to create graph database vertex: {}", error),
         }
     }
@@ -39,6 +45,9 @@ pub trait EntityVertexManager: Send + Sync {
     /// the vertex and the type.
     fn get_properties(&self, id: Uuid) -> Option<VertexProperties>;
 
+    /// Returns the vertex properties of all vertices stored in the graph database.
+    fn get_all_properties(&self) -> Vec<VertexProperties>;
+
     /// Creates a new vertex with the given type and the given properties.
     fn create(&self, type_name: String, properties: HashMap<String, Value>) -> Result<Uuid, EntityVertexCreationError>;
 
@@ -1,13 +1,27 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
 
 use async_trait::async_trait;
 use indradb::EdgeKey;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
 use uuid::Uuid;
 
 use crate::api::Lifecycle;
 use crate::model::ReactiveEntityInstance;
 
-#[derive(Eq, Hash, PartialEq, Clone, Debug)]
+// Namespace for stable UUIDs of the system event entities which are derived from their labels
+pub static UUID_SYSTEM_EVENT: Uuid = Uuid::from_u128(0x6ba7b8109e1511d150b400c04fd630c7);
+
+/// The number of system events which are kept in the history.
+pub const SYSTEM_EVENT_HISTORY_CAPACITY: usize = 1000;
+
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, Serialize)]
 pub enum SystemEventTypes {
     ComponentCreated,
     ComponentDeleted,
@@ -21,6 +35,16 @@ pub enum SystemEventTypes {
     RelationInstanceDeleted,
     FlowCreated,
     FlowDeleted,
+    EntityComponentAdded,
+    EntityComponentRemoved,
+    RelationComponentAdded,
+    RelationComponentRemoved,
+    EntityTypeUpdated,
+    FlowCommitted,
+    PluginLoaded,
+    PluginUnloaded,
+    PluginFailed,
+    ShutdownRequested,
 }
 
 pub enum SystemEvent {
@@ -36,11 +60,182 @@ pub enum SystemEvent {
     RelationInstanceDeleted(EdgeKey),
     FlowCreated(Uuid),
     FlowDeleted(Uuid),
+    /// A component has been added to the entity instance with the given id.
+    EntityComponentAdded(Uuid, String),
+    /// A component has been removed from the entity instance with the given id.
+    EntityComponentRemoved(Uuid, String),
+    /// A component has been added to the relation instance with the given edge key.
+    RelationComponentAdded(EdgeKey, String),
+    /// A component has been removed from the relation instance with the given edge key.
+    RelationComponentRemoved(EdgeKey, String),
+    EntityTypeUpdated(String),
+    FlowCommitted(Uuid),
+    PluginLoaded(String),
+    PluginUnloaded(String),
+    PluginFailed(String),
+    ShutdownRequested,
+}
+
+impl SystemEvent {
+    pub fn event_type(&self) -> SystemEventTypes {
+        match self {
+            SystemEvent::ComponentCreated(_) => SystemEventTypes::ComponentCreated,
+            SystemEvent::ComponentDeleted(_) => SystemEventTypes::ComponentDeleted,
+            SystemEvent::EntityTypeCreated(_) => SystemEventTypes::EntityTypeCreated,
+            SystemEvent::EntityTypeDeleted(_) => SystemEventTypes::EntityTypeDeleted,
+            SystemEvent::RelationTypeCreated(_) => SystemEventTypes::RelationTypeCreated,
+            SystemEvent::RelationTypeDeleted(_) => SystemEventTypes::RelationTypeDeleted,
+            SystemEvent::EntityInstanceCreated(_) => SystemEventTypes::EntityInstanceCreated,
+            SystemEvent::EntityInstanceDeleted(_) => SystemEventTypes::EntityInstanceDeleted,
+            SystemEvent::RelationInstanceCreated(_) => SystemEventTypes::RelationInstanceCreated,
+            SystemEvent::RelationInstanceDeleted(_) => SystemEventTypes::RelationInstanceDeleted,
+            SystemEvent::FlowCreated(_) => SystemEventTypes::FlowCreated,
+            SystemEvent::FlowDeleted(_) => SystemEventTypes::FlowDeleted,
+            SystemEvent::EntityComponentAdded(_, _) => SystemEventTypes::EntityComponentAdded,
+            SystemEvent::EntityComponentRemoved(_, _) => SystemEventTypes::EntityComponentRemoved,
+            SystemEvent::RelationComponentAdded(_, _) => SystemEventTypes::RelationComponentAdded,
+            SystemEvent::RelationComponentRemoved(_, _) => SystemEventTypes::RelationComponentRemoved,
+            SystemEvent::EntityTypeUpdated(_) => SystemEventTypes::EntityTypeUpdated,
+            SystemEvent::FlowCommitted(_) => SystemEventTypes::FlowCommitted,
+            SystemEvent::PluginLoaded(_) => SystemEventTypes::PluginLoaded,
+            SystemEvent::PluginUnloaded(_) => SystemEventTypes::PluginUnloaded,
+            SystemEvent::PluginFailed(_) => SystemEventTypes::PluginFailed,
+            SystemEvent::ShutdownRequested => SystemEventTypes::ShutdownRequested,
+        }
+    }
+
+    /// Returns the name of the type or plugin, the id of the instance or the edge key of the
+    /// relation instance as JSON representation.
+    ///
+    /// The events about added or removed components return an object containing the id or
+    /// the edge key and the name of the component.
+    pub fn subject(&self) -> Value {
+        match self {
+            SystemEvent::ComponentCreated(name)
+            | SystemEvent::ComponentDeleted(name)
+            | SystemEvent::EntityTypeCreated(name)
+            | SystemEvent::EntityTypeDeleted(name)
+            | SystemEvent::RelationTypeCreated(name)
+            | SystemEvent::RelationTypeDeleted(name)
+            | SystemEvent::EntityTypeUpdated(name)
+            | SystemEvent::PluginLoaded(name)
+            | SystemEvent::PluginUnloaded(name)
+            | SystemEvent::PluginFailed(name) => json!(name),
+            SystemEvent::EntityInstanceCreated(id)
+            | SystemEvent::EntityInstanceDeleted(id)
+            | SystemEvent::FlowCreated(id)
+            | SystemEvent::FlowDeleted(id)
+            | SystemEvent::FlowCommitted(id) => json!(id),
+            SystemEvent::RelationInstanceCreated(edge_key) | SystemEvent::RelationInstanceDeleted(edge_key) => json!(edge_key),
+            SystemEvent::EntityComponentAdded(id, component) | SystemEvent::EntityComponentRemoved(id, component) => {
+                json!({ "id": id, "component": component })
+            }
+            SystemEvent::RelationComponentAdded(edge_key, component) | SystemEvent::RelationComponentRemoved(edge_key, component) => {
+                json!({ "edge_key": edge_key, "component": component })
+            }
+            SystemEvent::ShutdownRequested => json!(true),
+        }
+    }
+}
+
+/// The origin of a system event.
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, Serialize)]
+pub enum SystemEventSource {
+    /// The event was caused by the application itself (for example at startup).
+    Core,
+
+    /// The event was caused by a GraphQL query.
+    GraphQL,
+
+    /// The event was caused by a request of the REST API.
+    Rest,
+
+    /// The event was caused by a plugin.
+    Plugin,
+
+    /// The event was caused by a component, entity type, relation type or flow provider.
+    Provider,
+}
+
+thread_local! {
+    static EVENT_SOURCE: Cell<SystemEventSource> = Cell::new(SystemEventSource::Core);
+}
+
+/// Returns the source of the system events which are emitted by the current thread.
+pub fn get_event_source() -> SystemEventSource {
+    EVENT_SOURCE.with(|source| source.get())
+}
+
+/// Executes the given function. System events which are emitted meanwhile are attributed to
+/// the given source.
+pub fn with_event_source<T, F: FnOnce() -> T>(source: SystemEventSource, f: F) -> T {
+    let previous = EVENT_SOURCE.with(|current| current.replace(source));
+    let result = f();
+    EVENT_SOURCE.with(|current| current.set(previous));
+    result
+}
+
+/// Attributes the system events which are emitted while polling the inner future to the
+/// given source. Other futures which are polled by the same thread are not affected.
+pub struct EventSourceFuture<F: Future> {
+    source: SystemEventSource,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> EventSourceFuture<F> {
+    pub fn new(source: SystemEventSource, future: F) -> Self {
+        EventSourceFuture {
+            source,
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for EventSourceFuture<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let source = self.source;
+        with_event_source(source, || self.future.as_mut().poll(context))
+    }
+}
+
+/// A system event in the history of system events.
+#[derive(Clone, Debug, Serialize)]
+pub struct SystemEventRecord {
+    /// The sequence number of the event.
+    pub sequence: u64,
+
+    /// Milliseconds since the unix epoch.
+    pub timestamp: u64,
+
+    pub event_type: SystemEventTypes,
+
+    /// The name of the type, the id of the instance or the edge key of the relation instance.
+    pub subject: Value,
+
+    pub source: SystemEventSource,
+
+    /// The deleted type or instance.
+    pub before: Option<Value>,
+
+    /// The created type or instance.
+    pub after: Option<Value>,
 }
 
 #[async_trait]
 pub trait SystemEventManager: Send + Sync + Lifecycle {
     fn emit_event(&self, event: SystemEvent);
 
+    /// Emits the event with the state of the type or instance before and after the event.
+    fn emit_event_with_data(&self, event: SystemEvent, before: Option<Value>, after: Option<Value>);
+
     fn get_system_event_instances(&self) -> Vec<Arc<ReactiveEntityInstance>>;
+
+    /// Returns the system event instance of the given type.
+    fn get_system_event_instance(&self, event_type: SystemEventTypes) -> Option<Arc<ReactiveEntityInstance>>;
+
+    /// Returns the recorded system events which happened at or after the given timestamp
+    /// (milliseconds since the unix epoch), optionally filtered by the event types.
+    fn get_system_events(&self, since: Option<u64>, event_types: &[SystemEventTypes]) -> Vec<SystemEventRecord>;
 }
@@ -1,15 +1,31 @@
+use std::fmt;
+
 use crate::api::entity_instance_manager::EntityInstanceCreationError;
 use crate::api::RelationInstanceCreationError;
 use async_trait::async_trait;
+use uuid::Uuid;
 
 use crate::model::Flow;
 
+/// The name of the vertex property of the wrapper entity instance which stores the members of
+/// the flow. The property is internal and not part of the wrapper entity instance.
+pub const FLOW_MEMBERS: &str = "flow_members";
+
 #[derive(Debug)]
 pub enum FlowCreationError {
     EntityInstanceCreationError(EntityInstanceCreationError),
     RelationInstanceCreationError(RelationInstanceCreationError),
 }
 
+impl fmt::Display for FlowCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            FlowCreationError::EntityInstanceCreationError(error) => write!(f, "Failed to create entity instance: {}", error),
+            FlowCreationError::RelationInstanceCreationError(error) => write!(f, "Failed to create relation instance: {}", error),
+        }
+    }
+}
+
 impl From<EntityInstanceCreationError> for FlowCreationError {
     fn from(e: EntityInstanceCreationError) -> Self {
         FlowCreationError::EntityInstanceCreationError(e)
@@ -49,6 +65,13 @@ impl From<FlowCreationError> for FlowImportError {
 
 #[async_trait]
 pub trait FlowManager: Send + Sync {
+    /// Returns the flow with the given id (which is the id of the wrapper entity instance)
+    /// from the graph database or None.
+    fn get(&self, id: Uuid) -> Option<Flow>;
+
+    /// Returns all flows stored in the graph database.
+    fn get_all(&self) -> Vec<Flow>;
+
     fn create(&self, flow: Flow) -> Result<Flow, FlowCreationError>;
 
     fn commit(&self, flow: Flow);
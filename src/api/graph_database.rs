@@ -1,8 +1,31 @@
-use async_trait::async_trait;
-use indradb::MemoryDatastore;
+use std::fmt;
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use indradb::Datastore;
+
+use crate::api::Lifecycle;
+
+#[derive(Debug)]
+pub enum GraphDatabaseError {
+    MissingPath,
+    OpenError(String, String),
+}
+
+impl fmt::Display for GraphDatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            GraphDatabaseError::MissingPath => write!(f, "The RocksDB graph database requires a path"),
+            GraphDatabaseError::OpenError(path, e) => write!(f, "Failed to open graph database {}: {}", path, e),
+        }
+    }
+}
+
 #[async_trait]
-pub trait GraphDatabase: Send + Sync {
-    fn get_datastore(&self) -> Arc<MemoryDatastore>;
+pub trait GraphDatabase: Send + Sync + Lifecycle {
+    /// Returns the datastore. The storage backend is configured in `config/graph_database.toml`.
+    fn get_datastore(&self) -> Arc<dyn Datastore + Send + Sync>;
+
+    /// Flushes pending changes of the datastore to disk.
+    fn sync(&self);
 }
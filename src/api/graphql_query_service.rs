@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use serde_json::Error;
+
+use crate::graphql::InexorSchema;
+use crate::graphql::InexorSchemaBuilder;
+
+#[async_trait]
+pub trait GraphQLQueryService: Send + Sync {
+    /// Returns a builder for the schema which contains the data of the type system, the
+    /// instances, the flows and the snapshots.
+    fn get_schema_builder(&self) -> InexorSchemaBuilder;
+
+    /// Returns the schema without the plugin management.
+    fn get_schema(&self) -> InexorSchema;
+
+    /// Executes the given GraphQL request and returns the response as JSON string.
+    async fn query(&self, request: String) -> Result<String, Error>;
+}
@@ -1,3 +1,5 @@
+pub use access_control_manager::*;
+pub use auth_manager::*;
 pub use component_behaviour_manager::*;
 pub use component_manager::*;
 pub use entity_behaviour_manager::*;
@@ -7,9 +9,12 @@ pub use entity_vertex_manager::*;
 pub use event_manager::*;
 pub use flow_manager::*;
 pub use graph_database::*;
+pub use graphql_query_service::*;
 pub use graphql_server::*;
 pub use lifecycle::*;
 pub use plugin_registry::*;
+pub use property_journal::*;
+pub use property_validator::*;
 pub use reactive_entity_instance_manager::*;
 pub use reactive_flow_manager::*;
 pub use reactive_property_instance_manager::*;
@@ -19,8 +24,12 @@ pub use relation_edge_manager::*;
 pub use relation_instance_manager::*;
 pub use relation_type_manager::*;
 pub use shutdown_manager::*;
+pub use snapshot_manager::*;
+pub use transaction_manager::*;
 pub use web_resource_manager::*;
 
+pub mod access_control_manager;
+pub mod auth_manager;
 pub mod component_behaviour_manager;
 pub mod component_manager;
 pub mod entity_behaviour_manager;
@@ -30,9 +39,12 @@ pub mod entity_vertex_manager;
 pub mod event_manager;
 pub mod flow_manager;
 pub mod graph_database;
+pub mod graphql_query_service;
 pub mod graphql_server;
 pub mod lifecycle;
 pub mod plugin_registry;
+pub mod property_journal;
+pub mod property_validator;
 pub mod reactive_entity_instance_manager;
 pub mod reactive_flow_manager;
 pub mod reactive_property_instance_manager;
@@ -42,4 +54,6 @@ pub mod relation_edge_manager;
 pub mod relation_instance_manager;
 pub mod relation_type_manager;
 pub mod shutdown_manager;
+pub mod snapshot_manager;
+pub mod transaction_manager;
 pub mod web_resource_manager;
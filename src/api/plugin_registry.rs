@@ -5,6 +5,9 @@ use inexor_rgf_core_plugins::PluginError;
 
 use crate::api::Lifecycle;
 use crate::plugin::proxy::PluginProxy;
+use crate::plugin::PluginContributions;
+use crate::plugin::PluginLoadError;
+use crate::plugin::PluginStatus;
 
 #[async_trait]
 pub trait PluginRegistry: Send + Sync + Lifecycle {
@@ -16,9 +19,45 @@ pub trait PluginRegistry: Send + Sync + Lifecycle {
 
     fn load_plugin(&self, name: String, path: String);
 
+    /// Unloads the plugin with the given name. The components, entity types, relation types,
+    /// behaviour providers, flow providers and web resource providers of the plugin are removed
+    /// and the library is dropped if it doesn't contain any other loaded plugin.
+    fn unload_plugin(&self, name: String) -> Result<(), PluginError>;
+
+    /// Unloads the plugin with the given name and loads it again from the same path. The
+    /// behaviours of the plugin are applied to the existing instances.
+    fn reload_plugin(&self, name: String) -> Result<(), PluginError>;
+
+    /// Loads the configured or deployed plugin with the given name again after it has been
+    /// deactivated or failed to load.
+    fn activate_plugin(&self, name: String) -> Result<(), PluginError>;
+
+    /// Unloads the plugin with the given name. The plugin can be activated again.
+    fn deactivate_plugin(&self, name: String) -> Result<(), PluginError>;
+
+    /// Returns the status of all configured and deployed plugins.
+    fn get_plugin_statuses(&self) -> Vec<PluginStatus>;
+
+    /// Returns the status of the plugin with the given name.
+    fn get_plugin_status(&self, name: String) -> Option<PluginStatus>;
+
+    /// Returns the types, flows, web resources and providers which have been contributed by the
+    /// plugin with the given name.
+    fn get_contributions(&self, name: String) -> Option<PluginContributions>;
+
     fn unload_plugins(&self);
 
-    unsafe fn load(&self, library_path: String) -> Result<(), PluginError>;
+    /// Scans the deploy directory: new libraries are loaded, overwritten libraries are reloaded
+    /// and the plugins of deleted libraries are unloaded. Does nothing if the deploy directory
+    /// is disabled or the scan interval hasn't elapsed yet.
+    fn deploy_plugins(&self);
+
+    /// Loads the library at the given path and returns the names of the contained plugins.
+    ///
+    /// # Safety
+    ///
+    /// The library must be implemented using the `plugin_declaration!()` macro.
+    unsafe fn load(&self, library_path: String) -> Result<Vec<String>, PluginLoadError>;
     // unsafe fn unload<P: AsRef<OsStr>>(&mut self, library_path: P) -> Result<(), PluginError>;
 
     fn plugin_init(&self, name: String) -> Result<(), PluginError>;
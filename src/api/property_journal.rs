@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::Lifecycle;
+use crate::model::ReactiveEntityInstance;
+
+// Handle of the journal observers of the property instances
+pub static UUID_PROPERTY_JOURNAL: Uuid = Uuid::from_u128(0x6ba7b8109e1511d150b400c04fd730c7);
+
+/// A change of a property of an entity instance which hasn't been committed to the graph
+/// database yet.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PropertyJournalEntry {
+    /// The id of the entity instance.
+    pub id: Uuid,
+
+    /// The name of the property.
+    pub property: String,
+
+    /// The new value of the property.
+    pub value: Value,
+
+    /// The time of the change in milliseconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Append-only journal of property changes. Changes which haven't been committed to the graph
+/// database are replayed at startup.
+///
+/// Appended changes are buffered and written to disk periodically. The changes of committed
+/// entity instances are removed from the journal file periodically.
+#[async_trait]
+pub trait PropertyJournal: Send + Sync + Lifecycle {
+    /// Starts recording the property changes of the given reactive entity instance.
+    fn observe(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>);
+
+    /// Stops recording the property changes of the given reactive entity instance.
+    fn unobserve(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>);
+
+    /// Appends a property change to the buffer of the journal.
+    fn append(&self, id: Uuid, property: String, value: Value);
+
+    /// Returns the property changes which haven't been committed yet.
+    fn get_entries(&self) -> Vec<PropertyJournalEntry>;
+
+    /// Removes the changes of the entity instance with the given id after it has been committed.
+    /// The changes are removed from the journal file by the next periodic compaction.
+    fn compact(&self, id: Uuid);
+
+    /// Removes all changes after all entity instances have been committed and compacts the
+    /// journal file immediately.
+    fn compact_all(&self);
+
+    /// Writes the buffered changes to disk.
+    fn flush(&self);
+
+    /// Writes the uncommitted changes of a previous run to the graph database.
+    fn replay(&self);
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use log::warn;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::Lifecycle;
+use crate::model::{DataType, PropertyType, ReactiveEntityInstance, ReactiveRelationInstance};
+
+/// The name of the extension of a property type which contains the constraints of the property.
+pub const PROPERTY_CONSTRAINTS_EXTENSION: &str = "property_constraints";
+
+lazy_static! {
+    /// The compiled patterns of the property types by pattern.
+    static ref PATTERNS: RwLock<HashMap<String, Regex>> = RwLock::new(HashMap::new());
+}
+
+/// The constraints of a property type. The constraints are stored in the extension
+/// `property_constraints` of the property type.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct PropertyConstraints {
+    /// If true, the property must be given when an instance is created. Required properties
+    /// are never initialized with a default value.
+    #[serde(default)]
+    pub required: bool,
+
+    /// The default value which is used instead of the default value of the data type.
+    pub default: Option<Value>,
+
+    /// The minimum of a number.
+    pub minimum: Option<f64>,
+
+    /// The maximum of a number.
+    pub maximum: Option<f64>,
+
+    /// The regular expression which a string has to match.
+    pub pattern: Option<String>,
+
+    /// The allowed values.
+    #[serde(rename = "enum")]
+    pub values: Option<Vec<Value>>,
+}
+
+impl PropertyConstraints {
+    /// Returns the constraints of the given property type. Property types without constraints
+    /// are unconstrained.
+    pub fn of(property_type: &PropertyType) -> PropertyConstraints {
+        property_type
+            .extensions
+            .iter()
+            .find(|extension| extension.name == PROPERTY_CONSTRAINTS_EXTENSION)
+            .and_then(|extension| match serde_json::from_value(extension.extension.clone()) {
+                Ok(constraints) => Some(constraints),
+                Err(e) => {
+                    warn!("Ignoring invalid constraints of property {}: {}", property_type.name, e);
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the default value of the given property type.
+pub fn get_default_value(property_type: &PropertyType) -> Value {
+    PropertyConstraints::of(property_type)
+        .default
+        .unwrap_or_else(|| property_type.data_type.default_value())
+}
+
+/// Returns the given properties completed by the default values of the missing properties
+/// which are not required.
+pub fn with_default_values(property_types: &[PropertyType], properties: HashMap<String, Value>) -> HashMap<String, Value> {
+    let mut values: HashMap<String, Value> = property_types
+        .iter()
+        .filter(|property_type| !PropertyConstraints::of(property_type).required)
+        .map(|property_type| (property_type.name.clone(), get_default_value(property_type)))
+        .collect();
+    values.extend(properties);
+    values
+}
+
+/// Returns the name of the first required property which is missing.
+pub fn get_missing_required_property(property_types: &[PropertyType], properties: &HashMap<String, Value>) -> Option<String> {
+    property_types
+        .iter()
+        .find(|property_type| !properties.contains_key(&property_type.name) && PropertyConstraints::of(property_type).required)
+        .map(|property_type| property_type.name.clone())
+}
+
+/// A property which doesn't match its property type.
+#[derive(Debug, Clone)]
+pub enum InvalidProperty {
+    /// The value doesn't match the data type of the property type.
+    DataTypeMismatch { name: String, data_type: DataType, value: Value },
+
+    /// The property is required but missing.
+    MissingRequiredProperty(String),
+
+    /// The number is less than the minimum.
+    BelowMinimum { name: String, minimum: f64, value: Value },
+
+    /// The number is greater than the maximum.
+    AboveMaximum { name: String, maximum: f64, value: Value },
+
+    /// The string doesn't match the pattern.
+    PatternMismatch { name: String, pattern: String, value: Value },
+
+    /// The value is not one of the allowed values.
+    NotAllowed { name: String, value: Value },
+
+    /// The property is not defined by the type or the components (strict mode only).
+    UnknownProperty(String),
+}
+
+impl InvalidProperty {
+    /// Returns the name of the invalid property.
+    pub fn name(&self) -> &str {
+        match self {
+            InvalidProperty::DataTypeMismatch { name, .. } => name,
+            InvalidProperty::MissingRequiredProperty(name) => name,
+            InvalidProperty::BelowMinimum { name, .. } => name,
+            InvalidProperty::AboveMaximum { name, .. } => name,
+            InvalidProperty::PatternMismatch { name, .. } => name,
+            InvalidProperty::NotAllowed { name, .. } => name,
+            InvalidProperty::UnknownProperty(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for InvalidProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            InvalidProperty::DataTypeMismatch { name, data_type, value } => write!(f, "Property {} expects {} but got {}", name, data_type, value),
+            InvalidProperty::MissingRequiredProperty(name) => write!(f, "Property {} is required", name),
+            InvalidProperty::BelowMinimum { name, minimum, value } => write!(f, "Property {} must be at least {} but got {}", name, minimum, value),
+            InvalidProperty::AboveMaximum { name, maximum, value } => write!(f, "Property {} must be at most {} but got {}", name, maximum, value),
+            InvalidProperty::PatternMismatch { name, pattern, value } => write!(f, "Property {} must match {} but got {}", name, pattern, value),
+            InvalidProperty::NotAllowed { name, value } => write!(f, "Property {} doesn't allow {}", name, value),
+            InvalidProperty::UnknownProperty(name) => write!(f, "Property {} is unknown", name),
+        }
+    }
+}
+
+/// The properties which don't match their property types.
+#[derive(Debug, Clone)]
+pub struct PropertyValidationError(pub Vec<InvalidProperty>);
+
+impl fmt::Display for PropertyValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let invalid_properties: Vec<String> = self.0.iter().map(|invalid_property| invalid_property.to_string()).collect();
+        write!(f, "Invalid properties: {}", invalid_properties.join(", "))
+    }
+}
+
+/// Returns true, if the given value is of the given data type.
+pub fn matches_data_type(data_type: DataType, value: &Value) -> bool {
+    match data_type {
+        DataType::Null => value.is_null(),
+        DataType::Bool => value.is_boolean(),
+        DataType::Number => value.is_number(),
+        DataType::String => value.is_string(),
+        DataType::Array => value.is_array(),
+        DataType::Object => value.is_object(),
+        DataType::Any => true,
+    }
+}
+
+/// The pattern of a property type is not a valid regular expression.
+#[derive(Debug, Clone)]
+pub struct InvalidPatternError {
+    pub property: String,
+    pub pattern: String,
+    pub reason: String,
+}
+
+impl fmt::Display for InvalidPatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid pattern {} of property {}: {}", self.pattern, self.property, self.reason)
+    }
+}
+
+/// Compiles the patterns of the given property types. Types are only registered if all
+/// patterns are valid. The compiled patterns are cached and used for validating the property
+/// values.
+pub fn compile_patterns(property_types: &[PropertyType]) -> Result<(), InvalidPatternError> {
+    for property_type in property_types.iter() {
+        if let Some(pattern) = PropertyConstraints::of(property_type).pattern {
+            if PATTERNS.read().unwrap().contains_key(&pattern) {
+                continue;
+            }
+            let regex = Regex::new(&pattern).map_err(|e| InvalidPatternError {
+                property: property_type.name.clone(),
+                pattern: pattern.clone(),
+                reason: e.to_string(),
+            })?;
+            PATTERNS.write().unwrap().insert(pattern, regex);
+        }
+    }
+    Ok(())
+}
+
+/// Returns true, if the given string matches the given pattern. The patterns of registered
+/// types are already compiled. Other patterns are compiled once. An invalid pattern never
+/// matches.
+fn is_pattern_match(pattern: &str, string: &str) -> bool {
+    if let Some(regex) = PATTERNS.read().unwrap().get(pattern) {
+        return regex.is_match(string);
+    }
+    match Regex::new(pattern) {
+        Ok(regex) => {
+            let is_match = regex.is_match(string);
+            PATTERNS.write().unwrap().insert(pattern.to_string(), regex);
+            is_match
+        }
+        Err(_) => false,
+    }
+}
+
+/// Validates the value of a property against the constraints of the property type. An invalid
+/// pattern never matches.
+fn validate_constraints(property_type: &PropertyType, value: &Value) -> Option<InvalidProperty> {
+    let name = property_type.name.clone();
+    let constraints = PropertyConstraints::of(property_type);
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = constraints.minimum.filter(|minimum| number < *minimum) {
+            return Some(InvalidProperty::BelowMinimum {
+                name,
+                minimum,
+                value: value.clone(),
+            });
+        }
+        if let Some(maximum) = constraints.maximum.filter(|maximum| number > *maximum) {
+            return Some(InvalidProperty::AboveMaximum {
+                name,
+                maximum,
+                value: value.clone(),
+            });
+        }
+    }
+    if let (Some(string), Some(pattern)) = (value.as_str(), constraints.pattern) {
+        if !is_pattern_match(&pattern, string) {
+            return Some(InvalidProperty::PatternMismatch {
+                name,
+                pattern,
+                value: value.clone(),
+            });
+        }
+    }
+    if let Some(values) = constraints.values {
+        if !values.contains(value) {
+            return Some(InvalidProperty::NotAllowed { name, value: value.clone() });
+        }
+    }
+    None
+}
+
+/// Validates the given properties against the data types and the constraints of the given
+/// property types. Properties without a property type are only rejected in strict mode.
+pub fn validate_properties(property_types: &[PropertyType], properties: &HashMap<String, Value>, strict: bool) -> Result<(), PropertyValidationError> {
+    let mut invalid_properties = Vec::new();
+    for (name, value) in properties.iter() {
+        match property_types.iter().find(|property_type| &property_type.name == name) {
+            Some(property_type) => {
+                if !matches_data_type(property_type.data_type, value) {
+                    invalid_properties.push(InvalidProperty::DataTypeMismatch {
+                        name: name.clone(),
+                        data_type: property_type.data_type,
+                        value: value.clone(),
+                    });
+                } else if let Some(invalid_property) = validate_constraints(property_type, value) {
+                    invalid_properties.push(invalid_property);
+                }
+            }
+            None => {
+                if strict {
+                    invalid_properties.push(InvalidProperty::UnknownProperty(name.clone()));
+                }
+            }
+        }
+    }
+    if invalid_properties.is_empty() {
+        return Ok(());
+    }
+    invalid_properties.sort_by(|a, b| a.name().cmp(b.name()));
+    Err(PropertyValidationError(invalid_properties))
+}
+
+/// Validates the properties of a new instance. In addition to [validate_properties] all
+/// required properties must be given.
+pub fn validate_new_properties(property_types: &[PropertyType], properties: &HashMap<String, Value>, strict: bool) -> Result<(), PropertyValidationError> {
+    let mut invalid_properties: Vec<InvalidProperty> = property_types
+        .iter()
+        .filter(|property_type| !properties.contains_key(&property_type.name) && PropertyConstraints::of(property_type).required)
+        .map(|property_type| InvalidProperty::MissingRequiredProperty(property_type.name.clone()))
+        .collect();
+    if let Err(mut e) = validate_properties(property_types, properties, strict) {
+        invalid_properties.append(&mut e.0);
+    }
+    if invalid_properties.is_empty() {
+        return Ok(());
+    }
+    invalid_properties.sort_by(|a, b| a.name().cmp(b.name()));
+    Err(PropertyValidationError(invalid_properties))
+}
+
+#[async_trait]
+pub trait PropertyValidator: Send + Sync + Lifecycle {
+    /// Returns true, if properties which are not defined by the type or the components of an
+    /// instance are rejected.
+    fn is_strict(&self) -> bool;
+
+    /// Returns the property types of an entity instance of the given entity type with the given
+    /// components. This includes the properties provided by the components of the entity type.
+    fn get_entity_instance_property_types(&self, type_name: String, components: &[String]) -> Vec<PropertyType>;
+
+    /// Returns the property types of a relation instance of the given relation type with the
+    /// given components.
+    fn get_relation_instance_property_types(&self, type_name: String, components: &[String]) -> Vec<PropertyType>;
+
+    /// Validates the properties of a new entity instance of the given type. Required properties
+    /// must be given.
+    fn validate_new_entity_instance(
+        &self,
+        type_name: String,
+        components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError>;
+
+    /// Validates the properties of a new relation instance of the given type. Required
+    /// properties must be given.
+    fn validate_new_relation_instance(
+        &self,
+        type_name: String,
+        components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError>;
+
+    /// Validates the given property values of an existing entity instance which is about to
+    /// get the given components.
+    fn validate_entity_instance(
+        &self,
+        entity_instance: &ReactiveEntityInstance,
+        add_components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError>;
+
+    /// Validates the given property values of an existing relation instance which is about to
+    /// get the given components.
+    fn validate_relation_instance(
+        &self,
+        relation_instance: &ReactiveRelationInstance,
+        add_components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError>;
+}
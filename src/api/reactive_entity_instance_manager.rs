@@ -84,13 +84,17 @@ pub trait ReactiveEntityInstanceManager: Send + Sync + Lifecycle {
     /// reactive entity instance will be registered.
     fn create_reactive_instance(&self, entity_instance: EntityInstance) -> Result<Arc<ReactiveEntityInstance>, ReactiveEntityInstanceCreationError>;
 
-    /// Registers a reactive entity instance.
-    fn register_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>);
+    /// Registers a reactive entity instance. The entity instance is stored in the graph database
+    /// if it doesn't exist yet. Returns an error if the entity instance cannot be stored.
+    fn register_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) -> Result<(), ReactiveEntityInstanceCreationError>;
 
     /// Registers a reactive entity instance if and only if the given instance doesn't exist.
     ///
     /// No properties are merged if the given entity instance already exists.
-    fn register_or_merge_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) -> Arc<ReactiveEntityInstance>;
+    fn register_or_merge_reactive_instance(
+        &self,
+        reactive_entity_instance: Arc<ReactiveEntityInstance>,
+    ) -> Result<Arc<ReactiveEntityInstance>, ReactiveEntityInstanceCreationError>;
 
     /// Adds the component with the given name to the entity instance with the given id.
     fn add_component(&self, id: Uuid, component: String);
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use uuid::Uuid;
 
+use crate::api::FlowCreationError;
 use crate::api::Lifecycle;
 use crate::model::{Flow, ReactiveFlow, ReactiveFlowConstructionError};
 use crate::plugins::FlowProvider;
@@ -15,6 +16,7 @@ pub enum ReactiveFlowCreationError {
     // ReactiveEntityInstanceCreationError(ReactiveEntityInstanceCreationError),
     // ReactiveRelationInstanceCreationError(ReactiveRelationInstanceCreationError),
     ReactiveFlowConstructionError(ReactiveFlowConstructionError),
+    FlowCreationError(FlowCreationError),
 }
 
 impl fmt::Display for ReactiveFlowCreationError {
@@ -29,6 +31,7 @@ impl fmt::Display for ReactiveFlowCreationError {
             // ReactiveFlowCreationError::ReactiveEntityInstanceCreationError(error) => write!(f, "Failed to create reactive entity instance: {}", error.to_string()),
             // ReactiveFlowCreationError::ReactiveRelationInstanceCreationError(error) => write!(f, "Failed to create reactive relation instance: {}", error.to_string())
             ReactiveFlowCreationError::ReactiveFlowConstructionError(error) => write!(f, "Failed to construct reactive flow: {}", error),
+            ReactiveFlowCreationError::FlowCreationError(error) => write!(f, "Failed to store flow: {}", error),
         }
     }
 }
@@ -79,4 +82,7 @@ pub trait ReactiveFlowManager: Send + Sync + Lifecycle {
     fn export(&self, id: Uuid, path: String);
 
     fn add_provider(&self, flow_provider: Arc<dyn FlowProvider>);
+
+    /// Unregisters a flow provider. The flows which have been created by the provider are kept.
+    fn remove_provider(&self, flow_provider: Arc<dyn FlowProvider>);
 }
@@ -7,7 +7,7 @@ use indradb::{EdgeKey, ValidationError};
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::api::{RelationInstanceCreationError, RelationInstanceImportError};
+use crate::api::{Lifecycle, RelationInstanceCreationError, RelationInstanceImportError};
 use crate::model::{ReactiveRelationInstance, RelationInstance};
 
 #[derive(Debug)]
@@ -65,7 +65,7 @@ pub enum ReactiveRelationInstanceImportError {
 }
 
 #[async_trait]
-pub trait ReactiveRelationInstanceManager: Send + Sync {
+pub trait ReactiveRelationInstanceManager: Send + Sync + Lifecycle {
     /// Returns true, if an relation of the given type exists which starts at the given outbound entity and
     /// ends at the given inbound entity.
     fn has(&self, edge_key: EdgeKey) -> bool;
@@ -91,9 +91,14 @@ pub trait ReactiveRelationInstanceManager: Send + Sync {
 
     fn create_reactive_instance(&self, relation_instance: RelationInstance) -> Result<Arc<ReactiveRelationInstance>, ReactiveRelationInstanceCreationError>;
 
-    fn register_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>);
+    /// Registers a reactive relation instance. The relation instance is stored in the graph
+    /// database if it doesn't exist yet. Returns an error if the relation instance cannot be stored.
+    fn register_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>) -> Result<(), ReactiveRelationInstanceCreationError>;
 
-    fn register_or_merge_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>) -> Arc<ReactiveRelationInstance>;
+    fn register_or_merge_reactive_instance(
+        &self,
+        reactive_relation_instance: Arc<ReactiveRelationInstance>,
+    ) -> Result<Arc<ReactiveRelationInstance>, ReactiveRelationInstanceCreationError>;
 
     /// Adds the component with the given name to the relation instance with the given edge key.
     fn add_component(&self, edge_key: EdgeKey, component: String);
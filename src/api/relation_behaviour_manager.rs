@@ -15,4 +15,6 @@ pub trait RelationBehaviourManager: Send + Sync {
     fn remove_behaviours_by_key(&self, edge_key: EdgeKey);
 
     fn add_provider(&self, behaviour_provider: Arc<dyn RelationBehaviourProvider>);
+
+    fn remove_provider(&self, behaviour_provider: Arc<dyn RelationBehaviourProvider>);
 }
@@ -6,6 +6,8 @@ use indradb::{Edge, EdgeKey, EdgeProperties};
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::api::PropertyValidationError;
+
 #[derive(Debug)]
 pub struct RelationEdgeKeyInvalid;
 
@@ -13,6 +15,7 @@ pub struct RelationEdgeKeyInvalid;
 pub enum RelationEdgeCreationError {
     RelationTypeMissing(String),
     MissingRequiredProperty(String),
+    InvalidProperties(PropertyValidationError),
     GraphDatabaseError(indradb::Error),
 }
 
@@ -25,6 +28,7 @@ impl fmt::Display for RelationEdgeCreationError {
             RelationEdgeCreationError::MissingRequiredProperty(property_name) => {
                 write!(f, "Missing required property {}!", property_name.clone())
             }
+            RelationEdgeCreationError::InvalidProperties(error) => write!(f, "{}", error),
             RelationEdgeCreationError::GraphDatabaseError(error) => write!(f, "Failed to create graph database edge: {}", error),
         }
     }
@@ -52,6 +56,9 @@ pub trait RelationEdgeManager: Send + Sync {
     /// the edge and the type.
     fn get_properties(&self, edge_key: EdgeKey) -> Option<EdgeProperties>;
 
+    /// Returns the edge properties of all edges stored in the graph database.
+    fn get_all_properties(&self) -> Vec<EdgeProperties>;
+
     /// Creates a new edge with the given edge key and the given properties.
     fn create(&self, edge_key: EdgeKey, properties: HashMap<String, Value>) -> Result<EdgeKey, RelationEdgeCreationError>;
 
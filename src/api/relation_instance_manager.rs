@@ -65,6 +65,9 @@ pub trait RelationInstanceManager: Send + Sync {
     /// Returns the relation instance with the given key or None.
     fn get(&self, edge_key: EdgeKey) -> Option<RelationInstance>;
 
+    /// Returns all relation instances stored in the graph database.
+    fn get_all(&self) -> Vec<RelationInstance>;
+
     fn get_by_outbound_entity(&self, outbound_entity_id: Uuid) -> Vec<RelationInstance>;
 
     fn get_by_inbound_entity(&self, inbound_entity_id: Uuid) -> Vec<RelationInstance>;
@@ -0,0 +1,130 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+use crate::api::Lifecycle;
+use crate::model::{Component, EntityInstance, EntityType, Flow, RelationInstance, RelationType};
+
+/// The version of the snapshot format. Snapshots with another version are rejected.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// The file format of a snapshot.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotFormat {
+    Json,
+    Cbor,
+}
+
+impl SnapshotFormat {
+    /// Returns the snapshot format for the given path. Files ending with `.cbor` are CBOR, all
+    /// other files are JSON.
+    pub fn from_path(path: &str) -> SnapshotFormat {
+        if path.to_lowercase().ends_with(".cbor") {
+            SnapshotFormat::Cbor
+        } else {
+            SnapshotFormat::Json
+        }
+    }
+}
+
+impl Default for SnapshotFormat {
+    fn default() -> Self {
+        SnapshotFormat::Json
+    }
+}
+
+/// A snapshot of the whole graph: the type system, the instances and the flows.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Snapshot {
+    /// The version of the snapshot format.
+    pub version: u32,
+
+    /// The creation time of the snapshot in seconds since the unix epoch.
+    pub timestamp: u64,
+
+    #[serde(default = "Vec::new")]
+    pub components: Vec<Component>,
+
+    #[serde(default = "Vec::new")]
+    pub entity_types: Vec<EntityType>,
+
+    #[serde(default = "Vec::new")]
+    pub relation_types: Vec<RelationType>,
+
+    #[serde(default = "Vec::new")]
+    pub entity_instances: Vec<EntityInstance>,
+
+    #[serde(default = "Vec::new")]
+    pub relation_instances: Vec<RelationInstance>,
+
+    #[serde(default = "Vec::new")]
+    pub flows: Vec<Flow>,
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Cbor(serde_cbor::Error),
+    UnsupportedVersion(u32),
+    InvalidName(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            SnapshotError::Io(error) => write!(f, "Failed to access snapshot file: {}", error),
+            SnapshotError::Json(error) => write!(f, "Failed to (de)serialize snapshot as JSON: {}", error),
+            SnapshotError::Cbor(error) => write!(f, "Failed to (de)serialize snapshot as CBOR: {}", error),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported snapshot version {} (expected version {})", version, SNAPSHOT_VERSION)
+            }
+            SnapshotError::InvalidName(name) => write!(f, "Invalid snapshot name {}: Expected a file name without a directory", name),
+        }
+    }
+}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(e: std::io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(e: serde_json::Error) -> Self {
+        SnapshotError::Json(e)
+    }
+}
+
+impl From<serde_cbor::Error> for SnapshotError {
+    fn from(e: serde_cbor::Error) -> Self {
+        SnapshotError::Cbor(e)
+    }
+}
+
+#[async_trait]
+pub trait SnapshotManager: Send + Sync + Lifecycle {
+    /// Creates a snapshot of all components, entity types, relation types, entity instances,
+    /// relation instances and flows.
+    fn create_snapshot(&self) -> Snapshot;
+
+    /// Restores the given snapshot. Types and instances which already exist are kept.
+    fn restore_snapshot(&self, snapshot: Snapshot) -> Result<(), SnapshotError>;
+
+    /// Returns the path of the snapshot with the given name in the snapshot directory. The
+    /// snapshot directory is the directory of the path configured in `config/snapshot.toml`.
+    /// Without a name, the configured path is returned. Names which are not a plain file name
+    /// are rejected.
+    fn get_snapshot_path(&self, name: Option<String>) -> Result<String, SnapshotError>;
+
+    /// Creates a snapshot and writes it to the given path. The format is derived from the file
+    /// extension.
+    fn export(&self, path: String) -> Result<Snapshot, SnapshotError>;
+
+    /// Reads the snapshot from the given path and restores it. The format is derived from the
+    /// file extension.
+    fn import(&self, path: String) -> Result<Snapshot, SnapshotError>;
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use indradb::EdgeKey;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::Lifecycle;
+use crate::model::{Flow, ReactiveEntityInstance, ReactiveFlow, ReactiveRelationInstance};
+
+/// An operation of a transaction.
+#[derive(Clone, Debug)]
+pub enum TransactionOperation {
+    /// Creates an entity instance. Properties which are not given are initialized with the
+    /// default value of their data type.
+    CreateEntityInstance {
+        type_name: String,
+        id: Option<Uuid>,
+        components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+
+    /// Adds and removes components and sets the given properties of an entity instance.
+    UpdateEntityInstance {
+        id: Uuid,
+        add_components: Vec<String>,
+        remove_components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+
+    DeleteEntityInstance {
+        id: Uuid,
+    },
+
+    /// Creates a relation instance. Properties which are not given are initialized with the
+    /// default value of their data type.
+    CreateRelationInstance {
+        edge_key: EdgeKey,
+        components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+
+    /// Adds and removes components and sets the given properties of a relation instance.
+    UpdateRelationInstance {
+        edge_key: EdgeKey,
+        add_components: Vec<String>,
+        remove_components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+
+    DeleteRelationInstance {
+        edge_key: EdgeKey,
+    },
+
+    CreateFlow {
+        flow: Flow,
+    },
+
+    DeleteFlow {
+        id: Uuid,
+    },
+}
+
+#[derive(Debug)]
+pub enum TransactionError {
+    /// The operation with the given index is invalid. No operation has been applied.
+    Invalid { index: usize, message: String },
+
+    /// The operation with the given index failed. The previous operations have been rolled back.
+    Failed { index: usize, message: String },
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            TransactionError::Invalid { index, message } => write!(f, "Operation {} is invalid: {}", index, message),
+            TransactionError::Failed { index, message } => write!(f, "Operation {} failed and the transaction has been rolled back: {}", index, message),
+        }
+    }
+}
+
+/// The instances and flows which have been created or updated by a transaction.
+#[derive(Default)]
+pub struct TransactionResult {
+    pub entity_instances: Vec<Arc<ReactiveEntityInstance>>,
+
+    pub relation_instances: Vec<Arc<ReactiveRelationInstance>>,
+
+    pub flows: Vec<Arc<ReactiveFlow>>,
+}
+
+#[async_trait]
+pub trait TransactionManager: Send + Sync + Lifecycle {
+    /// Validates and applies the given operations in the given order.
+    ///
+    /// If any operation is invalid, no operation is applied. If an operation fails, the
+    /// previously applied operations are rolled back. Transactions are executed one after
+    /// another.
+    fn execute(&self, operations: Vec<TransactionOperation>) -> Result<TransactionResult, TransactionError>;
+}
@@ -12,4 +12,5 @@ pub trait WebResourceManager: Send + Sync + Lifecycle {
     fn get_default(&self) -> Option<Arc<dyn WebResourceProvider>>;
     fn get_default_base_path(&self) -> Option<String>;
     fn add_provider(&self, web_resource_provider: Arc<dyn WebResourceProvider>);
+    fn remove_provider(&self, base_path: String);
 }
@@ -71,9 +71,23 @@ pub trait Application: Send + Sync {
 
     fn get_graphql_server(&self) -> Arc<dyn GraphQLServer>;
 
+    fn get_graphql_query_service(&self) -> Arc<dyn GraphQLQueryService>;
+
     fn get_plugin_registry(&self) -> Arc<dyn PluginRegistry>;
 
     fn get_web_resource_manager(&self) -> Arc<dyn WebResourceManager>;
+
+    fn get_snapshot_manager(&self) -> Arc<dyn SnapshotManager>;
+
+    fn get_property_journal(&self) -> Arc<dyn PropertyJournal>;
+
+    fn get_auth_manager(&self) -> Arc<dyn AuthManager>;
+
+    fn get_access_control_manager(&self) -> Arc<dyn AccessControlManager>;
+
+    fn get_transaction_manager(&self) -> Arc<dyn TransactionManager>;
+
+    fn get_property_validator(&self) -> Arc<dyn PropertyValidator>;
 }
 
 #[module]
@@ -98,66 +112,102 @@ pub struct ApplicationImpl {
     relation_instance_manager: Wrc<dyn RelationInstanceManager>,
     relation_type_manager: Wrc<dyn RelationTypeManager>,
     graphql_server: Wrc<dyn GraphQLServer>,
+    graphql_query_service: Wrc<dyn GraphQLQueryService>,
     plugin_registry: Wrc<dyn PluginRegistry>,
     web_resource_manager: Wrc<dyn WebResourceManager>,
+    snapshot_manager: Wrc<dyn SnapshotManager>,
+    property_journal: Wrc<dyn PropertyJournal>,
+    auth_manager: Wrc<dyn AuthManager>,
+    access_control_manager: Wrc<dyn AccessControlManager>,
+    transaction_manager: Wrc<dyn TransactionManager>,
+    property_validator: Wrc<dyn PropertyValidator>,
 }
 
 #[async_trait]
 #[provides]
 impl Application for ApplicationImpl {
     fn init(&self) {
+        self.graph_database.init();
+        self.property_journal.init(); // after graph_database!
         self.component_manager.init();
         self.entity_type_manager.init();
         self.relation_type_manager.init();
         self.plugin_registry.init();
         self.reactive_flow_manager.init();
         self.web_resource_manager.init();
+        self.auth_manager.init();
+        self.access_control_manager.init();
         self.graphql_server.init();
         self.shutdown_manager.init();
         self.event_manager.init();
         self.reactive_entity_instance_manager.init();
+        self.reactive_relation_instance_manager.init();
+        self.snapshot_manager.init();
     }
 
     fn post_init(&self) {
+        self.graph_database.post_init();
+        self.property_journal.post_init();
         self.component_manager.post_init();
         self.entity_type_manager.post_init();
         self.relation_type_manager.post_init();
         self.plugin_registry.post_init();
-        self.reactive_flow_manager.post_init();
         self.web_resource_manager.post_init();
+        self.auth_manager.post_init();
+        self.access_control_manager.post_init();
         self.graphql_server.post_init();
         self.shutdown_manager.post_init();
         self.event_manager.post_init();
         self.reactive_entity_instance_manager.post_init(); // after event_manager!
+        self.reactive_relation_instance_manager.post_init(); // after reactive_entity_instance_manager!
+        self.reactive_flow_manager.post_init(); // after reactive_relation_instance_manager!
+        self.snapshot_manager.post_init(); // after reactive_flow_manager!
     }
 
     fn pre_shutdown(&self) {
+        self.snapshot_manager.pre_shutdown();
+        self.reactive_relation_instance_manager.pre_shutdown();
         self.reactive_entity_instance_manager.pre_shutdown();
         self.event_manager.pre_shutdown();
         self.shutdown_manager.pre_shutdown();
         self.graphql_server.pre_shutdown();
+        self.access_control_manager.pre_shutdown();
+        self.auth_manager.pre_shutdown();
         self.web_resource_manager.pre_shutdown();
         self.reactive_flow_manager.pre_shutdown();
         self.plugin_registry.pre_shutdown();
         self.relation_type_manager.pre_shutdown();
         self.entity_type_manager.pre_shutdown();
         self.component_manager.pre_shutdown();
+        self.property_journal.pre_shutdown();
+        self.graph_database.pre_shutdown();
     }
 
     fn shutdown(&self) {
+        self.snapshot_manager.shutdown();
+        self.reactive_relation_instance_manager.shutdown();
         self.reactive_entity_instance_manager.shutdown();
         self.event_manager.shutdown();
         self.shutdown_manager.shutdown();
         self.graphql_server.shutdown();
+        self.access_control_manager.shutdown();
+        self.auth_manager.shutdown();
         self.web_resource_manager.shutdown();
         self.reactive_flow_manager.shutdown();
         self.plugin_registry.shutdown();
         self.relation_type_manager.shutdown();
         self.entity_type_manager.shutdown();
         self.component_manager.shutdown();
+        self.property_journal.shutdown();
+        self.graph_database.shutdown();
     }
 
     async fn run(&mut self) {
+        // A shutdown may have been requested during initialization
+        if self.shutdown_manager.is_shutdown() {
+            info!("Shutdown requested during initialization");
+            return;
+        }
         // Signal handling
         let terminate = Arc::new(AtomicBool::new(false));
         // This channel allows the main thread to stop the GraphQL server thread
@@ -200,6 +250,7 @@ impl Application for ApplicationImpl {
                 if self.shutdown_manager.is_shutdown() {
                     stopping = true;
                 }
+                self.plugin_registry.deploy_plugins();
             }
         } // Drop "running"
 
@@ -297,6 +348,10 @@ impl Application for ApplicationImpl {
         self.graphql_server.clone()
     }
 
+    fn get_graphql_query_service(&self) -> Arc<dyn GraphQLQueryService> {
+        self.graphql_query_service.clone()
+    }
+
     fn get_plugin_registry(&self) -> Arc<dyn PluginRegistry> {
         self.plugin_registry.clone()
     }
@@ -304,4 +359,28 @@ impl Application for ApplicationImpl {
     fn get_web_resource_manager(&self) -> Arc<dyn WebResourceManager> {
         self.web_resource_manager.clone()
     }
+
+    fn get_snapshot_manager(&self) -> Arc<dyn SnapshotManager> {
+        self.snapshot_manager.clone()
+    }
+
+    fn get_property_journal(&self) -> Arc<dyn PropertyJournal> {
+        self.property_journal.clone()
+    }
+
+    fn get_auth_manager(&self) -> Arc<dyn AuthManager> {
+        self.auth_manager.clone()
+    }
+
+    fn get_access_control_manager(&self) -> Arc<dyn AccessControlManager> {
+        self.access_control_manager.clone()
+    }
+
+    fn get_transaction_manager(&self) -> Arc<dyn TransactionManager> {
+        self.transaction_manager.clone()
+    }
+
+    fn get_property_validator(&self) -> Arc<dyn PropertyValidator> {
+        self.property_validator.clone()
+    }
 }
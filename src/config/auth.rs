@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(not(test))]
+use crate::config::load_required_config;
+use crate::config::ConfigError;
+
+/// A static API token.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiTokenConfig {
+    /// The name of the identity which is authenticated by the token.
+    pub name: String,
+
+    /// The secret token.
+    pub token: String,
+
+    /// The roles of the identity.
+    #[serde(default = "Vec::new")]
+    pub roles: Vec<String>,
+}
+
+/// JSON web tokens which are verified with a local key.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct JwtConfig {
+    /// The algorithm of the signature (HS256, HS384, HS512, RS256, ..., ES256, ES384, EdDSA).
+    #[serde(default = "default_jwt_algorithm")]
+    pub algorithm: String,
+
+    /// The shared secret (HMAC algorithms).
+    pub secret: Option<String>,
+
+    /// The path of the PEM encoded public key (RSA, EC and EdDSA algorithms).
+    pub public_key: Option<String>,
+
+    /// The expected issuer (claim `iss`).
+    pub issuer: Option<String>,
+
+    /// The expected audience (claim `aud`).
+    pub audience: Option<String>,
+}
+
+fn default_jwt_algorithm() -> String {
+    String::from("HS256")
+}
+
+/// Unknown keys are rejected, because a misspelled key must not silently weaken the
+/// authentication.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    /// If false, all requests are processed with the anonymous identity.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The static API tokens.
+    #[serde(default = "Vec::new")]
+    pub token: Vec<ApiTokenConfig>,
+
+    pub jwt: Option<JwtConfig>,
+}
+
+/// Tests don't authenticate.
+#[cfg(test)]
+pub(crate) fn get_auth_config() -> Result<AuthConfig, ConfigError> {
+    Ok(AuthConfig::default())
+}
+
+/// The auth configuration is required. A missing or invalid configuration file must never
+/// silently disable authentication.
+#[cfg(not(test))]
+pub(crate) fn get_auth_config() -> Result<AuthConfig, ConfigError> {
+    load_required_config("./config/auth.toml")
+}
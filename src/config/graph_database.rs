@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use serde::Serialize;
+use strum_macros::Display;
+
+#[cfg(not(test))]
+use crate::config::load_config;
+use crate::config::ConfigError;
+
+/// The storage backend of the graph database.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphDatabaseBackend {
+    /// The in-memory datastore. If a path is configured, the datastore is loaded from and
+    /// synced to a single file.
+    Memory,
+
+    /// The RocksDB datastore which stores the graph in a directory on disk.
+    Rocksdb,
+}
+
+impl Default for GraphDatabaseBackend {
+    fn default() -> Self {
+        GraphDatabaseBackend::Rocksdb
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GraphDatabaseConfig {
+    /// The storage backend.
+    #[serde(default)]
+    pub backend: GraphDatabaseBackend,
+
+    /// The path of the datastore. The memory backend is volatile if no path is given. The
+    /// RocksDB backend requires a path.
+    pub path: Option<String>,
+
+    /// The maximum number of open files (RocksDB only).
+    pub max_open_files: Option<i32>,
+}
+
+impl Default for GraphDatabaseConfig {
+    fn default() -> Self {
+        GraphDatabaseConfig {
+            backend: GraphDatabaseBackend::default(),
+            path: Some(String::from("./data/graph")),
+            max_open_files: None,
+        }
+    }
+}
+
+/// Tests always run against a fresh, volatile in-memory datastore.
+#[cfg(test)]
+pub(crate) fn get_graph_database_config() -> Result<GraphDatabaseConfig, ConfigError> {
+    Ok(GraphDatabaseConfig {
+        backend: GraphDatabaseBackend::Memory,
+        path: None,
+        max_open_files: None,
+    })
+}
+
+#[cfg(not(test))]
+pub(crate) fn get_graph_database_config() -> Result<GraphDatabaseConfig, ConfigError> {
+    load_config("./config/graph_database.toml")
+}
@@ -1,5 +1,19 @@
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::str::FromStr;
+
+use actix_cors::Cors;
+use actix_web::dev::RequestHead;
 use actix_web::middleware::Logger;
+use http::header::HeaderName;
+use http::header::HeaderValue;
+use http::Method;
+use http::Uri;
 use log::error;
+use rustls::server::{AllowAnyAnonymousOrAuthenticatedClient, AllowAnyAuthenticatedClient};
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use rustls_pemfile::Item;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -9,6 +23,298 @@ pub struct GraphQLLoggingConfig {
     pub format: Option<String>,
 }
 
+/// The CORS policy. Only same-origin requests are permitted unless origins are allowed
+/// explicitly. The origin `*` allows any origin. Empty lists of methods or headers allow any
+/// method or header.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct GraphQLCorsConfig {
+    #[serde(default = "Vec::new")]
+    pub allowed_origins: Vec<String>,
+
+    #[serde(default = "Vec::new")]
+    pub allowed_methods: Vec<String>,
+
+    #[serde(default = "Vec::new")]
+    pub allowed_headers: Vec<String>,
+
+    /// Allows cookies and the `Authorization` header in cross-origin requests.
+    #[serde(default)]
+    pub supports_credentials: bool,
+
+    /// The time in seconds a preflight request can be cached.
+    pub max_age: Option<usize>,
+}
+
+#[derive(Debug)]
+pub enum GraphQLCorsConfigError {
+    InvalidOrigin(String),
+    InvalidMethod(String),
+    InvalidHeader(String),
+}
+
+impl fmt::Display for GraphQLCorsConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            GraphQLCorsConfigError::InvalidOrigin(origin) => write!(f, "Invalid allowed origin: {}", origin),
+            GraphQLCorsConfigError::InvalidMethod(method) => write!(f, "Invalid allowed method: {}", method),
+            GraphQLCorsConfigError::InvalidHeader(header) => write!(f, "Invalid allowed header: {}", header),
+        }
+    }
+}
+
+impl GraphQLCorsConfig {
+    /// Validates the origins, methods and headers and creates the CORS policy.
+    ///
+    /// The origin `*` allows any origin, no origins permit same-origin requests only.
+    pub fn to_cors_policy(&self) -> Result<GraphQLCorsPolicy, GraphQLCorsConfigError> {
+        let allowed_origins = if self.allowed_origins.iter().any(|origin| origin == "*") {
+            None
+        } else {
+            let mut allowed_origins = Vec::new();
+            for origin in self.allowed_origins.iter() {
+                match Uri::from_str(origin) {
+                    Ok(uri) if uri.scheme().is_some() && uri.host().is_some() => allowed_origins.push(origin.clone()),
+                    _ => return Err(GraphQLCorsConfigError::InvalidOrigin(origin.clone())),
+                }
+            }
+            Some(allowed_origins)
+        };
+        let allowed_methods = if self.allowed_methods.is_empty() {
+            None
+        } else {
+            let mut allowed_methods = Vec::new();
+            for method in self.allowed_methods.iter() {
+                allowed_methods.push(Method::from_str(method).map_err(|_| GraphQLCorsConfigError::InvalidMethod(method.clone()))?);
+            }
+            Some(allowed_methods)
+        };
+        let allowed_headers = if self.allowed_headers.is_empty() {
+            None
+        } else {
+            let mut allowed_headers = Vec::new();
+            for header in self.allowed_headers.iter() {
+                allowed_headers.push(HeaderName::from_str(header).map_err(|_| GraphQLCorsConfigError::InvalidHeader(header.clone()))?);
+            }
+            Some(allowed_headers)
+        };
+        Ok(GraphQLCorsPolicy {
+            allowed_origins,
+            allowed_methods,
+            allowed_headers,
+            supports_credentials: self.supports_credentials,
+            max_age: self.max_age,
+        })
+    }
+}
+
+/// A validated CORS policy. `None` allows any origin, method or header. Same-origin requests are
+/// always permitted.
+#[derive(Debug, Clone)]
+pub struct GraphQLCorsPolicy {
+    pub allowed_origins: Option<Vec<String>>,
+    pub allowed_methods: Option<Vec<Method>>,
+    pub allowed_headers: Option<Vec<HeaderName>>,
+    pub supports_credentials: bool,
+    pub max_age: Option<usize>,
+}
+
+impl GraphQLCorsPolicy {
+    /// Creates the CORS middleware. Each worker creates its own middleware.
+    pub fn to_cors(&self) -> Cors {
+        let mut cors = match &self.allowed_origins {
+            Some(allowed_origins) => allowed_origins
+                .iter()
+                .fold(Cors::default().allowed_origin_fn(is_same_origin), |cors, origin| cors.allowed_origin(origin)),
+            None => Cors::default().allow_any_origin(),
+        };
+        cors = match &self.allowed_methods {
+            Some(allowed_methods) => cors.allowed_methods(allowed_methods.clone()),
+            None => cors.allow_any_method(),
+        };
+        cors = match &self.allowed_headers {
+            Some(allowed_headers) => cors.allowed_headers(allowed_headers.clone()),
+            None => cors.allow_any_header(),
+        };
+        if self.supports_credentials {
+            cors = cors.supports_credentials();
+        }
+        cors.max_age(self.max_age)
+    }
+}
+
+/// Returns true, if the origin of the request is the server itself. Browsers send the origin
+/// header with same-origin requests, too (for example the GraphQL client served by the server).
+fn is_same_origin(origin: &HeaderValue, request: &RequestHead) -> bool {
+    let authority = match origin.to_str().ok().and_then(|origin| Uri::from_str(origin).ok()) {
+        Some(origin) => match origin.authority() {
+            Some(authority) => authority.as_str().to_lowercase(),
+            None => return false,
+        },
+        None => return false,
+    };
+    let host = request
+        .headers()
+        .get(http::header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .map(String::from)
+        .or_else(|| request.uri.authority().map(|authority| authority.to_string()));
+    match host {
+        Some(host) => host.to_lowercase() == authority,
+        None => false,
+    }
+}
+
+/// The certificate and the private key of the server and the certificates of the certificate
+/// authorities which issue client certificates.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GraphQLTlsConfig {
+    /// The path of the PEM encoded certificate chain.
+    #[serde(default = "default_certificate")]
+    pub certificate: String,
+
+    /// The path of the PEM encoded private key (PKCS#8, PKCS#1 or SEC1).
+    #[serde(default = "default_private_key")]
+    pub private_key: String,
+
+    /// The path of the PEM encoded certificates of the trusted client certificate authorities.
+    /// If set, clients can authenticate with a client certificate.
+    pub client_certificates: Option<String>,
+
+    /// If true, clients without a valid client certificate are rejected.
+    #[serde(default)]
+    pub client_certificates_required: bool,
+}
+
+fn default_certificate() -> String {
+    String::from("./keys/cert.pem")
+}
+
+fn default_private_key() -> String {
+    String::from("./keys/key.pem")
+}
+
+impl Default for GraphQLTlsConfig {
+    fn default() -> Self {
+        GraphQLTlsConfig {
+            certificate: default_certificate(),
+            private_key: default_private_key(),
+            client_certificates: None,
+            client_certificates_required: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GraphQLTlsConfigError {
+    ReadError(String, std::io::Error),
+    MissingCertificate(String),
+    MissingPrivateKey(String),
+    InvalidClientCertificate(String),
+    TlsError(rustls::Error),
+}
+
+impl fmt::Display for GraphQLTlsConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            GraphQLTlsConfigError::ReadError(path, e) => write!(f, "Failed to read {}: {}", path, e),
+            GraphQLTlsConfigError::MissingCertificate(path) => write!(f, "No certificate found in {}", path),
+            GraphQLTlsConfigError::MissingPrivateKey(path) => write!(f, "No PKCS#8, PKCS#1 or EC private key found in {}", path),
+            GraphQLTlsConfigError::InvalidClientCertificate(path) => write!(f, "Invalid client certificate authority in {}", path),
+            GraphQLTlsConfigError::TlsError(e) => write!(f, "Invalid TLS configuration: {}", e),
+        }
+    }
+}
+
+impl GraphQLTlsConfig {
+    /// Loads the certificates and the private key and creates the TLS configuration.
+    pub fn to_server_config(&self) -> Result<ServerConfig, GraphQLTlsConfigError> {
+        let cert_chain: Vec<Certificate> = read_certificates(&self.certificate)?;
+        let private_key = read_pem_items(&self.private_key)?
+            .into_iter()
+            .find_map(|item| match item {
+                Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key) => Some(PrivateKey(key)),
+                _ => None,
+            })
+            .ok_or_else(|| GraphQLTlsConfigError::MissingPrivateKey(self.private_key.clone()))?;
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = match &self.client_certificates {
+            Some(path) => {
+                let certificates: Vec<Vec<u8>> = read_certificates(path)?.into_iter().map(|certificate| certificate.0).collect();
+                let mut root_cert_store = RootCertStore::empty();
+                let (_, invalid) = root_cert_store.add_parsable_certificates(&certificates);
+                if invalid > 0 {
+                    return Err(GraphQLTlsConfigError::InvalidClientCertificate(path.clone()));
+                }
+                if self.client_certificates_required {
+                    builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(root_cert_store))
+                } else {
+                    builder.with_client_cert_verifier(AllowAnyAnonymousOrAuthenticatedClient::new(root_cert_store))
+                }
+            }
+            None => builder.with_no_client_auth(),
+        };
+        builder.with_single_cert(cert_chain, private_key).map_err(GraphQLTlsConfigError::TlsError)
+    }
+}
+
+fn read_pem_items(path: &str) -> Result<Vec<Item>, GraphQLTlsConfigError> {
+    let file = File::open(path).map_err(|e| GraphQLTlsConfigError::ReadError(path.to_string(), e))?;
+    rustls_pemfile::read_all(&mut BufReader::new(file)).map_err(|e| GraphQLTlsConfigError::ReadError(path.to_string(), e))
+}
+
+fn read_certificates(path: &str) -> Result<Vec<Certificate>, GraphQLTlsConfigError> {
+    let certificates: Vec<Certificate> = read_pem_items(path)?
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::X509Certificate(certificate) => Some(Certificate(certificate)),
+            _ => None,
+        })
+        .collect();
+    if certificates.is_empty() {
+        return Err(GraphQLTlsConfigError::MissingCertificate(path.to_string()));
+    }
+    Ok(certificates)
+}
+
+/// Decides which values are kept if a subscriber consumes the values of a property slower
+/// than the property emits them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// Keeps the latest `capacity` values and drops the oldest values.
+    DropOldest,
+
+    /// Keeps only the latest value of each property.
+    CoalesceLatest,
+
+    /// Keeps the first `capacity` values and drops newer values until the subscriber catches up.
+    #[default]
+    Buffer,
+}
+
+fn default_subscription_capacity() -> usize {
+    1000
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GraphQLSubscriptionConfig {
+    #[serde(default)]
+    pub backpressure: BackpressurePolicy,
+
+    /// The maximum number of buffered values per subscription.
+    #[serde(default = "default_subscription_capacity")]
+    pub capacity: usize,
+}
+
+impl Default for GraphQLSubscriptionConfig {
+    fn default() -> Self {
+        GraphQLSubscriptionConfig {
+            backpressure: BackpressurePolicy::default(),
+            capacity: default_subscription_capacity(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GraphQLServerConfig {
     pub hostname: String,
@@ -18,6 +324,12 @@ pub struct GraphQLServerConfig {
     pub workers: Option<usize>,
     pub default_base_path: Option<String>,
     pub logging: Option<GraphQLLoggingConfig>,
+    /// The CORS policy. If omitted, only same-origin requests are permitted.
+    pub cors: Option<GraphQLCorsConfig>,
+    /// The TLS configuration which is used if `secure` is true.
+    pub tls: Option<GraphQLTlsConfig>,
+    /// The backpressure of subscriptions and server-sent events.
+    pub subscriptions: Option<GraphQLSubscriptionConfig>,
 }
 
 impl Default for GraphQLServerConfig {
@@ -30,6 +342,9 @@ impl Default for GraphQLServerConfig {
             workers: None,
             default_base_path: None,
             logging: None,
+            cors: None,
+            tls: None,
+            subscriptions: None,
         }
     }
 }
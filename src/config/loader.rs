@@ -0,0 +1,43 @@
+use std::fmt;
+use std::io::ErrorKind;
+
+use log::info;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    NotFound(String),
+    ReadError(String, std::io::Error),
+    InvalidToml(String, toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            ConfigError::NotFound(path) => write!(f, "Configuration file {} does not exist", path),
+            ConfigError::ReadError(path, e) => write!(f, "Failed to read configuration file {}: {}", path, e),
+            ConfigError::InvalidToml(path, e) => write!(f, "Invalid TOML in configuration file {}: {}", path, e),
+        }
+    }
+}
+
+/// Loads the configuration from the given TOML file. Fails if the file doesn't exist.
+pub fn load_required_config<T: DeserializeOwned>(path: &str) -> Result<T, ConfigError> {
+    let toml_string = std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => ConfigError::NotFound(path.to_string()),
+        _ => ConfigError::ReadError(path.to_string(), e),
+    })?;
+    toml::from_str(&toml_string).map_err(|e| ConfigError::InvalidToml(path.to_string(), e))
+}
+
+/// Loads the configuration from the given TOML file. Returns the default configuration if the
+/// file doesn't exist.
+pub fn load_config<T: DeserializeOwned + Default>(path: &str) -> Result<T, ConfigError> {
+    match load_required_config(path) {
+        Err(ConfigError::NotFound(_)) => {
+            info!("Configuration file {} does not exist. Using the default configuration.", path);
+            Ok(T::default())
+        }
+        result => result,
+    }
+}
@@ -1,3 +1,17 @@
+pub use auth::*;
+pub use graph_database::*;
 pub use graphql::*;
+pub use loader::*;
+pub use policy::*;
+pub use property_journal::*;
+pub use property_validation::*;
+pub use snapshot::*;
 
+pub mod auth;
+pub mod graph_database;
 pub mod graphql;
+pub mod loader;
+pub mod policy;
+pub mod property_journal;
+pub mod property_validation;
+pub mod snapshot;
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::api::Permission;
+#[cfg(not(test))]
+use crate::config::load_required_config;
+use crate::config::ConfigError;
+
+/// A role grants permissions on resources.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoleConfig {
+    /// The name of the role.
+    pub name: String,
+
+    /// The permissions which are granted by the role.
+    #[serde(default = "Vec::new")]
+    pub permissions: Vec<Permission>,
+
+    /// The resources which can be read. A pattern ending with `*` matches all resources with
+    /// the given prefix. A pattern starting with `!` excludes the matching resources.
+    #[serde(default = "default_patterns")]
+    pub read: Vec<String>,
+
+    /// The resources which can be written. Same syntax as `read`.
+    #[serde(default = "default_patterns")]
+    pub write: Vec<String>,
+}
+
+fn default_patterns() -> Vec<String> {
+    vec![String::from("*")]
+}
+
+impl RoleConfig {
+    /// Returns true, if the role grants the given permission on all of the given resources.
+    pub fn permits(&self, permission: Permission, resources: &[String]) -> bool {
+        if !self.permissions.iter().any(|p| *p == Permission::All || *p == permission) {
+            return false;
+        }
+        let patterns = if permission.is_read() { &self.read } else { &self.write };
+        resources.iter().all(|resource| {
+            let excluded = patterns
+                .iter()
+                .filter_map(|pattern| pattern.strip_prefix('!'))
+                .any(|pattern| matches_pattern(pattern, resource));
+            !excluded
+                && patterns
+                    .iter()
+                    .filter(|pattern| !pattern.starts_with('!'))
+                    .any(|pattern| matches_pattern(pattern, resource))
+        })
+    }
+}
+
+/// Matches the given resource against the given pattern. A trailing `*` matches any suffix.
+pub fn matches_pattern(pattern: &str, resource: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => resource.starts_with(prefix),
+        None => pattern == resource,
+    }
+}
+
+/// Unknown keys are rejected, because a misspelled key must not silently weaken the
+/// authorization rules.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// If false, every identity is permitted to do anything.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The roles which are granted to every identity, including the anonymous identity.
+    #[serde(default = "Vec::new")]
+    pub default_roles: Vec<String>,
+
+    /// The roles.
+    #[serde(default = "Vec::new")]
+    pub role: Vec<RoleConfig>,
+}
+
+/// Tests don't restrict access.
+#[cfg(test)]
+pub(crate) fn get_policy_config() -> Result<PolicyConfig, ConfigError> {
+    Ok(PolicyConfig::default())
+}
+
+/// The policy configuration is required. A missing or invalid configuration file must never
+/// silently drop the authorization rules.
+#[cfg(not(test))]
+pub(crate) fn get_policy_config() -> Result<PolicyConfig, ConfigError> {
+    load_required_config("./config/policy.toml")
+}
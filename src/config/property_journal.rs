@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(not(test))]
+use crate::config::load_config;
+use crate::config::ConfigError;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PropertyJournalConfig {
+    /// If true, property changes are recorded in the journal.
+    pub enabled: bool,
+
+    /// The path of the journal file.
+    pub path: String,
+
+    /// The interval in milliseconds in which buffered journal entries are written to disk.
+    #[serde(default = "default_flush_interval")]
+    pub flush_interval: u64,
+
+    /// The interval in seconds in which the entries of committed entity instances are removed
+    /// from the journal file.
+    #[serde(default = "default_compaction_interval")]
+    pub compaction_interval: u64,
+}
+
+fn default_flush_interval() -> u64 {
+    1000
+}
+
+fn default_compaction_interval() -> u64 {
+    60
+}
+
+impl Default for PropertyJournalConfig {
+    fn default() -> Self {
+        PropertyJournalConfig {
+            enabled: false,
+            path: String::from("./data/journal.jsonl"),
+            flush_interval: default_flush_interval(),
+            compaction_interval: default_compaction_interval(),
+        }
+    }
+}
+
+/// Tests never write a journal.
+#[cfg(test)]
+pub(crate) fn get_property_journal_config() -> Result<PropertyJournalConfig, ConfigError> {
+    Ok(PropertyJournalConfig::default())
+}
+
+#[cfg(not(test))]
+pub(crate) fn get_property_journal_config() -> Result<PropertyJournalConfig, ConfigError> {
+    load_config("./config/journal.toml")
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(not(test))]
+use crate::config::load_config;
+use crate::config::ConfigError;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PropertyValidationConfig {
+    /// If true, properties which are not defined by the type or the components of an instance
+    /// are rejected.
+    #[serde(default)]
+    pub strict: bool,
+}
+
+/// Tests run in the lenient mode.
+#[cfg(test)]
+pub(crate) fn get_property_validation_config() -> Result<PropertyValidationConfig, ConfigError> {
+    Ok(PropertyValidationConfig::default())
+}
+
+#[cfg(not(test))]
+pub(crate) fn get_property_validation_config() -> Result<PropertyValidationConfig, ConfigError> {
+    load_config("./config/validation.toml")
+}
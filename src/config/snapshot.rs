@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(not(test))]
+use crate::config::load_config;
+use crate::config::ConfigError;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SnapshotConfig {
+    /// The path of the snapshot file. Files ending with `.cbor` are written as CBOR, all other
+    /// files are written as JSON.
+    pub path: String,
+
+    /// The interval in seconds between two snapshots. No snapshots are taken periodically if no
+    /// interval is given.
+    pub interval: Option<u64>,
+
+    /// If true, the snapshot is restored at startup.
+    #[serde(default)]
+    pub restore_on_startup: bool,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        SnapshotConfig {
+            path: String::from("./data/snapshot.json"),
+            interval: None,
+            restore_on_startup: false,
+        }
+    }
+}
+
+/// Tests never take periodic snapshots.
+#[cfg(test)]
+pub(crate) fn get_snapshot_config() -> Result<SnapshotConfig, ConfigError> {
+    Ok(SnapshotConfig::default())
+}
+
+#[cfg(not(test))]
+pub(crate) fn get_snapshot_config() -> Result<SnapshotConfig, ConfigError> {
+    load_config("./config/snapshot.toml")
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Error, Guard, Result};
+
+use crate::api::get_resources;
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::graphql::query::GraphQLPropertyInstance;
+
+/// Guards a field of the GraphQL schema by a permission.
+pub struct PermissionGuard {
+    permission: Permission,
+}
+
+impl PermissionGuard {
+    pub fn new(permission: Permission) -> Self {
+        PermissionGuard { permission }
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for PermissionGuard {
+    async fn check(&self, context: &Context<'_>) -> Result<()> {
+        check_permission(context, self.permission, &[])
+    }
+}
+
+/// Returns true, if the caller is permitted to access the given resources.
+///
+/// Queries without an identity are denied. Internal callers (for example plugins) execute
+/// queries with the system identity.
+pub fn is_permitted(context: &Context<'_>, permission: Permission, resources: &[String]) -> bool {
+    match (context.data_opt::<Identity>(), context.data_opt::<Arc<dyn AccessControlManager>>()) {
+        (Some(identity), Some(access_control_manager)) => access_control_manager.is_permitted(identity, permission, resources),
+        _ => false,
+    }
+}
+
+/// Checks the permissions of the caller independently of the lifetime of the context. This
+/// is needed for filtering the items of subscription streams.
+#[derive(Clone)]
+pub struct PermissionChecker {
+    caller: Option<(Identity, Arc<dyn AccessControlManager>)>,
+}
+
+impl PermissionChecker {
+    pub fn new(context: &Context<'_>) -> Self {
+        let caller = match (context.data_opt::<Identity>(), context.data_opt::<Arc<dyn AccessControlManager>>()) {
+            (Some(identity), Some(access_control_manager)) => Some((identity.clone(), access_control_manager.clone())),
+            _ => None,
+        };
+        PermissionChecker { caller }
+    }
+
+    /// Returns true, if the caller is permitted to access the given resources.
+    pub fn is_permitted(&self, permission: Permission, resources: &[String]) -> bool {
+        match &self.caller {
+            Some((identity, access_control_manager)) => access_control_manager.is_permitted(identity, permission, resources),
+            None => false,
+        }
+    }
+}
+
+/// Returns an error, if the caller is not permitted to access the given resources.
+pub fn check_permission(context: &Context<'_>, permission: Permission, resources: &[String]) -> Result<()> {
+    match (context.data_opt::<Identity>(), context.data_opt::<Arc<dyn AccessControlManager>>()) {
+        (Some(identity), Some(access_control_manager)) => access_control_manager
+            .check(identity, permission, resources)
+            .map_err(|access_denied| Error::new(access_denied.to_string())),
+        _ => Err(Error::new("Access denied: Missing identity")),
+    }
+}
+
+/// Returns the type name and the label (if any) of an instance which is about to be created with
+/// the given properties.
+pub fn new_instance_resources(type_name: &str, properties: &Option<Vec<GraphQLPropertyInstance>>) -> Vec<String> {
+    let label = properties
+        .iter()
+        .flatten()
+        .find(|property| property.name == "label")
+        .map(|property| &property.value);
+    get_resources(type_name, label)
+}
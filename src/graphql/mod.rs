@@ -1,12 +1,16 @@
-use async_graphql::Schema;
+use async_graphql::{Schema, SchemaBuilder};
 
+pub use guard::*;
 pub use mutation::InexorMutation;
 pub use query::InexorQuery;
 pub use subscription::InexorSubscription;
 
+pub mod guard;
 pub mod mutation;
 pub mod query;
 pub mod subscription;
 
 /// Inexor GraphQL Interface
 pub type InexorSchema = Schema<InexorQuery, InexorMutation, InexorSubscription>;
+
+pub type InexorSchemaBuilder = SchemaBuilder<InexorQuery, InexorMutation, InexorSubscription>;
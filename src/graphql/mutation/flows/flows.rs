@@ -6,11 +6,13 @@ use indradb::EdgeKey;
 use uuid::Uuid;
 
 use crate::api::{
-    EntityTypeManager, ReactiveEntityInstanceManager, ReactiveFlowManager, ReactiveRelationInstanceCreationError, ReactiveRelationInstanceManager,
-    RelationTypeManager,
+    entity_instance_resources, flow_instance_resources, flow_resources, EntityTypeManager, Permission, ReactiveEntityInstanceManager, ReactiveFlowManager,
+    ReactiveRelationInstanceCreationError, ReactiveRelationInstanceManager, RelationTypeManager,
 };
 use crate::graphql::mutation::{GraphQLEdgeKey, GraphQLFlowDefinition};
 use crate::graphql::query::{GraphQLFlow, GraphQLPropertyInstance};
+use crate::graphql::{check_permission, new_instance_resources};
+use crate::model::Flow;
 use crate::model::ReactiveFlow;
 
 #[derive(Debug)]
@@ -95,6 +97,9 @@ impl MutationFlows {
         flow_id: Option<Uuid>,
         properties: Option<Vec<GraphQLPropertyInstance>>,
     ) -> Result<GraphQLFlow> {
+        let resources = new_instance_resources(&type_name, &properties);
+        check_permission(context, Permission::FlowsWrite, &resources)?;
+        check_permission(context, Permission::InstancesWrite, &resources)?;
         let flow_manager = context.data::<Arc<dyn ReactiveFlowManager>>()?;
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?;
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
@@ -148,6 +153,7 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
         flow_manager.commit(flow.id);
         Ok(flow.into())
     }
@@ -170,6 +176,8 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
+        check_permission(context, Permission::InstancesWrite, &new_instance_resources(&type_name, &properties))?;
 
         let entity_type = entity_type_manager.get(type_name.clone());
         if entity_type.is_none() {
@@ -199,12 +207,14 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
 
         let entity_instance = entity_instance_manager.get(entity_id);
         if entity_instance.is_none() {
             return Err(FlowMutationError::MissingEntityInstance(entity_id).into());
         }
         let entity_instance = entity_instance.unwrap();
+        check_permission(context, Permission::InstancesWrite, &entity_instance_resources(&entity_instance))?;
 
         flow.add_entity(entity_instance);
         // No commit necessary _> The entity_instance is registered in the reactive_entity_instance_manager
@@ -222,6 +232,7 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
 
         let entity_instance = entity_instance_manager.get(entity_id);
         if entity_instance.is_none() {
@@ -263,6 +274,8 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
 
         if !flow.has_entity_by_id(edge_key.outbound_id) {
             return Err(FlowMutationError::MissingOutboundEntityInstance(edge_key.outbound_id).into());
@@ -306,6 +319,8 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
 
         let edge_key: EdgeKey = edge_key.into();
         let relation_instance = relation_instance_manager.get(edge_key.clone());
@@ -328,6 +343,7 @@ impl MutationFlows {
             return Err(FlowMutationError::MissingFlow(flow_id).into());
         }
         let flow = flow.unwrap();
+        check_permission(context, Permission::FlowsWrite, &flow_resources(&flow))?;
 
         let edge_key: EdgeKey = edge_key.into();
 
@@ -346,8 +362,11 @@ impl MutationFlows {
     /// Imports the given flow. Creates entity instances and relation instances which are contained
     /// in the given flow.
     async fn import(&self, context: &Context<'_>, flow: GraphQLFlowDefinition) -> Result<GraphQLFlow> {
+        check_permission(context, Permission::FlowsWrite, &[flow.type_name.clone()])?;
+        let flow: Flow = flow.into();
+        check_permission(context, Permission::InstancesWrite, &flow_instance_resources(&flow))?;
         let flow_manager = context.data::<Arc<dyn ReactiveFlowManager>>()?;
-        let flow = flow_manager.create(flow.into())?;
+        let flow = flow_manager.create(flow)?;
         Ok(flow.into())
     }
 }
@@ -4,8 +4,12 @@ use async_graphql::*;
 use log::debug;
 use uuid::Uuid;
 
-use crate::api::{EntityTypeManager, ReactiveEntityInstanceManager, ReactiveRelationInstanceManager};
+use crate::api::{
+    entity_instance_resources, updated_entity_instance_resources, EntityTypeManager, Permission, PropertyValidator, ReactiveEntityInstanceManager,
+    ReactiveRelationInstanceManager,
+};
 use crate::graphql::query::{GraphQLEntityInstance, GraphQLPropertyInstance};
+use crate::graphql::{check_permission, new_instance_resources};
 use crate::model::PropertyInstanceSetter;
 
 #[derive(Default)]
@@ -32,6 +36,7 @@ impl MutationEntityInstances {
         #[graphql(desc = "Creates the entity instance with the given components.")] components: Option<Vec<String>>,
         properties: Option<Vec<GraphQLPropertyInstance>>,
     ) -> Result<GraphQLEntityInstance> {
+        check_permission(context, Permission::InstancesWrite, &new_instance_resources(&type_name, &properties))?;
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?;
 
@@ -42,6 +47,10 @@ impl MutationEntityInstances {
         }
 
         let properties = GraphQLPropertyInstance::to_map_with_defaults(properties, entity_type.unwrap().properties);
+        let property_validator = context.data::<Arc<dyn PropertyValidator>>()?;
+        property_validator
+            .validate_new_entity_instance(type_name.clone(), &components.clone().unwrap_or_default(), &properties)
+            .map_err(|e| Error::new(e.to_string()))?;
 
         let entity_instance = match id {
             Some(id) => entity_instance_manager.create_with_id(type_name, id, properties),
@@ -82,6 +91,19 @@ impl MutationEntityInstances {
             return Err("Entity instance not found!".into());
         }
         let entity_instance = entity_instance.unwrap();
+        check_permission(
+            context,
+            Permission::InstancesWrite,
+            &updated_entity_instance_resources(&entity_instance, &GraphQLPropertyInstance::to_map(properties.clone())),
+        )?;
+        let property_validator = context.data::<Arc<dyn PropertyValidator>>()?;
+        property_validator
+            .validate_entity_instance(
+                &entity_instance,
+                &add_components.clone().unwrap_or_default(),
+                &GraphQLPropertyInstance::to_map(properties.clone()),
+            )
+            .map_err(|e| Error::new(e.to_string()))?;
 
         if let Some(components) = add_components {
             for component in components {
@@ -119,6 +141,7 @@ impl MutationEntityInstances {
             return Err(Error::new(format!("Entity instance {} does not exist!", id)));
         }
         let entity_instance = entity_instance.unwrap();
+        check_permission(context, Permission::InstancesWrite, &entity_instance_resources(&entity_instance))?;
         entity_instance.tick();
         Ok(entity_instance.into())
     }
@@ -131,6 +154,9 @@ impl MutationEntityInstances {
         #[graphql(desc = "If true, all relations to and from the entity instance will be deleted as well")] delete_relations: Option<bool>,
     ) -> Result<bool> {
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
+        if let Some(entity_instance) = entity_instance_manager.get(id) {
+            check_permission(context, Permission::InstancesWrite, &entity_instance_resources(&entity_instance))?;
+        }
         if delete_relations.is_some() && delete_relations.unwrap() {
             let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
             relation_instance_manager.get_by_inbound_entity(id).iter().for_each(|r| {
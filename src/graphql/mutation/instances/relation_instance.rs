@@ -3,7 +3,10 @@ use std::sync::Arc;
 use async_graphql::*;
 use log::debug;
 
-use crate::api::{ReactiveEntityInstanceManager, ReactiveRelationInstanceCreationError, ReactiveRelationInstanceManager, RelationTypeManager};
+use crate::api::{
+    Permission, PropertyValidator, ReactiveEntityInstanceManager, ReactiveRelationInstanceCreationError, ReactiveRelationInstanceManager, RelationTypeManager,
+};
+use crate::graphql::check_permission;
 use crate::graphql::mutation::GraphQLEdgeKey;
 use crate::graphql::query::{GraphQLPropertyInstance, GraphQLRelationInstance};
 use crate::model::PropertyInstanceSetter;
@@ -37,6 +40,7 @@ impl MutationRelationInstances {
         #[graphql(desc = "Creates the relation instance with the given components.")] components: Option<Vec<String>>,
         properties: Option<Vec<GraphQLPropertyInstance>>,
     ) -> Result<GraphQLRelationInstance> {
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
         let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?;
         let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
@@ -55,6 +59,10 @@ impl MutationRelationInstances {
         }
 
         let properties = GraphQLPropertyInstance::to_map_with_defaults(properties, relation_type.unwrap().properties);
+        let property_validator = context.data::<Arc<dyn PropertyValidator>>()?;
+        property_validator
+            .validate_new_relation_instance(edge_key.type_name.clone(), &components.clone().unwrap_or_default(), &properties)
+            .map_err(|e| Error::new(e.to_string()))?;
 
         let relation_instance = match indradb::Identifier::new(edge_key.type_name.clone()) {
             Ok(_) => {
@@ -86,6 +94,7 @@ impl MutationRelationInstances {
         #[graphql(desc = "Removes the components with the given name")] remove_components: Option<Vec<String>>,
         #[graphql(desc = "Updates the given properties")] properties: Option<Vec<GraphQLPropertyInstance>>,
     ) -> Result<GraphQLRelationInstance> {
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
         let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?;
         let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
@@ -108,6 +117,14 @@ impl MutationRelationInstances {
             return Err(Error::new(format!("Relation instance {} does not exist!", edge_key)));
         }
         let relation_instance = relation_instance.unwrap();
+        let property_validator = context.data::<Arc<dyn PropertyValidator>>()?;
+        property_validator
+            .validate_relation_instance(
+                &relation_instance,
+                &add_components.clone().unwrap_or_default(),
+                &GraphQLPropertyInstance::to_map(properties.clone()),
+            )
+            .map_err(|e| Error::new(e.to_string()))?;
 
         if let Some(components) = add_components {
             for component in components {
@@ -139,6 +156,7 @@ impl MutationRelationInstances {
     /// In case of the default_connector it does NOT lead to a new value propagation, because the
     /// reactive streams are not consumed by the default_connector behaviour.
     async fn tick(&self, context: &Context<'_>, edge_key: GraphQLEdgeKey) -> Result<GraphQLRelationInstance> {
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
         let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
         let relation_instance = relation_instance_manager.get(edge_key.clone().into());
         if relation_instance.is_none() {
@@ -151,6 +169,7 @@ impl MutationRelationInstances {
 
     /// Deletes an relation instance.
     async fn delete(&self, context: &Context<'_>, edge_key: GraphQLEdgeKey) -> Result<bool> {
+        check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
         let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?;
         let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
         let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
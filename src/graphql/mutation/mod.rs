@@ -1,30 +1,77 @@
+use std::sync::Arc;
+
 use async_graphql::*;
 
+use crate::api::Permission;
+use crate::api::TransactionManager;
+use crate::graphql::PermissionGuard;
+
 pub use flows::*;
 pub use instances::*;
+pub use plugins::*;
+pub use snapshots::*;
+pub use transactions::*;
 pub use types::*;
 
 pub mod flows;
 pub mod instances;
+pub mod plugins;
+pub mod snapshots;
+pub mod transactions;
 pub mod types;
 
 pub struct InexorMutation;
 
-/// Mutations for the type system, the instances and the flows.
+/// Mutations for the type system, the instances, the flows, the snapshots and the plugins.
 #[Object(name = "Mutation")]
 impl InexorMutation {
     /// Mutations for types (components, entity types, relation types).
+    #[graphql(guard = "PermissionGuard::new(Permission::TypesWrite)")]
     async fn types(&self) -> MutationTypes {
         MutationTypes::default()
     }
 
     /// Mutations for instances (entity instances, relation instances).
+    #[graphql(guard = "PermissionGuard::new(Permission::InstancesWrite)")]
     async fn instances(&self) -> MutationInstances {
         MutationInstances::default()
     }
 
     /// Mutations for flows and their contained instances.
+    #[graphql(guard = "PermissionGuard::new(Permission::FlowsWrite)")]
     async fn flows(&self) -> MutationFlows {
         MutationFlows::default()
     }
+
+    /// Mutations for snapshots of the whole graph.
+    #[graphql(guard = "PermissionGuard::new(Permission::SnapshotsWrite)")]
+    async fn snapshots(&self) -> MutationSnapshots {
+        MutationSnapshots::default()
+    }
+
+    /// Mutations for plugins.
+    #[graphql(guard = "PermissionGuard::new(Permission::PluginsWrite)")]
+    async fn plugins(&self) -> MutationPlugins {
+        MutationPlugins::default()
+    }
+
+    /// Validates and applies the given operations atomically. The operations are applied in
+    /// the given order. If any operation is invalid or fails, none of the operations takes
+    /// effect.
+    ///
+    /// The permissions are checked for each operation.
+    async fn transaction(&self, context: &Context<'_>, operations: Vec<GraphQLTransactionOperation>) -> Result<GraphQLTransactionResult> {
+        let transaction_manager = context.data::<Arc<dyn TransactionManager>>()?;
+        let mut transaction_operations = Vec::new();
+        for (index, operation) in operations.into_iter().enumerate() {
+            let operation = operation
+                .into_operation(context)
+                .map_err(|e| Error::new(format!("Operation {} is invalid: {}", index, e.message)))?;
+            transaction_operations.push(operation);
+        }
+        transaction_manager
+            .execute(transaction_operations)
+            .map(|transaction_result| transaction_result.into())
+            .map_err(|e| Error::new(e.to_string()))
+    }
 }
@@ -0,0 +1,3 @@
+pub use plugins::MutationPlugins;
+
+pub mod plugins;
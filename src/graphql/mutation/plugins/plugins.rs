@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use async_graphql::*;
+
+use crate::api::PluginRegistry;
+use crate::graphql::query::GraphQLPlugin;
+
+#[derive(Default)]
+pub struct MutationPlugins;
+
+/// Mutations for plugins.
+#[Object]
+impl MutationPlugins {
+    /// Loads the plugin with the given name after it has been deactivated or failed to load.
+    async fn activate(&self, context: &Context<'_>, name: String) -> Result<GraphQLPlugin> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>()?;
+        if let Err(e) = plugin_registry.activate_plugin(name.clone()) {
+            return Err(Error::new(format!("Failed to activate plugin {}: {:?}", name, e)));
+        }
+        get_plugin(plugin_registry, name)
+    }
+
+    /// Unloads the plugin with the given name. The components, entity types, relation types,
+    /// behaviours, flow providers and web resources of the plugin are removed.
+    async fn deactivate(&self, context: &Context<'_>, name: String) -> Result<GraphQLPlugin> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>()?;
+        if let Err(e) = plugin_registry.deactivate_plugin(name.clone()) {
+            return Err(Error::new(format!("Failed to deactivate plugin {}: {:?}", name, e)));
+        }
+        get_plugin(plugin_registry, name)
+    }
+
+    /// Unloads the plugin with the given name and loads it again from the same path.
+    async fn reload(&self, context: &Context<'_>, name: String) -> Result<GraphQLPlugin> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>()?;
+        if let Err(e) = plugin_registry.reload_plugin(name.clone()) {
+            return Err(Error::new(format!("Failed to reload plugin {}: {:?}", name, e)));
+        }
+        get_plugin(plugin_registry, name)
+    }
+}
+
+fn get_plugin(plugin_registry: &Arc<dyn PluginRegistry>, name: String) -> Result<GraphQLPlugin> {
+    match plugin_registry.get_plugin_status(name.clone()) {
+        Some(status) => Ok(status.into()),
+        None => Err(Error::new(format!("Plugin {} not found", name))),
+    }
+}
@@ -0,0 +1,5 @@
+pub use snapshot::GraphQLSnapshot;
+pub use snapshots::MutationSnapshots;
+
+pub mod snapshot;
+pub mod snapshots;
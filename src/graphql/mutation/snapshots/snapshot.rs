@@ -0,0 +1,63 @@
+use async_graphql::*;
+
+use crate::api::Snapshot;
+
+pub struct GraphQLSnapshot {
+    path: String,
+    snapshot: Snapshot,
+}
+
+impl GraphQLSnapshot {
+    pub fn new(path: String, snapshot: Snapshot) -> Self {
+        GraphQLSnapshot { path, snapshot }
+    }
+}
+
+/// A snapshot of the whole graph: the type system, the instances and the flows.
+#[Object(name = "Snapshot")]
+impl GraphQLSnapshot {
+    /// The path of the snapshot file.
+    async fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// The version of the snapshot format.
+    async fn version(&self) -> u32 {
+        self.snapshot.version
+    }
+
+    /// The creation time of the snapshot in seconds since the unix epoch.
+    async fn timestamp(&self) -> u64 {
+        self.snapshot.timestamp
+    }
+
+    /// The number of components in the snapshot.
+    async fn components(&self) -> usize {
+        self.snapshot.components.len()
+    }
+
+    /// The number of entity types in the snapshot.
+    async fn entity_types(&self) -> usize {
+        self.snapshot.entity_types.len()
+    }
+
+    /// The number of relation types in the snapshot.
+    async fn relation_types(&self) -> usize {
+        self.snapshot.relation_types.len()
+    }
+
+    /// The number of entity instances in the snapshot.
+    async fn entity_instances(&self) -> usize {
+        self.snapshot.entity_instances.len()
+    }
+
+    /// The number of relation instances in the snapshot.
+    async fn relation_instances(&self) -> usize {
+        self.snapshot.relation_instances.len()
+    }
+
+    /// The number of flows in the snapshot.
+    async fn flows(&self) -> usize {
+        self.snapshot.flows.len()
+    }
+}
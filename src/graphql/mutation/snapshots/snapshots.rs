@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use async_graphql::*;
+
+use crate::api::SnapshotManager;
+use crate::graphql::mutation::GraphQLSnapshot;
+
+#[derive(Default)]
+pub struct MutationSnapshots;
+
+/// Mutations for snapshots of the whole graph.
+#[Object]
+impl MutationSnapshots {
+    /// Writes a snapshot of all components, entity types, relation types, entity instances,
+    /// relation instances and flows to a single file.
+    ///
+    /// The snapshot is written to the file with the given name in the snapshot directory. If no
+    /// name is given, the path configured in `config/snapshot.toml` is used. Files ending with
+    /// `.cbor` are written as CBOR, all other files are written as JSON.
+    async fn create(&self, context: &Context<'_>, name: Option<String>) -> Result<GraphQLSnapshot> {
+        let snapshot_manager = context.data::<Arc<dyn SnapshotManager>>()?;
+        let path = snapshot_manager.get_snapshot_path(name).map_err(|e| Error::new(e.to_string()))?;
+        match snapshot_manager.export(path.clone()) {
+            Ok(snapshot) => Ok(GraphQLSnapshot::new(path, snapshot)),
+            Err(e) => Err(Error::new(e.to_string())),
+        }
+    }
+
+    /// Restores the snapshot with the given name from the snapshot directory.
+    ///
+    /// If no name is given, the path configured in `config/snapshot.toml` is used. Types and
+    /// instances which already exist are kept.
+    async fn restore(&self, context: &Context<'_>, name: Option<String>) -> Result<GraphQLSnapshot> {
+        let snapshot_manager = context.data::<Arc<dyn SnapshotManager>>()?;
+        let path = snapshot_manager.get_snapshot_path(name).map_err(|e| Error::new(e.to_string()))?;
+        match snapshot_manager.import(path.clone()) {
+            Ok(snapshot) => Ok(GraphQLSnapshot::new(path, snapshot)),
+            Err(e) => Err(Error::new(e.to_string())),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use transaction_operation::*;
+pub use transaction_result::GraphQLTransactionResult;
+
+pub mod transaction_operation;
+pub mod transaction_result;
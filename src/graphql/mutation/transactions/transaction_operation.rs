@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_graphql::*;
+use indradb::EdgeKey;
+use indradb::Identifier;
+use uuid::Uuid;
+
+use crate::api::{
+    entity_instance_resources, flow_instance_resources, flow_resources, updated_entity_instance_resources, Permission, ReactiveEntityInstanceManager,
+    ReactiveFlowManager, TransactionOperation,
+};
+use crate::graphql::mutation::{GraphQLEdgeKey, GraphQLFlowDefinition};
+use crate::graphql::query::GraphQLPropertyInstance;
+use crate::graphql::{check_permission, new_instance_resources};
+use crate::model::Flow;
+
+/// Creates an entity instance of the given type.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "CreateEntityInstanceOperation")]
+pub struct GraphQLCreateEntityInstanceOperation {
+    /// The name of the entity type.
+    #[graphql(name = "type")]
+    pub type_name: String,
+
+    /// The id of the entity instance. If none is given a random uuid will be generated.
+    pub id: Option<Uuid>,
+
+    /// Creates the entity instance with the given components.
+    pub components: Option<Vec<String>>,
+
+    /// The initial values of the properties. Missing properties are initialized with the
+    /// default value of their data type.
+    pub properties: Option<Vec<GraphQLPropertyInstance>>,
+}
+
+/// Adds and removes components and sets properties of an entity instance.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "UpdateEntityInstanceOperation")]
+pub struct GraphQLUpdateEntityInstanceOperation {
+    /// The id of the entity instance.
+    pub id: Uuid,
+
+    /// The components to add.
+    pub add_components: Option<Vec<String>>,
+
+    /// The components to remove.
+    pub remove_components: Option<Vec<String>>,
+
+    /// The properties to set.
+    pub properties: Option<Vec<GraphQLPropertyInstance>>,
+}
+
+/// Creates a relation instance with the given edge key.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "CreateRelationInstanceOperation")]
+pub struct GraphQLCreateRelationInstanceOperation {
+    /// The edge key of the relation instance.
+    pub edge_key: GraphQLEdgeKey,
+
+    /// Creates the relation instance with the given components.
+    pub components: Option<Vec<String>>,
+
+    /// The initial values of the properties. Missing properties are initialized with the
+    /// default value of their data type.
+    pub properties: Option<Vec<GraphQLPropertyInstance>>,
+}
+
+/// Adds and removes components and sets properties of a relation instance.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "UpdateRelationInstanceOperation")]
+pub struct GraphQLUpdateRelationInstanceOperation {
+    /// The edge key of the relation instance.
+    pub edge_key: GraphQLEdgeKey,
+
+    /// The components to add.
+    pub add_components: Option<Vec<String>>,
+
+    /// The components to remove.
+    pub remove_components: Option<Vec<String>>,
+
+    /// The properties to set.
+    pub properties: Option<Vec<GraphQLPropertyInstance>>,
+}
+
+/// An operation of a transaction. Exactly one of the fields must be given.
+#[derive(Clone, Debug, InputObject)]
+#[graphql(name = "TransactionOperation")]
+pub struct GraphQLTransactionOperation {
+    /// Creates an entity instance.
+    pub create_entity_instance: Option<GraphQLCreateEntityInstanceOperation>,
+
+    /// Updates an entity instance.
+    pub update_entity_instance: Option<GraphQLUpdateEntityInstanceOperation>,
+
+    /// Deletes the entity instance with the given id. The entity instance must not be
+    /// connected by relation instances which are not deleted before.
+    pub delete_entity_instance: Option<Uuid>,
+
+    /// Creates a relation instance.
+    pub create_relation_instance: Option<GraphQLCreateRelationInstanceOperation>,
+
+    /// Updates a relation instance.
+    pub update_relation_instance: Option<GraphQLUpdateRelationInstanceOperation>,
+
+    /// Deletes the relation instance with the given edge key.
+    pub delete_relation_instance: Option<GraphQLEdgeKey>,
+
+    /// Creates a flow with the contained entity instances and relation instances.
+    pub create_flow: Option<GraphQLFlowDefinition>,
+
+    /// Deletes the flow with the given id.
+    pub delete_flow: Option<Uuid>,
+}
+
+impl GraphQLTransactionOperation {
+    /// Checks the permission of the caller and converts the operation.
+    pub fn into_operation(self, context: &Context<'_>) -> Result<TransactionOperation> {
+        let GraphQLTransactionOperation {
+            create_entity_instance,
+            update_entity_instance,
+            delete_entity_instance,
+            create_relation_instance,
+            update_relation_instance,
+            delete_relation_instance,
+            create_flow,
+            delete_flow,
+        } = self;
+        let mut operations = Vec::new();
+        if let Some(operation) = create_entity_instance {
+            check_permission(context, Permission::InstancesWrite, &new_instance_resources(&operation.type_name, &operation.properties))?;
+            operations.push(TransactionOperation::CreateEntityInstance {
+                type_name: operation.type_name,
+                id: operation.id,
+                components: operation.components.unwrap_or_default(),
+                properties: GraphQLPropertyInstance::to_map(operation.properties),
+            });
+        }
+        if let Some(operation) = update_entity_instance {
+            let properties = GraphQLPropertyInstance::to_map(operation.properties);
+            check_permission(
+                context,
+                Permission::InstancesWrite,
+                &updated_existing_entity_instance_resources(context, operation.id, &properties)?,
+            )?;
+            operations.push(TransactionOperation::UpdateEntityInstance {
+                id: operation.id,
+                add_components: operation.add_components.unwrap_or_default(),
+                remove_components: operation.remove_components.unwrap_or_default(),
+                properties,
+            });
+        }
+        if let Some(id) = delete_entity_instance {
+            check_permission(context, Permission::InstancesWrite, &existing_entity_instance_resources(context, id)?)?;
+            operations.push(TransactionOperation::DeleteEntityInstance { id });
+        }
+        if let Some(operation) = create_relation_instance {
+            check_permission(context, Permission::InstancesWrite, &[operation.edge_key.type_name.clone()])?;
+            operations.push(TransactionOperation::CreateRelationInstance {
+                edge_key: to_edge_key(operation.edge_key)?,
+                components: operation.components.unwrap_or_default(),
+                properties: GraphQLPropertyInstance::to_map(operation.properties),
+            });
+        }
+        if let Some(operation) = update_relation_instance {
+            check_permission(context, Permission::InstancesWrite, &[operation.edge_key.type_name.clone()])?;
+            operations.push(TransactionOperation::UpdateRelationInstance {
+                edge_key: to_edge_key(operation.edge_key)?,
+                add_components: operation.add_components.unwrap_or_default(),
+                remove_components: operation.remove_components.unwrap_or_default(),
+                properties: GraphQLPropertyInstance::to_map(operation.properties),
+            });
+        }
+        if let Some(edge_key) = delete_relation_instance {
+            check_permission(context, Permission::InstancesWrite, &[edge_key.type_name.clone()])?;
+            operations.push(TransactionOperation::DeleteRelationInstance {
+                edge_key: to_edge_key(edge_key)?,
+            });
+        }
+        if let Some(flow) = create_flow {
+            check_permission(context, Permission::FlowsWrite, &[flow.type_name.clone()])?;
+            let flow: Flow = flow.into();
+            check_permission(context, Permission::InstancesWrite, &flow_instance_resources(&flow))?;
+            operations.push(TransactionOperation::CreateFlow { flow });
+        }
+        if let Some(id) = delete_flow {
+            let flow_manager = context.data::<Arc<dyn ReactiveFlowManager>>()?;
+            let resources = flow_manager.get(id).map(|flow| flow_resources(&flow)).unwrap_or_default();
+            check_permission(context, Permission::FlowsWrite, &resources)?;
+            operations.push(TransactionOperation::DeleteFlow { id });
+        }
+        match operations.len() {
+            1 => Ok(operations.remove(0)),
+            _ => Err(Error::new("Exactly one operation must be given")),
+        }
+    }
+}
+
+/// Returns the resources of the existing entity instance. Entity instances which are created
+/// within the same transaction have no resources yet.
+fn existing_entity_instance_resources(context: &Context<'_>, id: Uuid) -> Result<Vec<String>> {
+    let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
+    Ok(entity_instance_manager
+        .get(id)
+        .map(|entity_instance| entity_instance_resources(&entity_instance))
+        .unwrap_or_default())
+}
+
+/// Returns the resources of the existing entity instance and the new label (if any) which is
+/// set by the update.
+fn updated_existing_entity_instance_resources(context: &Context<'_>, id: Uuid, properties: &HashMap<String, serde_json::Value>) -> Result<Vec<String>> {
+    let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
+    Ok(match entity_instance_manager.get(id) {
+        Some(entity_instance) => updated_entity_instance_resources(&entity_instance, properties),
+        None => properties
+            .get("label")
+            .and_then(|label| label.as_str())
+            .map(|label| vec![label.to_string()])
+            .unwrap_or_default(),
+    })
+}
+
+fn to_edge_key(edge_key: GraphQLEdgeKey) -> Result<EdgeKey> {
+    if Identifier::new(edge_key.type_name.clone()).is_err() {
+        return Err(Error::new(format!("Invalid relation type name {}", edge_key.type_name)));
+    }
+    Ok(edge_key.into())
+}
@@ -0,0 +1,42 @@
+use async_graphql::*;
+
+use crate::api::TransactionResult;
+use crate::graphql::query::{GraphQLEntityInstance, GraphQLFlow, GraphQLRelationInstance};
+
+pub struct GraphQLTransactionResult {
+    transaction_result: TransactionResult,
+}
+
+/// The entity instances, relation instances and flows which have been created or updated by
+/// a transaction.
+#[Object(name = "TransactionResult")]
+impl GraphQLTransactionResult {
+    /// The created and updated entity instances.
+    async fn entity_instances(&self) -> Vec<GraphQLEntityInstance> {
+        self.transaction_result
+            .entity_instances
+            .iter()
+            .map(|entity_instance| entity_instance.clone().into())
+            .collect()
+    }
+
+    /// The created and updated relation instances.
+    async fn relation_instances(&self) -> Vec<GraphQLRelationInstance> {
+        self.transaction_result
+            .relation_instances
+            .iter()
+            .map(|relation_instance| relation_instance.clone().into())
+            .collect()
+    }
+
+    /// The created flows.
+    async fn flows(&self) -> Vec<GraphQLFlow> {
+        self.transaction_result.flows.iter().map(|flow| flow.clone().into()).collect()
+    }
+}
+
+impl From<TransactionResult> for GraphQLTransactionResult {
+    fn from(transaction_result: TransactionResult) -> Self {
+        GraphQLTransactionResult { transaction_result }
+    }
+}
@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use async_graphql::*;
 
-use crate::api::ComponentManager;
+use crate::api::{compile_patterns, ComponentManager, Permission};
+use crate::graphql::check_permission;
 use crate::graphql::mutation::PropertyTypeDefinition;
 use crate::graphql::query::GraphQLComponent;
 
@@ -14,17 +15,20 @@ pub struct MutationComponents;
 impl MutationComponents {
     /// Creates a new component with the given name and properties.
     async fn create(&self, context: &Context<'_>, name: String, properties: Option<Vec<PropertyTypeDefinition>>) -> Result<GraphQLComponent> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let component_manager = context.data::<Arc<dyn ComponentManager>>()?;
         let property_types = match properties {
             Some(properties) => properties.iter().map(|property| property.clone().into()).collect(),
             None => Vec::new(),
         };
         let component = crate::model::Component::new(name, property_types);
+        compile_patterns(&component.properties).map_err(|e| Error::new(e.to_string()))?;
         component_manager.register(component.clone());
         Ok(component.into())
     }
 
     async fn delete(&self, context: &Context<'_>, name: String) -> Result<bool> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let component_manager = context.data::<Arc<dyn ComponentManager>>()?;
         component_manager.delete(name);
         Ok(true)
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use async_graphql::*;
 use log::debug;
 
-use crate::api::EntityTypeManager;
+use crate::api::{compile_patterns, EntityTypeManager, Permission};
 use crate::builder::EntityTypeBuilder;
+use crate::graphql::check_permission;
 use crate::graphql::mutation::PropertyTypeDefinition;
 use crate::graphql::query::{GraphQLEntityType, GraphQLExtension};
 
@@ -26,6 +27,7 @@ impl MutationEntityTypes {
         >,
         #[graphql(desc = "The extension on the entity type.")] extensions: Option<Vec<GraphQLExtension>>,
     ) -> Result<GraphQLEntityType> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?;
 
         if entity_type_manager.has(name.clone()) {
@@ -56,6 +58,7 @@ impl MutationEntityTypes {
         }
 
         let entity_type = entity_type_builder.build();
+        compile_patterns(&entity_type.properties).map_err(|e| Error::new(e.to_string()))?;
         entity_type_manager.register(entity_type.clone());
         Ok(entity_type.into())
     }
@@ -69,6 +72,7 @@ impl MutationEntityTypes {
 
     /// Deletes the entity type with the given name.
     async fn delete(&self, context: &Context<'_>, name: String) -> Result<bool> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?;
         entity_type_manager.delete(name);
         Ok(true)
@@ -1,8 +1,48 @@
 use async_graphql::*;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+use crate::api::{PropertyConstraints, PROPERTY_CONSTRAINTS_EXTENSION};
 use crate::graphql::query::{GraphQLDataType, GraphQLExtension, GraphQLSocketType};
-use crate::model::PropertyType;
+use crate::model::{Extension, PropertyType};
+
+/// The constraints of a property type.
+#[derive(Serialize, Deserialize, Clone, Debug, InputObject)]
+#[graphql(name = "PropertyConstraintsDefinition")]
+pub struct PropertyConstraintsDefinition {
+    /// If true, the property must be given when an instance is created.
+    #[serde(default)]
+    #[graphql(default)]
+    pub required: bool,
+
+    /// The default value which is used instead of the default value of the data type.
+    pub default_value: Option<Value>,
+
+    /// The minimum of a number.
+    pub minimum: Option<f64>,
+
+    /// The maximum of a number.
+    pub maximum: Option<f64>,
+
+    /// The regular expression which a string has to match.
+    pub pattern: Option<String>,
+
+    /// The allowed values.
+    pub values: Option<Vec<Value>>,
+}
+
+impl From<PropertyConstraintsDefinition> for PropertyConstraints {
+    fn from(constraints: PropertyConstraintsDefinition) -> Self {
+        PropertyConstraints {
+            required: constraints.required,
+            default: constraints.default_value,
+            minimum: constraints.minimum,
+            maximum: constraints.maximum,
+            pattern: constraints.pattern,
+            values: constraints.values,
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, InputObject)]
 #[graphql(name = "PropertyTypeDefinition")]
@@ -23,16 +63,27 @@ pub struct PropertyTypeDefinition {
     /// Property specific extensions
     #[serde(default = "Vec::new")]
     pub extensions: Vec<GraphQLExtension>,
+
+    /// The constraints of the property. Replaces the extension `property_constraints`.
+    pub constraints: Option<PropertyConstraintsDefinition>,
 }
 
 impl From<PropertyTypeDefinition> for PropertyType {
     fn from(property_type: PropertyTypeDefinition) -> Self {
+        let mut extensions: Vec<Extension> = property_type.extensions.iter().map(|extension| extension.clone().into()).collect();
+        if let Some(constraints) = property_type.constraints {
+            extensions.retain(|extension| extension.name != PROPERTY_CONSTRAINTS_EXTENSION);
+            extensions.push(Extension {
+                name: PROPERTY_CONSTRAINTS_EXTENSION.to_string(),
+                extension: serde_json::to_value(PropertyConstraints::from(constraints)).unwrap_or_default(),
+            });
+        }
         PropertyType {
             name: property_type.name,
             description: property_type.description,
             data_type: property_type.data_type.into(),
             socket_type: property_type.socket_type.into(),
-            extensions: property_type.extensions.iter().map(|extension| extension.clone().into()).collect(),
+            extensions,
         }
     }
 }
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use async_graphql::*;
 use log::debug;
 
-use crate::api::{EntityTypeManager, RelationTypeManager};
+use crate::api::{compile_patterns, EntityTypeManager, Permission, RelationTypeManager};
 use crate::builder::RelationTypeBuilder;
+use crate::graphql::check_permission;
 use crate::graphql::mutation::PropertyTypeDefinition;
 use crate::graphql::query::{GraphQLExtension, GraphQLRelationType};
 
@@ -29,6 +30,7 @@ impl MutationRelationTypes {
         >,
         #[graphql(desc = "The extension on the relation type.")] extensions: Option<Vec<GraphQLExtension>>,
     ) -> Result<GraphQLRelationType> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?;
         let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?;
 
@@ -63,6 +65,7 @@ impl MutationRelationTypes {
         }
 
         let relation_type = relation_type_builder.build();
+        compile_patterns(&relation_type.properties).map_err(|e| Error::new(e.to_string()))?;
         relation_type_manager.register(relation_type.clone());
         Ok(relation_type.into())
     }
@@ -99,6 +102,7 @@ impl MutationRelationTypes {
 
     /// Deletes the relation type with the given name.
     async fn delete(&self, context: &Context<'_>, name: String) -> Result<bool> {
+        check_permission(context, Permission::TypesWrite, &[name.clone()])?;
         let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?;
         relation_type_manager.delete(name);
         Ok(true)
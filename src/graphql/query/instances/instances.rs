@@ -3,7 +3,8 @@ use std::sync::Arc;
 use async_graphql::*;
 use uuid::Uuid;
 
-use crate::api::{ReactiveEntityInstanceManager, ReactiveRelationInstanceManager};
+use crate::api::{entity_instance_resources, Permission, ReactiveEntityInstanceManager, ReactiveRelationInstanceManager};
+use crate::graphql::is_permitted;
 use crate::graphql::query::{GraphQLEntityInstance, GraphQLPropertyInstance, GraphQLRelationInstance};
 
 #[derive(Default)]
@@ -31,10 +32,13 @@ impl Instances {
         if entity_instance_manager.is_ok() {
             let entity_instance_manager = entity_instance_manager.unwrap();
             if id.is_some() {
-                let entity_instance = entity_instance_manager.get(id.unwrap()).map(|entity_instance| {
-                    let entity_instance: GraphQLEntityInstance = entity_instance.into();
-                    entity_instance
-                });
+                let entity_instance = entity_instance_manager
+                    .get(id.unwrap())
+                    .filter(|entity_instance| is_permitted(context, Permission::InstancesRead, &entity_instance_resources(entity_instance)))
+                    .map(|entity_instance| {
+                        let entity_instance: GraphQLEntityInstance = entity_instance.into();
+                        entity_instance
+                    });
                 return if entity_instance.is_some() {
                     vec![entity_instance.unwrap()]
                 } else {
@@ -42,10 +46,13 @@ impl Instances {
                 };
             }
             if label.is_some() {
-                let entity_instance = entity_instance_manager.get_by_label(label.unwrap()).map(|entity_instance| {
-                    let entity_instance: GraphQLEntityInstance = entity_instance.into();
-                    entity_instance
-                });
+                let entity_instance = entity_instance_manager
+                    .get_by_label(label.unwrap())
+                    .filter(|entity_instance| is_permitted(context, Permission::InstancesRead, &entity_instance_resources(entity_instance)))
+                    .map(|entity_instance| {
+                        let entity_instance: GraphQLEntityInstance = entity_instance.into();
+                        entity_instance
+                    });
                 return if entity_instance.is_some() {
                     vec![entity_instance.unwrap()]
                 } else {
@@ -56,6 +63,7 @@ impl Instances {
                 .get_entity_instances()
                 .iter()
                 .filter(|entity_instance| entity_type.is_none() || entity_type.clone().unwrap() == entity_instance.type_name.clone())
+                .filter(|entity_instance| is_permitted(context, Permission::InstancesRead, &entity_instance_resources(entity_instance)))
                 .filter(|entity_instance| {
                     components.is_none() || {
                         let components = components.clone().unwrap();
@@ -124,6 +132,7 @@ impl Instances {
                 .filter(|relation_instance| inbound_type.is_none() || inbound_type.clone().unwrap() == relation_instance.inbound.clone().type_name.clone())
                 .filter(|relation_instance| outbound_id.is_none() || outbound_id.unwrap() == relation_instance.outbound.id)
                 .filter(|relation_instance| inbound_id.is_none() || inbound_id.unwrap() == relation_instance.inbound.id)
+                .filter(|relation_instance| is_permitted(context, Permission::InstancesRead, &[relation_instance.type_name.clone()]))
                 .filter(|relation_instance| {
                     components.is_none() || {
                         let components = components.clone().unwrap();
@@ -1,4 +1,4 @@
-use crate::api::{EntityTypeManager, RelationTypeManager};
+use crate::api::{with_default_values, EntityTypeManager, RelationTypeManager};
 use crate::graphql::query::GraphQLPropertyType;
 use async_graphql::{Context, InputObject, Object};
 use inexor_rgf_core_model::PropertyType;
@@ -116,16 +116,9 @@ impl GraphQLPropertyInstance {
         }
     }
 
+    /// Returns the given properties completed by the default values of the missing properties
+    /// which are not required.
     pub fn to_map_with_defaults(properties: Option<Vec<GraphQLPropertyInstance>>, property_types: Vec<PropertyType>) -> HashMap<String, Value> {
-        let mut props = HashMap::new();
-        for property_type in property_types {
-            props.insert(property_type.name.clone(), property_type.data_type.default_value());
-        }
-        if let Some(properties) = properties {
-            for property in properties {
-                props.insert(property.name.clone(), property.value.clone());
-            }
-        }
-        props
+        with_default_values(&property_types, GraphQLPropertyInstance::to_map(properties))
     }
 }
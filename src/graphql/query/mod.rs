@@ -5,32 +5,46 @@ use uuid::Uuid;
 
 pub use flows::*;
 pub use instances::*;
+pub use plugins::*;
+pub use system_events::*;
 pub use types::*;
 
+use crate::api::flow_resources;
+use crate::api::Permission;
+use crate::api::PluginRegistry;
 use crate::api::ReactiveFlowManager;
+use crate::api::SystemEventManager;
+use crate::api::SystemEventTypes;
+use crate::graphql::is_permitted;
 use crate::graphql::query::Instances;
 use crate::graphql::query::Types;
+use crate::graphql::PermissionGuard;
 
 pub mod flows;
 pub mod instances;
+pub mod plugins;
+pub mod system_events;
 pub mod types;
 
 pub struct InexorQuery;
 
-/// Search queries for the type system, the instances and the flows.
+/// Search queries for the type system, the instances, the flows, the plugins and the system events.
 #[Object(name = "Query")]
 impl InexorQuery {
     /// Search for types (components, entity types, relation types).
+    #[graphql(guard = "PermissionGuard::new(Permission::TypesRead)")]
     async fn types(&self) -> Types {
         Types::default()
     }
 
     /// Search for instances (entity instances, relation instances).
+    #[graphql(guard = "PermissionGuard::new(Permission::InstancesRead)")]
     async fn instances(&self) -> Instances {
         Instances::default()
     }
 
     /// Search for flows and their contained instances.
+    #[graphql(guard = "PermissionGuard::new(Permission::FlowsRead)")]
     async fn flows(
         &self,
         context: &Context<'_>,
@@ -40,22 +54,30 @@ impl InexorQuery {
     ) -> Vec<GraphQLFlow> {
         if let Ok(flow_manager) = context.data::<Arc<dyn ReactiveFlowManager>>() {
             if id.is_some() {
-                return match flow_manager.get(id.unwrap()).map(|flow| flow.into()) {
+                return match flow_manager
+                    .get(id.unwrap())
+                    .filter(|flow| is_permitted(context, Permission::FlowsRead, &flow_resources(flow)))
+                    .map(|flow| flow.into())
+                {
                     Some(flow) => vec![flow],
                     None => Vec::new(),
                 };
             }
             if label.is_some() {
-                let flow = flow_manager.get_by_label(label.unwrap()).map(|flow| {
-                    let flow: GraphQLFlow = flow.into();
-                    flow
-                });
+                let flow = flow_manager
+                    .get_by_label(label.unwrap())
+                    .filter(|flow| is_permitted(context, Permission::FlowsRead, &flow_resources(flow)))
+                    .map(|flow| {
+                        let flow: GraphQLFlow = flow.into();
+                        flow
+                    });
                 return if flow.is_some() { vec![flow.unwrap()] } else { Vec::new() };
             }
             return flow_manager
                 .get_all()
                 .iter()
                 .filter(|flow| flow_type.is_none() || flow_type.clone().unwrap() == flow.type_name)
+                .filter(|flow| is_permitted(context, Permission::FlowsRead, &flow_resources(flow)))
                 .map(|flow| {
                     let flow: GraphQLFlow = flow.clone().into();
                     flow
@@ -64,4 +86,40 @@ impl InexorQuery {
         }
         Vec::new()
     }
+
+    /// Search for plugins.
+    #[graphql(guard = "PermissionGuard::new(Permission::PluginsRead)")]
+    async fn plugins(&self, context: &Context<'_>, #[graphql(desc = "Filters by the name of the plugin")] name: Option<String>) -> Vec<GraphQLPlugin> {
+        if let Ok(plugin_registry) = context.data::<Arc<dyn PluginRegistry>>() {
+            return plugin_registry
+                .get_plugin_statuses()
+                .into_iter()
+                .filter(|status| name.is_none() || name.clone().unwrap() == status.name)
+                .map(|status| status.into())
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// Returns the recorded system events. Only the most recent system events are kept.
+    async fn system_events(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Returns only the events which happened at or after the given timestamp (milliseconds since the unix epoch)")] since: Option<u64>,
+        #[graphql(desc = "Filters by the types of the events")] types: Option<Vec<GraphQLSystemEventType>>,
+    ) -> Vec<GraphQLSystemEvent> {
+        if let Ok(event_manager) = context.data::<Arc<dyn SystemEventManager>>() {
+            let event_types: Vec<SystemEventTypes> = types.unwrap_or_default().into_iter().map(|event_type| event_type.into()).collect();
+            return event_manager
+                .get_system_events(since, &event_types)
+                .into_iter()
+                .filter(|record| {
+                    let (permission, resources) = get_system_event_permission(record);
+                    is_permitted(context, permission, &resources)
+                })
+                .map(GraphQLSystemEvent::from)
+                .collect();
+        }
+        Vec::new()
+    }
 }
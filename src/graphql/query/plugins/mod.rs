@@ -0,0 +1,7 @@
+pub use plugin::*;
+pub use plugin_load_error_kind::*;
+pub use plugin_state::*;
+
+pub mod plugin;
+pub mod plugin_load_error_kind;
+pub mod plugin_state;
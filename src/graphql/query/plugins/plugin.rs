@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use async_graphql::*;
+
+use crate::api::ComponentManager;
+use crate::api::EntityTypeManager;
+use crate::api::PluginRegistry;
+use crate::api::ReactiveFlowManager;
+use crate::api::RelationTypeManager;
+use crate::graphql::query::{GraphQLComponent, GraphQLEntityType, GraphQLFlow, GraphQLPluginLoadErrorKind, GraphQLPluginState, GraphQLRelationType};
+use crate::plugin::{PluginContributions, PluginStatus};
+use crate::plugins::Plugin;
+
+pub struct GraphQLPlugin {
+    status: PluginStatus,
+}
+
+impl GraphQLPlugin {
+    fn get_contributions(&self, context: &Context<'_>) -> Option<PluginContributions> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>().ok()?;
+        plugin_registry.get_contributions(self.status.name.clone())
+    }
+}
+
+/// A plugin is a dynamically linked library which contributes components, entity types,
+/// relation types, behaviours, flows and web resources.
+#[Object(name = "Plugin")]
+impl GraphQLPlugin {
+    /// The name of the plugin.
+    async fn name(&self) -> String {
+        self.status.name.clone()
+    }
+
+    /// The path of the library which contains the plugin.
+    async fn path(&self) -> String {
+        self.status.path.clone()
+    }
+
+    /// The version of the plugin if the plugin is loaded.
+    async fn version(&self, context: &Context<'_>) -> Option<String> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>().ok()?;
+        let plugin_proxy = plugin_registry.get(self.status.name.clone())?;
+        plugin_proxy.metadata().ok().map(|metadata| metadata.version)
+    }
+
+    /// The description of the plugin if the plugin is loaded.
+    async fn description(&self, context: &Context<'_>) -> Option<String> {
+        let plugin_registry = context.data::<Arc<dyn PluginRegistry>>().ok()?;
+        let plugin_proxy = plugin_registry.get(self.status.name.clone())?;
+        plugin_proxy.metadata().ok().map(|metadata| metadata.description)
+    }
+
+    /// The state of the plugin.
+    async fn state(&self) -> GraphQLPluginState {
+        self.status.state.into()
+    }
+
+    /// The kind of error if the plugin failed to load or to initialize.
+    async fn error_kind(&self) -> Option<GraphQLPluginLoadErrorKind> {
+        self.status.error.as_ref().map(|error| error.kind().into())
+    }
+
+    /// The error message if the plugin failed to load or to initialize.
+    async fn error(&self) -> Option<String> {
+        self.status.error.as_ref().map(|error| error.to_string())
+    }
+
+    /// The components contributed by the plugin.
+    async fn components(&self, context: &Context<'_>) -> Vec<GraphQLComponent> {
+        let contributions = self.get_contributions(context);
+        match (contributions, context.data::<Arc<dyn ComponentManager>>()) {
+            (Some(contributions), Ok(component_manager)) => contributions
+                .components
+                .into_iter()
+                .filter_map(|name| component_manager.get(name))
+                .map(|component| component.into())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The entity types contributed by the plugin.
+    async fn entity_types(&self, context: &Context<'_>) -> Vec<GraphQLEntityType> {
+        let contributions = self.get_contributions(context);
+        match (contributions, context.data::<Arc<dyn EntityTypeManager>>()) {
+            (Some(contributions), Ok(entity_type_manager)) => contributions
+                .entity_types
+                .into_iter()
+                .filter_map(|name| entity_type_manager.get(name))
+                .map(|entity_type| entity_type.into())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The relation types contributed by the plugin.
+    async fn relation_types(&self, context: &Context<'_>) -> Vec<GraphQLRelationType> {
+        let contributions = self.get_contributions(context);
+        match (contributions, context.data::<Arc<dyn RelationTypeManager>>()) {
+            (Some(contributions), Ok(relation_type_manager)) => contributions
+                .relation_types
+                .into_iter()
+                .filter_map(|type_name| relation_type_manager.get(type_name))
+                .map(|relation_type| relation_type.into())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The kinds of behaviours (component, entity, relation) contributed by the plugin.
+    async fn behaviours(&self, context: &Context<'_>) -> Vec<String> {
+        let mut behaviours = Vec::new();
+        if let Some(contributions) = self.get_contributions(context) {
+            if contributions.component_behaviour_provider.is_some() {
+                behaviours.push(String::from("component"));
+            }
+            if contributions.entity_behaviour_provider.is_some() {
+                behaviours.push(String::from("entity"));
+            }
+            if contributions.relation_behaviour_provider.is_some() {
+                behaviours.push(String::from("relation"));
+            }
+        }
+        behaviours
+    }
+
+    /// The flows contributed by the plugin.
+    async fn flows(&self, context: &Context<'_>) -> Vec<GraphQLFlow> {
+        let contributions = self.get_contributions(context);
+        match (contributions, context.data::<Arc<dyn ReactiveFlowManager>>()) {
+            (Some(contributions), Ok(flow_manager)) => contributions
+                .flows
+                .into_iter()
+                .filter_map(|id| flow_manager.get(id))
+                .map(|flow| flow.into())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The base paths of the web resources contributed by the plugin.
+    async fn web_resources(&self, context: &Context<'_>) -> Vec<String> {
+        self.get_contributions(context)
+            .map(|contributions| contributions.web_resources)
+            .unwrap_or_default()
+    }
+}
+
+impl From<PluginStatus> for GraphQLPlugin {
+    fn from(status: PluginStatus) -> Self {
+        GraphQLPlugin { status }
+    }
+}
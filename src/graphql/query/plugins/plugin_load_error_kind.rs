@@ -0,0 +1,33 @@
+use async_graphql::*;
+
+/// The kind of error which prevented a plugin from being loaded or initialized.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[graphql(name = "PluginLoadErrorKind", remote = "crate::plugin::PluginLoadErrorKind")]
+pub enum GraphQLPluginLoadErrorKind {
+    /// The library file doesn't exist.
+    MissingFile,
+
+    /// The library exists but couldn't be loaded.
+    LibraryError,
+
+    /// The library doesn't export the `plugin_declaration` symbol.
+    MissingDeclaration,
+
+    /// The library doesn't contain a plugin with the expected name.
+    PluginNotFound,
+
+    /// The library has been compiled with another version of rustc.
+    RustcVersionMismatch,
+
+    /// The library has been compiled against another version of the plugin API.
+    ApiVersionMismatch,
+
+    /// The plugin depends on plugins which are not loaded.
+    UnmetDependencies,
+
+    /// The plugin failed to initialize.
+    InitFailed,
+
+    /// The plugin failed to post-initialize.
+    PostInitFailed,
+}
@@ -0,0 +1,18 @@
+use async_graphql::*;
+
+/// The state of a plugin.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[graphql(name = "PluginState", remote = "crate::plugin::PluginState")]
+pub enum GraphQLPluginState {
+    /// The library has been loaded but the plugin hasn't been initialized yet.
+    Loaded,
+
+    /// The plugin has been initialized and its providers have been registered.
+    Initialized,
+
+    /// The plugin failed to load or to initialize.
+    Failed,
+
+    /// The plugin is not active or has been deactivated.
+    Deactivated,
+}
@@ -0,0 +1,7 @@
+pub use system_event::*;
+pub use system_event_source::*;
+pub use system_event_type::*;
+
+pub mod system_event;
+pub mod system_event_source;
+pub mod system_event_type;
@@ -0,0 +1,102 @@
+use async_graphql::*;
+use serde_json::Value;
+
+use crate::api::get_resources;
+use crate::api::Permission;
+use crate::api::SystemEventRecord;
+use crate::api::SystemEventTypes;
+use crate::api::SHUTDOWN;
+use crate::graphql::query::GraphQLSystemEventSource;
+use crate::graphql::query::GraphQLSystemEventType;
+
+pub struct GraphQLSystemEvent {
+    record: SystemEventRecord,
+}
+
+/// A system event which has been recorded in the event history.
+#[Object(name = "SystemEvent")]
+impl GraphQLSystemEvent {
+    /// The sequence number of the event. The sequence number increases with each event.
+    async fn sequence(&self) -> u64 {
+        self.record.sequence
+    }
+
+    /// The point in time of the event in milliseconds since the unix epoch.
+    async fn timestamp(&self) -> u64 {
+        self.record.timestamp
+    }
+
+    /// The type of the event.
+    #[graphql(name = "type")]
+    async fn event_type(&self) -> GraphQLSystemEventType {
+        self.record.event_type.into()
+    }
+
+    /// The name of the type, the id of the instance or the edge key of the relation instance.
+    async fn subject(&self) -> Value {
+        self.record.subject.clone()
+    }
+
+    /// The origin of the event.
+    async fn source(&self) -> GraphQLSystemEventSource {
+        self.record.source.into()
+    }
+
+    /// The deleted type or instance.
+    async fn before(&self) -> Option<Value> {
+        self.record.before.clone()
+    }
+
+    /// The created type or instance.
+    async fn after(&self) -> Option<Value> {
+        self.record.after.clone()
+    }
+}
+
+impl From<SystemEventRecord> for GraphQLSystemEvent {
+    fn from(record: SystemEventRecord) -> Self {
+        GraphQLSystemEvent { record }
+    }
+}
+
+/// Returns the permission and the resources which are required to read the given system event.
+///
+/// The resources are the name of the type or plugin or the type name and the label of the
+/// instance or flow. The type name and the label are taken from the state before or after the
+/// event.
+pub fn get_system_event_permission(record: &SystemEventRecord) -> (Permission, Vec<String>) {
+    let permission = match record.event_type {
+        SystemEventTypes::ComponentCreated
+        | SystemEventTypes::ComponentDeleted
+        | SystemEventTypes::EntityTypeCreated
+        | SystemEventTypes::EntityTypeDeleted
+        | SystemEventTypes::RelationTypeCreated
+        | SystemEventTypes::RelationTypeDeleted
+        | SystemEventTypes::EntityTypeUpdated => {
+            return (Permission::TypesRead, record.subject.as_str().map(|name| vec![name.to_string()]).unwrap_or_default());
+        }
+        SystemEventTypes::EntityInstanceCreated
+        | SystemEventTypes::EntityInstanceDeleted
+        | SystemEventTypes::RelationInstanceCreated
+        | SystemEventTypes::RelationInstanceDeleted
+        | SystemEventTypes::EntityComponentAdded
+        | SystemEventTypes::EntityComponentRemoved
+        | SystemEventTypes::RelationComponentAdded
+        | SystemEventTypes::RelationComponentRemoved => Permission::InstancesRead,
+        SystemEventTypes::FlowCreated | SystemEventTypes::FlowDeleted | SystemEventTypes::FlowCommitted => Permission::FlowsRead,
+        SystemEventTypes::PluginLoaded | SystemEventTypes::PluginUnloaded | SystemEventTypes::PluginFailed => {
+            return (Permission::PluginsRead, record.subject.as_str().map(|name| vec![name.to_string()]).unwrap_or_default());
+        }
+        SystemEventTypes::ShutdownRequested => return (Permission::InstancesRead, vec![SHUTDOWN.to_string()]),
+    };
+    let resources = record
+        .after
+        .as_ref()
+        .or(record.before.as_ref())
+        .and_then(|state| {
+            let type_name = state.get("type_name")?.as_str()?;
+            Some(get_resources(type_name, state.get("properties").and_then(|properties| properties.get("label"))))
+        })
+        .unwrap_or_default();
+    (permission, resources)
+}
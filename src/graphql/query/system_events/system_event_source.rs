@@ -0,0 +1,22 @@
+use async_graphql::*;
+
+/// The origin of a system event.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[graphql(name = "SystemEventSource", remote = "crate::api::SystemEventSource")]
+pub enum GraphQLSystemEventSource {
+    /// The event was caused by the application itself.
+    Core,
+
+    /// The event was caused by a GraphQL query.
+    #[graphql(name = "GRAPHQL")]
+    GraphQL,
+
+    /// The event was caused by a request of the REST API.
+    Rest,
+
+    /// The event was caused by a plugin.
+    Plugin,
+
+    /// The event was caused by a component, entity type, relation type or flow provider.
+    Provider,
+}
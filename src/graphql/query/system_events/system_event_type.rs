@@ -0,0 +1,29 @@
+use async_graphql::*;
+
+/// The type of a system event.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+#[graphql(name = "SystemEventType", remote = "crate::api::SystemEventTypes")]
+pub enum GraphQLSystemEventType {
+    ComponentCreated,
+    ComponentDeleted,
+    EntityTypeCreated,
+    EntityTypeDeleted,
+    RelationTypeCreated,
+    RelationTypeDeleted,
+    EntityInstanceCreated,
+    EntityInstanceDeleted,
+    RelationInstanceCreated,
+    RelationInstanceDeleted,
+    FlowCreated,
+    FlowDeleted,
+    EntityComponentAdded,
+    EntityComponentRemoved,
+    RelationComponentAdded,
+    RelationComponentRemoved,
+    EntityTypeUpdated,
+    FlowCommitted,
+    PluginLoaded,
+    PluginUnloaded,
+    PluginFailed,
+    ShutdownRequested,
+}
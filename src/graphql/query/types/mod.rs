@@ -2,6 +2,7 @@ pub use component::*;
 pub use data_type::*;
 pub use entity_type::*;
 pub use extension::*;
+pub use property_constraints::*;
 pub use property_type::*;
 pub use relation_type::*;
 pub use socket_type::*;
@@ -11,6 +12,7 @@ pub mod component;
 pub mod data_type;
 pub mod entity_type;
 pub mod extension;
+pub mod property_constraints;
 pub mod property_type;
 pub mod relation_type;
 pub mod socket_type;
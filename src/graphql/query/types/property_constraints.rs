@@ -0,0 +1,49 @@
+use async_graphql::*;
+use serde_json::Value;
+
+use crate::api::PropertyConstraints;
+
+pub struct GraphQLPropertyConstraints {
+    constraints: PropertyConstraints,
+}
+
+/// The constraints of a property type. The constraints are defined by the extension
+/// `property_constraints` of the property type.
+#[Object(name = "PropertyConstraints")]
+impl GraphQLPropertyConstraints {
+    /// If true, the property must be given when an instance is created.
+    async fn required(&self) -> bool {
+        self.constraints.required
+    }
+
+    /// The default value which is used instead of the default value of the data type.
+    async fn default_value(&self) -> Option<Value> {
+        self.constraints.default.clone()
+    }
+
+    /// The minimum of a number.
+    async fn minimum(&self) -> Option<f64> {
+        self.constraints.minimum
+    }
+
+    /// The maximum of a number.
+    async fn maximum(&self) -> Option<f64> {
+        self.constraints.maximum
+    }
+
+    /// The regular expression which a string has to match.
+    async fn pattern(&self) -> Option<String> {
+        self.constraints.pattern.clone()
+    }
+
+    /// The allowed values.
+    async fn values(&self) -> Option<Vec<Value>> {
+        self.constraints.values.clone()
+    }
+}
+
+impl From<PropertyConstraints> for GraphQLPropertyConstraints {
+    fn from(constraints: PropertyConstraints) -> Self {
+        GraphQLPropertyConstraints { constraints }
+    }
+}
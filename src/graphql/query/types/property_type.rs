@@ -1,6 +1,8 @@
 use async_graphql::*;
 
+use crate::api::PropertyConstraints;
 use crate::graphql::query::GraphQLExtension;
+use crate::graphql::query::GraphQLPropertyConstraints;
 use crate::graphql::query::{GraphQLDataType, GraphQLSocketType};
 use crate::model::PropertyType;
 
@@ -34,6 +36,11 @@ impl GraphQLPropertyType {
         self.property_type.socket_type.into()
     }
 
+    /// The constraints of the property instances.
+    async fn constraints(&self) -> GraphQLPropertyConstraints {
+        PropertyConstraints::of(&self.property_type).into()
+    }
+
     /// The extensions which are defined by the entity type.
     async fn extensions(&self, name: Option<String>) -> Vec<GraphQLExtension> {
         if name.is_some() {
@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use async_graphql::*;
 
-use crate::api::{ComponentManager, EntityTypeManager, RelationTypeManager};
+use crate::api::{ComponentManager, EntityTypeManager, Permission, RelationTypeManager};
+use crate::graphql::is_permitted;
 use crate::graphql::query::{GraphQLComponent, GraphQLEntityType, GraphQLRelationType};
 
 #[derive(Default)]
@@ -24,16 +25,28 @@ impl Types {
         if component_manager.is_ok() {
             let component_manager = component_manager.unwrap();
             if name.is_some() {
-                let component = component_manager.get(name.unwrap());
+                let component = component_manager
+                    .get(name.unwrap())
+                    .filter(|component| is_permitted(context, Permission::TypesRead, &[component.name.clone()]));
                 if component.is_some() {
                     return vec![component.unwrap().into()];
                 }
                 return Vec::new();
             }
             if search.is_some() {
-                return component_manager.find(search.unwrap()).into_iter().map(|component| component.into()).collect();
+                return component_manager
+                    .find(search.unwrap())
+                    .into_iter()
+                    .filter(|component| is_permitted(context, Permission::TypesRead, &[component.name.clone()]))
+                    .map(|component| component.into())
+                    .collect();
             }
-            return component_manager.get_components().into_iter().map(|component| component.into()).collect();
+            return component_manager
+                .get_components()
+                .into_iter()
+                .filter(|component| is_permitted(context, Permission::TypesRead, &[component.name.clone()]))
+                .map(|component| component.into())
+                .collect();
         }
         Vec::new()
     }
@@ -51,7 +64,9 @@ impl Types {
         if entity_type_manager.is_ok() {
             let entity_type_manager = entity_type_manager.unwrap();
             if name.is_some() {
-                let entity_type = entity_type_manager.get(name.unwrap());
+                let entity_type = entity_type_manager
+                    .get(name.unwrap())
+                    .filter(|entity_type| is_permitted(context, Permission::TypesRead, &[entity_type.name.clone()]));
                 if entity_type.is_some() {
                     let entity_type: GraphQLEntityType = entity_type.unwrap().into();
                     return vec![entity_type];
@@ -62,12 +77,14 @@ impl Types {
                 return entity_type_manager
                     .find(search.unwrap())
                     .into_iter()
+                    .filter(|entity_type| is_permitted(context, Permission::TypesRead, &[entity_type.name.clone()]))
                     .map(|entity_type| entity_type.into())
                     .collect();
             }
             return entity_type_manager
                 .get_entity_types()
                 .iter()
+                .filter(|entity_type| is_permitted(context, Permission::TypesRead, &[entity_type.name.clone()]))
                 .map(|entity_type| {
                     let entity_type: GraphQLEntityType = entity_type.clone().into();
                     entity_type
@@ -95,6 +112,7 @@ impl Types {
                     .iter()
                     .filter(|relation_type| outbound_type.is_none() || outbound_type.clone().unwrap() == relation_type.outbound_type.clone())
                     .filter(|relation_type| inbound_type.is_none() || inbound_type.clone().unwrap() == relation_type.inbound_type.clone())
+                    .filter(|relation_type| is_permitted(context, Permission::TypesRead, &[relation_type.type_name.clone()]))
                     .map(|relation_type| {
                         let relation_type: GraphQLRelationType = relation_type.clone().into();
                         relation_type
@@ -107,6 +125,7 @@ impl Types {
                 .filter(|relation_type| outbound_type.is_none() || outbound_type.clone().unwrap() == relation_type.outbound_type.clone())
                 .filter(|relation_type| name.is_none() || name.clone().unwrap() == relation_type.type_name.clone())
                 .filter(|relation_type| inbound_type.is_none() || inbound_type.clone().unwrap() == relation_type.inbound_type.clone())
+                .filter(|relation_type| is_permitted(context, Permission::TypesRead, &[relation_type.type_name.clone()]))
                 .map(|relation_type| {
                     let relation_type: GraphQLRelationType = relation_type.clone().into();
                     relation_type
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use log::trace;
+use serde_json::Value;
+
+use crate::config::BackpressurePolicy;
+use crate::config::GraphQLSubscriptionConfig;
+
+struct PropertyValueBufferState {
+    values: VecDeque<(String, Value)>,
+    waker: Option<Waker>,
+}
+
+/// Buffers the values emitted by reactive properties until the subscriber consumes them.
+///
+/// The observer of a property pushes values into the buffer and wakes the subscriber. If
+/// the subscriber is slower than the property, the backpressure policy decides which values
+/// are kept.
+#[derive(Clone)]
+pub struct PropertyValueBuffer {
+    state: Arc<Mutex<PropertyValueBufferState>>,
+    policy: BackpressurePolicy,
+    capacity: usize,
+}
+
+impl PropertyValueBuffer {
+    pub fn new(config: &GraphQLSubscriptionConfig) -> Self {
+        PropertyValueBuffer {
+            state: Arc::new(Mutex::new(PropertyValueBufferState {
+                values: VecDeque::new(),
+                waker: None,
+            })),
+            policy: config.backpressure,
+            capacity: config.capacity.max(1),
+        }
+    }
+
+    /// Pushes the value of the property with the given name and wakes the subscriber.
+    pub fn push(&self, name: &str, value: &Value) {
+        let mut state = self.state.lock().unwrap();
+        match self.policy {
+            BackpressurePolicy::CoalesceLatest => match state.values.iter().position(|(property_name, _)| property_name == name) {
+                Some(index) => state.values[index].1 = value.clone(),
+                None => state.values.push_back((name.to_string(), value.clone())),
+            },
+            BackpressurePolicy::DropOldest => {
+                if state.values.len() >= self.capacity {
+                    state.values.pop_front();
+                    trace!("Subscription buffer is full: dropped oldest value");
+                }
+                state.values.push_back((name.to_string(), value.clone()));
+            }
+            BackpressurePolicy::Buffer => {
+                if state.values.len() >= self.capacity {
+                    trace!("Subscription buffer is full: dropped value of property {}", name);
+                    return;
+                }
+                state.values.push_back((name.to_string(), value.clone()));
+            }
+        }
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns the next buffered value. If the buffer is empty, the subscriber is woken up
+    /// as soon as the next value is pushed.
+    pub fn poll_pop(&self, context: &mut Context<'_>) -> Poll<(String, Value)> {
+        let mut state = self.state.lock().unwrap();
+        match state.values.pop_front() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Returns the number of buffered values.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of handles of the buffer. The subscriber and the observer of the
+    /// property hold a handle each.
+    pub fn handles(&self) -> usize {
+        Arc::strong_count(&self.state)
+    }
+}
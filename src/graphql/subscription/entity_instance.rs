@@ -1,5 +1,6 @@
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::PropertyValueBuffer;
 use crate::model::ReactiveEntityInstance;
-use crossbeam::channel::Receiver;
 use futures_util::Stream;
 use log::debug;
 use rand::Rng;
@@ -7,26 +8,27 @@ use serde_json::Value;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Poll;
-use std::time::Duration;
 
 pub struct EntityPropertyInstanceStream {
     entity_instance: Arc<ReactiveEntityInstance>,
     property_name: String,
     handle_id: u128,
-    receiver: Receiver<Value>,
+    buffer: PropertyValueBuffer,
 }
 
 impl EntityPropertyInstanceStream {
-    pub fn new(entity_instance: Arc<ReactiveEntityInstance>, property_name: String) -> EntityPropertyInstanceStream {
+    pub fn new(entity_instance: Arc<ReactiveEntityInstance>, property_name: String, config: &GraphQLSubscriptionConfig) -> EntityPropertyInstanceStream {
         debug!("Opened subscription entity({})[{}]", entity_instance.id, property_name);
         let mut rng = rand::thread_rng();
         let handle_id = rng.gen::<u128>();
         let entity_instance2 = entity_instance.clone();
         let property_instance = entity_instance2.properties.get(&property_name).unwrap();
-        let (sender, receiver) = crossbeam::channel::unbounded();
+        let buffer = PropertyValueBuffer::new(config);
+        let observer_buffer = buffer.clone();
+        let name = property_name.clone();
         property_instance.stream.read().unwrap().observe_with_handle(
             move |value: &Value| {
-                let _ = sender.send(value.clone());
+                observer_buffer.push(&name, value);
             },
             handle_id,
         );
@@ -34,25 +36,21 @@ impl EntityPropertyInstanceStream {
             entity_instance,
             property_name,
             handle_id,
-            receiver,
+            buffer,
         }
     }
+
+    /// Returns a handle of the buffer of the subscription.
+    pub fn get_buffer(&self) -> PropertyValueBuffer {
+        self.buffer.clone()
+    }
 }
 
 impl Stream for EntityPropertyInstanceStream {
     type Item = Value;
 
-    fn poll_next(self: Pin<&mut Self>, _context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
-        match self.receiver.try_recv() {
-            Ok(value) => {
-                std::thread::sleep(Duration::from_millis(10));
-                Poll::Ready(Some(value))
-            }
-            Err(_) => {
-                std::thread::sleep(Duration::from_millis(100));
-                Poll::Ready(None)
-            }
-        }
+    fn poll_next(self: Pin<&mut Self>, context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.buffer.poll_pop(context).map(|(_, value)| Some(value))
     }
 }
 
@@ -63,3 +61,70 @@ impl Drop for EntityPropertyInstanceStream {
         property_instance.stream.read().unwrap().remove(self.handle_id);
     }
 }
+
+/// Streams the changes of multiple properties of an entity instance as tuples of
+/// property name and value.
+pub struct EntityPropertiesInstanceStream {
+    entity_instance: Arc<ReactiveEntityInstance>,
+    property_names: Vec<String>,
+    handle_id: u128,
+    buffer: PropertyValueBuffer,
+}
+
+impl EntityPropertiesInstanceStream {
+    /// Subscribes the given properties or all properties if no property names are given.
+    /// Unknown property names are ignored.
+    pub fn new(
+        entity_instance: Arc<ReactiveEntityInstance>,
+        property_names: Option<Vec<String>>,
+        config: &GraphQLSubscriptionConfig,
+    ) -> EntityPropertiesInstanceStream {
+        let property_names: Vec<String> = entity_instance
+            .properties
+            .iter()
+            .map(|property_instance| property_instance.key().clone())
+            .filter(|name| property_names.is_none() || property_names.as_ref().unwrap().contains(name))
+            .collect();
+        debug!("Opened subscription entity({})[{}]", entity_instance.id, property_names.join(", "));
+        let mut rng = rand::thread_rng();
+        let handle_id = rng.gen::<u128>();
+        let buffer = PropertyValueBuffer::new(config);
+        for property_name in property_names.iter() {
+            if let Some(property_instance) = entity_instance.properties.get(property_name) {
+                let observer_buffer = buffer.clone();
+                let name = property_name.clone();
+                property_instance.stream.read().unwrap().observe_with_handle(
+                    move |value: &Value| {
+                        observer_buffer.push(&name, value);
+                    },
+                    handle_id,
+                );
+            }
+        }
+        EntityPropertiesInstanceStream {
+            entity_instance,
+            property_names,
+            handle_id,
+            buffer,
+        }
+    }
+}
+
+impl Stream for EntityPropertiesInstanceStream {
+    type Item = (String, Value);
+
+    fn poll_next(self: Pin<&mut Self>, context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.buffer.poll_pop(context).map(Some)
+    }
+}
+
+impl Drop for EntityPropertiesInstanceStream {
+    fn drop(&mut self) {
+        debug!("Closing subscription entity({})[{}]", self.entity_instance.id, self.property_names.join(", "));
+        for property_name in self.property_names.iter() {
+            if let Some(property_instance) = self.entity_instance.properties.get(property_name) {
+                property_instance.stream.read().unwrap().remove(self.handle_id);
+            }
+        }
+    }
+}
@@ -1,25 +1,42 @@
+pub use buffer::*;
 pub use entity_instance::*;
 pub use relation_instance::*;
+pub use system_events::*;
 
 use serde::Serialize;
 
+pub mod buffer;
 pub mod entity_instance;
 pub mod relation_instance;
+pub mod system_events;
 
 pub struct InexorSubscription;
 
-use crate::api::{ReactiveEntityInstanceManager, ReactiveRelationInstanceManager};
+use crate::api::{entity_instance_resources, flow_resources, Permission, ReactiveEntityInstanceManager, ReactiveRelationInstanceManager};
+use crate::api::{ComponentManager, EntityTypeManager, ReactiveFlowManager, RelationTypeManager, SystemEventTypes};
+use crate::config::GraphQLSubscriptionConfig;
 use crate::graphql::mutation::GraphQLEdgeKey;
-use crate::graphql::query::GraphQLPropertyInstance;
-use async_graphql::{async_stream, Context, Result, Subscription};
+use crate::graphql::query::{
+    GraphQLComponent, GraphQLEntityInstance, GraphQLEntityType, GraphQLFlow, GraphQLPropertyInstance, GraphQLRelationInstance, GraphQLRelationType,
+};
+use crate::graphql::{check_permission, PermissionChecker};
+use async_graphql::{Context, Result, Subscription};
+use futures_util::future::ready;
 use futures_util::Stream;
 use futures_util::StreamExt;
 use serde_json::Value;
 use std::sync::Arc;
-use std::time::Duration;
 use uuid::Uuid;
 
+/// Returns the backpressure configuration of the subscriptions.
+fn get_subscription_config(context: &Context<'_>) -> GraphQLSubscriptionConfig {
+    context.data_opt::<GraphQLSubscriptionConfig>().cloned().unwrap_or_default()
+}
+
 /// Subscriptions for the reactive property instances.
+///
+/// The streams are push-based: the subscriber is woken up as soon as the property emits a
+/// new value.
 #[Subscription(name = "Subscription")]
 impl InexorSubscription {
     async fn entity(
@@ -41,25 +58,13 @@ impl InexorSubscription {
                 }
                 match entity_instance {
                     Some(entity_instance) => {
+                        check_permission(context, Permission::InstancesRead, &entity_instance_resources(&entity_instance))?;
                         if !entity_instance.properties.contains_key(&property_name) {
                             return Err("Error: property by name not found".into());
                         }
                         let type_name = entity_instance.type_name.clone();
-                        let mut stream = EntityPropertyInstanceStream::new(entity_instance, property_name.clone());
-
-                        Ok(async_stream::stream! {
-                            loop {
-                                match stream.next().await {
-                                    Some(value) => {
-                                        futures_timer::Delay::new(Duration::from_millis(10)).await;
-                                        yield GraphQLPropertyInstance::new_entity_property(type_name.clone(), property_name.clone(), value.clone());
-                                    }
-                                    None => {
-                                        futures_timer::Delay::new(Duration::from_millis(100)).await;
-                                    }
-                                };
-                            }
-                        })
+                        let stream = EntityPropertyInstanceStream::new(entity_instance, property_name.clone(), &get_subscription_config(context));
+                        Ok(stream.map(move |value| GraphQLPropertyInstance::new_entity_property(type_name.clone(), property_name.clone(), value)))
                     }
                     None => Err("Error: id not found".into()),
                 }
@@ -68,6 +73,28 @@ impl InexorSubscription {
         }
     }
 
+    /// Streams the changes of all properties of an entity instance. The stream can be
+    /// restricted to the given property names.
+    async fn entity_properties(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "The uuid of the entity instance")] id: Option<Uuid>,
+        #[graphql(desc = "The label of the entity instance")] label: Option<String>,
+        #[graphql(desc = "The names of the properties. If not given, all properties are streamed.")] property_names: Option<Vec<String>>,
+    ) -> Result<impl Stream<Item = GraphQLPropertyInstance>> {
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?;
+        let entity_instance = match (id, label) {
+            (Some(id), _) => entity_instance_manager.get(id),
+            (None, Some(label)) => entity_instance_manager.get_by_label(label),
+            (None, None) => return Err("Either id or label must be given!".into()),
+        }
+        .ok_or("Error: id not found")?;
+        check_permission(context, Permission::InstancesRead, &entity_instance_resources(&entity_instance))?;
+        let type_name = entity_instance.type_name.clone();
+        let stream = EntityPropertiesInstanceStream::new(entity_instance, property_names, &get_subscription_config(context));
+        Ok(stream.map(move |(property_name, value)| GraphQLPropertyInstance::new_entity_property(type_name.clone(), property_name, value)))
+    }
+
     async fn relation(
         &self,
         context: &Context<'_>,
@@ -77,31 +104,226 @@ impl InexorSubscription {
         match context.data::<Arc<dyn ReactiveRelationInstanceManager>>() {
             Ok(relation_instance_manager) => match relation_instance_manager.get(edge_key.into()) {
                 Some(relation_instance) => {
+                    check_permission(context, Permission::InstancesRead, &[relation_instance.type_name.clone()])?;
                     if !relation_instance.properties.contains_key(&property_name) {
                         return Err("Error: property by name not found".into());
                     }
                     let type_name = relation_instance.type_name.clone();
-                    let mut stream = RelationPropertyInstanceStream::new(relation_instance, property_name.clone());
-
-                    Ok(async_stream::stream! {
-                        loop {
-                            match stream.next().await {
-                                Some(value) => {
-                                    futures_timer::Delay::new(Duration::from_millis(10)).await;
-                                    yield GraphQLPropertyInstance::new_entity_property(type_name.clone(), property_name.clone(), value.clone());
-                                }
-                                None => {
-                                    futures_timer::Delay::new(Duration::from_millis(100)).await;
-                                }
-                            };
-                        }
-                    })
+                    let stream = RelationPropertyInstanceStream::new(relation_instance, property_name.clone(), &get_subscription_config(context));
+                    Ok(stream.map(move |value| GraphQLPropertyInstance::new_entity_property(type_name.clone(), property_name.clone(), value)))
                 }
                 None => Err("Error: id not found".into()),
             },
             Err(_) => Err("Error: REIM".into()),
         }
     }
+
+    /// Streams the changes of all properties of a relation instance. The stream can be
+    /// restricted to the given property names.
+    async fn relation_properties(
+        &self,
+        context: &Context<'_>,
+        edge_key: GraphQLEdgeKey,
+        #[graphql(desc = "The names of the properties. If not given, all properties are streamed.")] property_names: Option<Vec<String>>,
+    ) -> Result<impl Stream<Item = GraphQLPropertyInstance>> {
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?;
+        let relation_instance = relation_instance_manager.get(edge_key.into()).ok_or("Error: id not found")?;
+        check_permission(context, Permission::InstancesRead, &[relation_instance.type_name.clone()])?;
+        let type_name = relation_instance.type_name.clone();
+        let stream = RelationPropertiesInstanceStream::new(relation_instance, property_names, &get_subscription_config(context));
+        Ok(stream.map(move |(property_name, value)| GraphQLPropertyInstance::new_relation_property(type_name.clone(), property_name, value)))
+    }
+
+    /// Streams the components which have been created.
+    async fn component_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the component")] name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLComponent>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let component_manager = context.data::<Arc<dyn ComponentManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::ComponentCreated)?.filter_map(move |value| {
+            let component = event_as_string(&value)
+                .filter(|component_name| matches_name(&name, component_name))
+                .filter(|component_name| permission_checker.is_permitted(Permission::TypesRead, &[component_name.clone()]))
+                .and_then(|component_name| component_manager.get(component_name))
+                .map(GraphQLComponent::from);
+            ready(component)
+        }))
+    }
+
+    /// Streams the names of the components which have been deleted.
+    async fn component_deleted(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the component")] name: Option<String>,
+    ) -> Result<impl Stream<Item = String>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::ComponentDeleted)?.filter_map(move |value| {
+            let component_name = event_as_string(&value)
+                .filter(|component_name| matches_name(&name, component_name))
+                .filter(|component_name| permission_checker.is_permitted(Permission::TypesRead, &[component_name.clone()]));
+            ready(component_name)
+        }))
+    }
+
+    /// Streams the entity types which have been created.
+    async fn entity_type_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the entity type")] name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLEntityType>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let entity_type_manager = context.data::<Arc<dyn EntityTypeManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::EntityTypeCreated)?.filter_map(move |value| {
+            let entity_type = event_as_string(&value)
+                .filter(|type_name| matches_name(&name, type_name))
+                .filter(|type_name| permission_checker.is_permitted(Permission::TypesRead, &[type_name.clone()]))
+                .and_then(|type_name| entity_type_manager.get(type_name))
+                .map(GraphQLEntityType::from);
+            ready(entity_type)
+        }))
+    }
+
+    /// Streams the names of the entity types which have been deleted.
+    async fn entity_type_deleted(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the entity type")] name: Option<String>,
+    ) -> Result<impl Stream<Item = String>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::EntityTypeDeleted)?.filter_map(move |value| {
+            let type_name = event_as_string(&value)
+                .filter(|type_name| matches_name(&name, type_name))
+                .filter(|type_name| permission_checker.is_permitted(Permission::TypesRead, &[type_name.clone()]));
+            ready(type_name)
+        }))
+    }
+
+    /// Streams the relation types which have been created.
+    async fn relation_type_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the relation type")] name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLRelationType>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let relation_type_manager = context.data::<Arc<dyn RelationTypeManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::RelationTypeCreated)?.filter_map(move |value| {
+            let relation_type = event_as_string(&value)
+                .filter(|type_name| matches_name(&name, type_name))
+                .filter(|type_name| permission_checker.is_permitted(Permission::TypesRead, &[type_name.clone()]))
+                .and_then(|type_name| relation_type_manager.get(type_name))
+                .map(GraphQLRelationType::from);
+            ready(relation_type)
+        }))
+    }
+
+    /// Streams the names of the relation types which have been deleted.
+    async fn relation_type_deleted(
+        &self,
+        context: &Context<'_>,
+        #[graphql(desc = "Filters by the name of the relation type")] name: Option<String>,
+    ) -> Result<impl Stream<Item = String>> {
+        check_permission(context, Permission::TypesRead, &[])?;
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::RelationTypeDeleted)?.filter_map(move |value| {
+            let type_name = event_as_string(&value)
+                .filter(|type_name| matches_name(&name, type_name))
+                .filter(|type_name| permission_checker.is_permitted(Permission::TypesRead, &[type_name.clone()]));
+            ready(type_name)
+        }))
+    }
+
+    /// Streams the entity instances which have been created.
+    async fn entity_instance_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Filters by the entity type")] type_name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLEntityInstance>> {
+        check_permission(context, Permission::InstancesRead, &[])?;
+        let entity_instance_manager = context.data::<Arc<dyn ReactiveEntityInstanceManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::EntityInstanceCreated)?.filter_map(move |value| {
+            let entity_instance = event_as_uuid(&value)
+                .and_then(|id| entity_instance_manager.get(id))
+                .filter(|entity_instance| matches_name(&type_name, &entity_instance.type_name))
+                .filter(|entity_instance| permission_checker.is_permitted(Permission::InstancesRead, &entity_instance_resources(entity_instance)))
+                .map(GraphQLEntityInstance::from);
+            ready(entity_instance)
+        }))
+    }
+
+    /// Streams the ids of the entity instances which have been deleted.
+    async fn entity_instance_deleted(&self, context: &Context<'_>) -> Result<impl Stream<Item = Uuid>> {
+        check_permission(context, Permission::InstancesRead, &[])?;
+        Ok(system_event_stream(context, SystemEventTypes::EntityInstanceDeleted)?.filter_map(|value| ready(event_as_uuid(&value))))
+    }
+
+    /// Streams the relation instances which have been created.
+    async fn relation_instance_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Filters by the relation type")] type_name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLRelationInstance>> {
+        check_permission(context, Permission::InstancesRead, &[])?;
+        let relation_instance_manager = context.data::<Arc<dyn ReactiveRelationInstanceManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::RelationInstanceCreated)?.filter_map(move |value| {
+            let relation_instance = event_as_edge_key(&value)
+                .filter(|edge_key| matches_relation_type_name(&type_name, edge_key.t.as_str()))
+                .filter(|edge_key| permission_checker.is_permitted(Permission::InstancesRead, &[edge_key.t.as_str().to_string()]))
+                .and_then(|edge_key| relation_instance_manager.get(edge_key))
+                .map(GraphQLRelationInstance::from);
+            ready(relation_instance)
+        }))
+    }
+
+    /// Streams the edge keys of the relation instances which have been deleted.
+    async fn relation_instance_deleted(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Filters by the relation type")] type_name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLEdgeKeyEvent>> {
+        check_permission(context, Permission::InstancesRead, &[])?;
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::RelationInstanceDeleted)?.filter_map(move |value| {
+            let edge_key = event_as_edge_key(&value)
+                .filter(|edge_key| matches_relation_type_name(&type_name, edge_key.t.as_str()))
+                .filter(|edge_key| permission_checker.is_permitted(Permission::InstancesRead, &[edge_key.t.as_str().to_string()]))
+                .map(GraphQLEdgeKeyEvent::from);
+            ready(edge_key)
+        }))
+    }
+
+    /// Streams the flows which have been created.
+    async fn flow_created(
+        &self,
+        context: &Context<'_>,
+        #[graphql(name = "type", desc = "Filters by the entity type of the flow")] type_name: Option<String>,
+    ) -> Result<impl Stream<Item = GraphQLFlow>> {
+        check_permission(context, Permission::FlowsRead, &[])?;
+        let flow_manager = context.data::<Arc<dyn ReactiveFlowManager>>()?.clone();
+        let permission_checker = PermissionChecker::new(context);
+        Ok(system_event_stream(context, SystemEventTypes::FlowCreated)?.filter_map(move |value| {
+            let flow = event_as_uuid(&value)
+                .and_then(|id| flow_manager.get(id))
+                .filter(|flow| matches_name(&type_name, &flow.type_name))
+                .filter(|flow| permission_checker.is_permitted(Permission::FlowsRead, &flow_resources(flow)))
+                .map(GraphQLFlow::from);
+            ready(flow)
+        }))
+    }
+
+    /// Streams the ids of the flows which have been deleted.
+    async fn flow_deleted(&self, context: &Context<'_>) -> Result<impl Stream<Item = Uuid>> {
+        check_permission(context, Permission::FlowsRead, &[])?;
+        Ok(system_event_stream(context, SystemEventTypes::FlowDeleted)?.filter_map(|value| ready(event_as_uuid(&value))))
+    }
 }
 
 #[derive(Serialize)]
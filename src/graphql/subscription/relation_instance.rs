@@ -1,5 +1,6 @@
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::PropertyValueBuffer;
 use crate::model::ReactiveRelationInstance;
-use crossbeam::channel::Receiver;
 use futures_util::Stream;
 use log::debug;
 use rand::Rng;
@@ -7,17 +8,16 @@ use serde_json::Value;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Poll;
-use std::time::Duration;
 
 pub struct RelationPropertyInstanceStream {
     relation_instance: Arc<ReactiveRelationInstance>,
     property_name: String,
     handle_id: u128,
-    receiver: Receiver<Value>,
+    buffer: PropertyValueBuffer,
 }
 
 impl RelationPropertyInstanceStream {
-    pub fn new(relation_instance: Arc<ReactiveRelationInstance>, property_name: String) -> RelationPropertyInstanceStream {
+    pub fn new(relation_instance: Arc<ReactiveRelationInstance>, property_name: String, config: &GraphQLSubscriptionConfig) -> RelationPropertyInstanceStream {
         debug!(
             "Opened subscription relation({}__{}__{})[{}]",
             relation_instance.inbound.id,
@@ -29,10 +29,12 @@ impl RelationPropertyInstanceStream {
         let handle_id = rng.gen::<u128>();
         let relation_instance2 = relation_instance.clone();
         let property_instance = relation_instance2.properties.get(&property_name).unwrap();
-        let (sender, receiver) = crossbeam::channel::unbounded();
+        let buffer = PropertyValueBuffer::new(config);
+        let observer_buffer = buffer.clone();
+        let name = property_name.clone();
         property_instance.stream.read().unwrap().observe_with_handle(
             move |value: &Value| {
-                let _ = sender.send(value.clone());
+                observer_buffer.push(&name, value);
             },
             handle_id,
         );
@@ -40,7 +42,7 @@ impl RelationPropertyInstanceStream {
             relation_instance,
             property_name,
             handle_id,
-            receiver,
+            buffer,
         }
     }
 }
@@ -48,17 +50,8 @@ impl RelationPropertyInstanceStream {
 impl Stream for RelationPropertyInstanceStream {
     type Item = Value;
 
-    fn poll_next(self: Pin<&mut Self>, _context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
-        match self.receiver.try_recv() {
-            Ok(value) => {
-                std::thread::sleep(Duration::from_millis(10));
-                Poll::Ready(Some(value))
-            }
-            Err(_) => {
-                std::thread::sleep(Duration::from_millis(100));
-                Poll::Ready(None)
-            }
-        }
+    fn poll_next(self: Pin<&mut Self>, context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.buffer.poll_pop(context).map(|(_, value)| Some(value))
     }
 }
 
@@ -75,3 +68,82 @@ impl Drop for RelationPropertyInstanceStream {
         property_instance.stream.read().unwrap().remove(self.handle_id);
     }
 }
+
+/// Streams the changes of multiple properties of a relation instance as tuples of
+/// property name and value.
+pub struct RelationPropertiesInstanceStream {
+    relation_instance: Arc<ReactiveRelationInstance>,
+    property_names: Vec<String>,
+    handle_id: u128,
+    buffer: PropertyValueBuffer,
+}
+
+impl RelationPropertiesInstanceStream {
+    /// Subscribes the given properties or all properties if no property names are given.
+    /// Unknown property names are ignored.
+    pub fn new(
+        relation_instance: Arc<ReactiveRelationInstance>,
+        property_names: Option<Vec<String>>,
+        config: &GraphQLSubscriptionConfig,
+    ) -> RelationPropertiesInstanceStream {
+        let property_names: Vec<String> = relation_instance
+            .properties
+            .iter()
+            .map(|property_instance| property_instance.key().clone())
+            .filter(|name| property_names.is_none() || property_names.as_ref().unwrap().contains(name))
+            .collect();
+        debug!(
+            "Opened subscription relation({}__{}__{})[{}]",
+            relation_instance.inbound.id,
+            relation_instance.type_name.clone(),
+            relation_instance.outbound.id,
+            property_names.join(", ")
+        );
+        let mut rng = rand::thread_rng();
+        let handle_id = rng.gen::<u128>();
+        let buffer = PropertyValueBuffer::new(config);
+        for property_name in property_names.iter() {
+            if let Some(property_instance) = relation_instance.properties.get(property_name) {
+                let observer_buffer = buffer.clone();
+                let name = property_name.clone();
+                property_instance.stream.read().unwrap().observe_with_handle(
+                    move |value: &Value| {
+                        observer_buffer.push(&name, value);
+                    },
+                    handle_id,
+                );
+            }
+        }
+        RelationPropertiesInstanceStream {
+            relation_instance,
+            property_names,
+            handle_id,
+            buffer,
+        }
+    }
+}
+
+impl Stream for RelationPropertiesInstanceStream {
+    type Item = (String, Value);
+
+    fn poll_next(self: Pin<&mut Self>, context: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.buffer.poll_pop(context).map(Some)
+    }
+}
+
+impl Drop for RelationPropertiesInstanceStream {
+    fn drop(&mut self) {
+        debug!(
+            "Closing subscription relation({}__{}__{})[{}]",
+            self.relation_instance.inbound.id,
+            self.relation_instance.type_name.clone(),
+            self.relation_instance.outbound.id,
+            self.property_names.join(", ")
+        );
+        for property_name in self.property_names.iter() {
+            if let Some(property_instance) = self.relation_instance.properties.get(property_name) {
+                property_instance.stream.read().unwrap().remove(self.handle_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Error, Result, SimpleObject};
+use indradb::EdgeKey;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::SystemEventManager;
+use crate::api::SystemEventTypes;
+use crate::graphql::subscription::get_subscription_config;
+use crate::graphql::subscription::EntityPropertyInstanceStream;
+
+/// The edge key of a deleted relation instance.
+#[derive(SimpleObject)]
+#[graphql(name = "EdgeKey")]
+pub struct GraphQLEdgeKeyEvent {
+    /// The id of the outbound entity instance.
+    pub outbound_id: Uuid,
+
+    /// The type name of the relation instance.
+    pub type_name: String,
+
+    /// The id of the inbound entity instance.
+    pub inbound_id: Uuid,
+}
+
+impl From<EdgeKey> for GraphQLEdgeKeyEvent {
+    fn from(edge_key: EdgeKey) -> Self {
+        GraphQLEdgeKeyEvent {
+            outbound_id: edge_key.outbound_id,
+            type_name: edge_key.t.as_str().to_string(),
+            inbound_id: edge_key.inbound_id,
+        }
+    }
+}
+
+/// Streams the values of the property `event` of the system event instance of the given type.
+pub fn system_event_stream(context: &Context<'_>, event_type: SystemEventTypes) -> Result<EntityPropertyInstanceStream> {
+    let system_event_manager = context.data::<Arc<dyn SystemEventManager>>()?;
+    let entity_instance = system_event_manager
+        .get_system_event_instance(event_type.clone())
+        .ok_or_else(|| Error::new(format!("System event {:?} is not available", event_type)))?;
+    Ok(EntityPropertyInstanceStream::new(entity_instance, String::from("event"), &get_subscription_config(context)))
+}
+
+/// Returns the name of a type or the id of an instance from the value of a system event.
+pub fn event_as_string(value: &Value) -> Option<String> {
+    value.as_str().map(String::from)
+}
+
+pub fn event_as_uuid(value: &Value) -> Option<Uuid> {
+    value.as_str().and_then(|id| Uuid::parse_str(id).ok())
+}
+
+pub fn event_as_edge_key(value: &Value) -> Option<EdgeKey> {
+    serde_json::from_value(value.clone()).ok()
+}
+
+/// Returns true, if no name is given or the name matches exactly.
+pub fn matches_name(filter: &Option<String>, name: &str) -> bool {
+    filter.as_ref().map(|filter| filter == name).unwrap_or(true)
+}
+
+/// Returns true, if no type name is given or the relation type name starts with the given
+/// type name. The type names of relation instances may contain an instance specific suffix.
+pub fn matches_relation_type_name(filter: &Option<String>, type_name: &str) -> bool {
+    filter.as_ref().map(|filter| type_name.starts_with(filter.as_str())).unwrap_or(true)
+}
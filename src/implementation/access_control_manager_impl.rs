@@ -0,0 +1,61 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::api::Permission;
+use crate::config::PolicyConfig;
+use crate::di::*;
+
+#[wrapper]
+pub struct PolicyConfigWrapper(RwLock<PolicyConfig>);
+
+#[provides]
+fn create_policy_config() -> PolicyConfigWrapper {
+    PolicyConfigWrapper(RwLock::new(PolicyConfig::default()))
+}
+
+#[component]
+pub struct AccessControlManagerImpl {
+    policy: PolicyConfigWrapper,
+}
+
+#[async_trait]
+#[provides]
+impl AccessControlManager for AccessControlManagerImpl {
+    fn is_enabled(&self) -> bool {
+        self.policy.0.read().unwrap().enabled
+    }
+
+    fn is_permitted(&self, identity: &Identity, permission: Permission, resources: &[String]) -> bool {
+        if identity.is_system() {
+            return true;
+        }
+        let policy = self.policy.0.read().unwrap();
+        if !policy.enabled {
+            return true;
+        }
+        policy
+            .role
+            .iter()
+            .filter(|role| identity.has_role(&role.name) || policy.default_roles.contains(&role.name))
+            .any(|role| role.permits(permission, resources))
+    }
+}
+
+impl Lifecycle for AccessControlManagerImpl {
+    fn init(&self) {
+        // Refuse to start instead of running without authorization rules
+        let policy_config = crate::config::get_policy_config().unwrap_or_else(|e| panic!("Failed to load policy configuration: {}", e));
+        let mut policy = self.policy.0.write().unwrap();
+        *policy = policy_config;
+    }
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+
+use crate::api::AuthError;
+use crate::api::AuthManager;
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::config::AuthConfig;
+use crate::config::JwtConfig;
+use crate::di::*;
+
+/// The claims of a JSON web token which are relevant for the identity.
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+
+    #[serde(default = "Vec::new")]
+    roles: Vec<String>,
+}
+
+/// The key and the validation rules of JSON web tokens.
+pub struct JwtVerifier {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtVerifier {
+    /// Returns an error if the algorithm is unknown or the key is missing or can't be read.
+    pub fn new(jwt_config: &JwtConfig) -> Result<Self, String> {
+        let algorithm = Algorithm::from_str(&jwt_config.algorithm).map_err(|e| e.to_string())?;
+        let key = match algorithm {
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => match &jwt_config.secret {
+                Some(secret) => DecodingKey::from_secret(secret.as_bytes()),
+                None => return Err(String::from("Missing secret")),
+            },
+            _ => {
+                let path = jwt_config.public_key.clone().ok_or_else(|| String::from("Missing public key"))?;
+                let pem = std::fs::read(&path).map_err(|e| format!("Failed to read public key {}: {}", path, e))?;
+                match algorithm {
+                    Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(&pem),
+                    Algorithm::EdDSA => DecodingKey::from_ed_pem(&pem),
+                    _ => DecodingKey::from_rsa_pem(&pem),
+                }
+                .map_err(|e| e.to_string())?
+            }
+        };
+        let mut validation = Validation::new(algorithm);
+        if let Some(issuer) = &jwt_config.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        if let Some(audience) = &jwt_config.audience {
+            validation.set_audience(&[audience]);
+        }
+        Ok(JwtVerifier { key, validation })
+    }
+
+    fn verify(&self, token: &str) -> Result<Identity, AuthError> {
+        match jsonwebtoken::decode::<Claims>(token, &self.key, &self.validation) {
+            Ok(token_data) => Ok(Identity::new(token_data.claims.sub, token_data.claims.roles)),
+            Err(e) => Err(AuthError::InvalidCredentials(e.to_string())),
+        }
+    }
+}
+
+pub struct AuthState {
+    enabled: bool,
+    /// The static API tokens and their identities.
+    tokens: HashMap<String, Identity>,
+    jwt_verifier: Option<JwtVerifier>,
+}
+
+#[wrapper]
+pub struct AuthStateWrapper(RwLock<AuthState>);
+
+#[provides]
+fn create_auth_state() -> AuthStateWrapper {
+    AuthStateWrapper(RwLock::new(AuthState {
+        enabled: false,
+        tokens: HashMap::new(),
+        jwt_verifier: None,
+    }))
+}
+
+#[component]
+pub struct AuthManagerImpl {
+    state: AuthStateWrapper,
+}
+
+#[async_trait]
+#[provides]
+impl AuthManager for AuthManagerImpl {
+    fn is_enabled(&self) -> bool {
+        self.state.0.read().unwrap().enabled
+    }
+
+    fn authenticate(&self, credentials: Option<String>) -> Result<Identity, AuthError> {
+        let state = self.state.0.read().unwrap();
+        if !state.enabled {
+            return Ok(Identity::anonymous());
+        }
+        let token = credentials.ok_or(AuthError::MissingCredentials)?;
+        if let Some(identity) = state.tokens.get(&token) {
+            return Ok(identity.clone());
+        }
+        match &state.jwt_verifier {
+            Some(jwt_verifier) => jwt_verifier.verify(&token),
+            None => Err(AuthError::InvalidCredentials(String::from("Unknown API token"))),
+        }
+    }
+}
+
+impl Lifecycle for AuthManagerImpl {
+    fn init(&self) {
+        // Refuse to start instead of running without authentication
+        let auth_config: AuthConfig = crate::config::get_auth_config().unwrap_or_else(|e| panic!("Failed to load auth configuration: {}", e));
+        // Refuse to start instead of rejecting every JSON web token
+        let jwt_verifier = auth_config
+            .jwt
+            .as_ref()
+            .map(|jwt_config| JwtVerifier::new(jwt_config).unwrap_or_else(|e| panic!("Failed to load auth configuration: Invalid [jwt] section: {}", e)));
+        let mut state = self.state.0.write().unwrap();
+        state.enabled = auth_config.enabled;
+        state.tokens = auth_config
+            .token
+            .into_iter()
+            .map(|token_config| (token_config.token, Identity::new(token_config.name, token_config.roles)))
+            .collect();
+        state.jwt_verifier = jwt_verifier;
+    }
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
@@ -94,4 +94,12 @@ impl ComponentBehaviourManager for ComponentBehaviourManagerImpl {
     fn add_provider(&self, provider: Arc<dyn ComponentBehaviourProvider>) {
         self.behaviour_providers.0.write().unwrap().push(provider);
     }
+
+    fn remove_provider(&self, provider: Arc<dyn ComponentBehaviourProvider>) {
+        self.behaviour_providers
+            .0
+            .write()
+            .unwrap()
+            .retain(|p| Arc::as_ptr(p) as *const () != Arc::as_ptr(&provider) as *const ());
+    }
 }
@@ -9,8 +9,11 @@ use async_trait::async_trait;
 use log::{debug, error};
 use wildmatch::WildMatch;
 
+use crate::api::compile_patterns;
+use crate::api::with_event_source;
 use crate::api::Lifecycle;
 use crate::api::SystemEventManager;
+use crate::api::SystemEventSource;
 use crate::api::{ComponentManager, SystemEvent};
 use crate::model::PropertyType;
 use crate::plugins::ComponentProvider;
@@ -51,11 +54,16 @@ impl ComponentManagerImpl {
 #[provides]
 impl ComponentManager for ComponentManagerImpl {
     fn register(&self, component: crate::model::Component) {
+        if let Err(e) = compile_patterns(&component.properties) {
+            error!("Component {} not registered: {}", component.name, e);
+            return;
+        }
         if !self.has(component.name.clone()) {
             let name = component.name.clone();
             debug!("Registered component {}", name);
+            let after = serde_json::to_value(&component).ok();
             self.components.0.write().unwrap().push(component);
-            self.event_manager.emit_event(SystemEvent::ComponentCreated(name));
+            self.event_manager.emit_event_with_data(SystemEvent::ComponentCreated(name), None, after);
         }
     }
 
@@ -90,8 +98,9 @@ impl ComponentManager for ComponentManagerImpl {
 
     fn delete(&self, name: String) {
         let event = SystemEvent::ComponentDeleted(name.clone());
+        let before = self.get(name.clone()).and_then(|component| serde_json::to_value(&component).ok());
         self.components.0.write().unwrap().retain(|component| component.name != name);
-        self.event_manager.emit_event(event);
+        self.event_manager.emit_event_with_data(event, before, None);
     }
 
     fn import(&self, path: String) {
@@ -119,7 +128,7 @@ impl ComponentManager for ComponentManagerImpl {
     fn add_provider(&self, component_provider: Arc<dyn ComponentProvider>) {
         for component in component_provider.get_components() {
             debug!("Registering component: {}", component.name);
-            self.register(component);
+            with_event_source(SystemEventSource::Provider, || self.register(component));
         }
     }
 }
@@ -47,4 +47,12 @@ impl EntityBehaviourManager for EntityBehaviourManagerImpl {
     fn add_provider(&self, provider: Arc<dyn EntityBehaviourProvider>) {
         self.behaviour_providers.0.write().unwrap().push(provider);
     }
+
+    fn remove_provider(&self, provider: Arc<dyn EntityBehaviourProvider>) {
+        self.behaviour_providers
+            .0
+            .write()
+            .unwrap()
+            .retain(|p| Arc::as_ptr(p) as *const () != Arc::as_ptr(&provider) as *const ());
+    }
 }
@@ -5,11 +5,12 @@ use std::io::BufReader;
 
 use crate::di::{component, provides, Component, Wrc};
 use async_trait::async_trait;
+use indradb::VertexProperties;
 use log::error;
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::api::{EntityInstanceCreationError, EntityInstanceImportError, EntityInstanceManager, EntityVertexCreationError, EntityVertexManager};
+use crate::api::{EntityInstanceCreationError, EntityInstanceImportError, EntityInstanceManager, EntityVertexCreationError, EntityVertexManager, FLOW_MEMBERS};
 use crate::model::EntityInstance;
 
 #[component]
@@ -17,6 +18,16 @@ pub struct EntityInstanceManagerImpl {
     entity_vertex_manager: Wrc<dyn EntityVertexManager>,
 }
 
+impl EntityInstanceManagerImpl {
+    /// Converts the vertex properties into an entity instance. Internal bookkeeping properties
+    /// are not part of the entity instance.
+    fn to_entity_instance(vertex_properties: VertexProperties) -> EntityInstance {
+        let mut entity_instance = EntityInstance::from(vertex_properties);
+        entity_instance.properties.remove(FLOW_MEMBERS);
+        entity_instance
+    }
+}
+
 #[async_trait]
 #[provides]
 impl EntityInstanceManager for EntityInstanceManagerImpl {
@@ -25,7 +36,15 @@ impl EntityInstanceManager for EntityInstanceManagerImpl {
     }
 
     fn get(&self, id: Uuid) -> Option<EntityInstance> {
-        self.entity_vertex_manager.get_properties(id).map(EntityInstance::from)
+        self.entity_vertex_manager.get_properties(id).map(EntityInstanceManagerImpl::to_entity_instance)
+    }
+
+    fn get_all(&self) -> Vec<EntityInstance> {
+        self.entity_vertex_manager
+            .get_all_properties()
+            .into_iter()
+            .map(EntityInstanceManagerImpl::to_entity_instance)
+            .collect()
     }
 
     fn create(&self, type_name: String, properties: HashMap<String, Value, RandomState>) -> Result<Uuid, EntityInstanceCreationError> {
@@ -9,6 +9,8 @@ use indradb::Identifier;
 use log::{debug, error, warn};
 use wildmatch::WildMatch;
 
+use crate::api::compile_patterns;
+use crate::api::{with_event_source, SystemEventSource};
 use crate::api::{ComponentManager, SystemEventManager};
 use crate::api::{EntityTypeImportError, Lifecycle};
 use crate::api::{EntityTypeManager, SystemEvent};
@@ -50,13 +52,9 @@ impl EntityTypeManagerImpl {
                 .build(),
         );
     }
-}
 
-#[async_trait]
-#[provides]
-impl EntityTypeManager for EntityTypeManagerImpl {
-    fn register(&self, mut entity_type: EntityType) -> EntityType {
-        // Construct the type
+    /// Constructs the type and appends the properties of the components.
+    fn construct(&self, mut entity_type: EntityType) -> EntityType {
         entity_type.t = Identifier::new(entity_type.name.clone()).unwrap();
         for component_name in entity_type.components.iter() {
             match self.component_manager.get(component_name.clone()) {
@@ -64,9 +62,49 @@ impl EntityTypeManager for EntityTypeManagerImpl {
                 None => warn!("Entity type {} not fully initialized: No component named {}", entity_type.name.clone(), component_name),
             }
         }
+        entity_type
+    }
+}
+
+#[async_trait]
+#[provides]
+impl EntityTypeManager for EntityTypeManagerImpl {
+    fn register(&self, entity_type: EntityType) -> EntityType {
+        let entity_type = self.construct(entity_type);
+        if let Err(e) = compile_patterns(&entity_type.properties) {
+            error!("Entity type {} not registered: {}", entity_type.name, e);
+            return entity_type;
+        }
         self.entity_types.0.write().unwrap().push(entity_type.clone());
         debug!("Registered entity type {}", entity_type.name);
-        self.event_manager.emit_event(SystemEvent::EntityTypeCreated(entity_type.name.clone()));
+        self.event_manager
+            .emit_event_with_data(SystemEvent::EntityTypeCreated(entity_type.name.clone()), None, serde_json::to_value(&entity_type).ok());
+        entity_type
+    }
+
+    fn replace(&self, entity_type: EntityType) -> EntityType {
+        if !self.has(entity_type.name.clone()) {
+            return self.register(entity_type);
+        }
+        let entity_type = self.construct(entity_type);
+        if let Err(e) = compile_patterns(&entity_type.properties) {
+            error!("Entity type {} not replaced: {}", entity_type.name, e);
+            return entity_type;
+        }
+        let before = self
+            .entity_types
+            .0
+            .write()
+            .unwrap()
+            .iter_mut()
+            .find(|existing| existing.name == entity_type.name)
+            .map(|existing| std::mem::replace(existing, entity_type.clone()));
+        debug!("Replaced entity type {}", entity_type.name);
+        self.event_manager.emit_event_with_data(
+            SystemEvent::EntityTypeUpdated(entity_type.name.clone()),
+            before.and_then(|before| serde_json::to_value(&before).ok()),
+            serde_json::to_value(&entity_type).ok(),
+        );
         entity_type
     }
 
@@ -101,8 +139,9 @@ impl EntityTypeManager for EntityTypeManagerImpl {
     /// TODO: first delete the entity instance of this type, then delete the entity type itself.
     fn delete(&self, name: String) {
         let event = SystemEvent::EntityTypeDeleted(name.clone());
+        let before = self.get(name.clone()).and_then(|entity_type| serde_json::to_value(&entity_type).ok());
         self.entity_types.0.write().unwrap().retain(|entity_type| entity_type.name != name);
-        self.event_manager.emit_event(event);
+        self.event_manager.emit_event_with_data(event, before, None);
     }
 
     fn import(&self, path: String) -> Result<EntityType, EntityTypeImportError> {
@@ -129,7 +168,7 @@ impl EntityTypeManager for EntityTypeManagerImpl {
 
     fn add_provider(&self, entity_type_provider: Arc<dyn EntityTypeProvider>) {
         for entity_type in entity_type_provider.get_entity_types() {
-            self.register(entity_type);
+            with_event_source(SystemEventSource::Provider, || self.register(entity_type));
         }
     }
 }
@@ -2,12 +2,12 @@ use std::collections::HashMap;
 
 use crate::di::*;
 use async_trait::async_trait;
-use indradb::{Datastore, Identifier, SpecificVertexQuery, Vertex, VertexProperties, VertexQueryExt};
+use indradb::{Identifier, RangeVertexQuery, SpecificVertexQuery, Vertex, VertexProperties, VertexQueryExt};
 use log::debug;
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::api::{EntityTypeManager, EntityVertexCreationError, EntityVertexManager, GraphDatabase};
+use crate::api::{get_missing_required_property, validate_properties, EntityTypeManager, EntityVertexCreationError, EntityVertexManager, GraphDatabase};
 
 // This service operates on the graph database.
 
@@ -46,13 +46,26 @@ impl EntityVertexManager for EntityVertexManagerImpl {
         None
     }
 
+    fn get_all_properties(&self) -> Vec<VertexProperties> {
+        self.graph_database
+            .get_datastore()
+            .get_all_vertex_properties(RangeVertexQuery::new().into())
+            .unwrap_or_default()
+    }
+
     fn create(&self, type_name: String, properties: HashMap<String, Value>) -> Result<Uuid, EntityVertexCreationError> {
         if !self.entity_type_manager.has(type_name.clone()) {
             return Err(EntityVertexCreationError::EntityTypeMissing(type_name));
         }
         let entity_type = self.entity_type_manager.get(type_name).unwrap();
 
-        // TODO: check if the given properties are suitable for the entity type
+        // Unknown properties are checked by the callers which know the components of the instance
+        if let Some(property_name) = get_missing_required_property(&entity_type.properties, &properties) {
+            return Err(EntityVertexCreationError::MissingRequiredProperty(property_name));
+        }
+        if let Err(e) = validate_properties(&entity_type.properties, &properties, false) {
+            return Err(EntityVertexCreationError::InvalidProperties(e));
+        }
         let result = self.graph_database.get_datastore().create_vertex_from_type(entity_type.t);
         if result.is_err() {
             return Err(EntityVertexCreationError::GraphDatabaseError(result.err().unwrap()));
@@ -81,6 +94,12 @@ impl EntityVertexManager for EntityVertexManagerImpl {
             return Err(EntityVertexCreationError::EntityTypeMissing(type_name));
         }
         let entity_type = self.entity_type_manager.get(type_name).unwrap();
+        if let Some(property_name) = get_missing_required_property(&entity_type.properties, &properties) {
+            return Err(EntityVertexCreationError::MissingRequiredProperty(property_name));
+        }
+        if let Err(e) = validate_properties(&entity_type.properties, &properties, false) {
+            return Err(EntityVertexCreationError::InvalidProperties(e));
+        }
 
         let result = self.graph_database.get_datastore().create_vertex(&Vertex::with_id(id, entity_type.t));
         if result.is_err() {
@@ -1,14 +1,23 @@
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use async_trait::async_trait;
 use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
 
+use crate::api::get_event_source;
 use crate::api::Lifecycle;
 use crate::api::SystemEvent;
 use crate::api::SystemEventManager;
+use crate::api::SystemEventRecord;
 use crate::api::SystemEventTypes;
+use crate::api::SYSTEM_EVENT_HISTORY_CAPACITY;
+use crate::api::UUID_SYSTEM_EVENT;
 use crate::builder::ReactiveEntityInstanceBuilder;
 use crate::di::*;
 use crate::model::PropertyInstanceSetter;
@@ -22,77 +31,57 @@ fn create_shutdown_state() -> SystemEventInstanceStorage {
     SystemEventInstanceStorage(RwLock::new(HashMap::new()))
 }
 
+#[derive(Default)]
+pub struct SystemEventHistoryState {
+    sequence: u64,
+    records: VecDeque<SystemEventRecord>,
+}
+
+#[wrapper]
+pub struct SystemEventHistory(RwLock<SystemEventHistoryState>);
+
+#[provides]
+fn create_system_event_history() -> SystemEventHistory {
+    SystemEventHistory(RwLock::new(SystemEventHistoryState::default()))
+}
+
 #[component]
 pub struct SystemEventManagerImpl {
     system_event_instances: SystemEventInstanceStorage,
+
+    system_event_history: SystemEventHistory,
 }
 
 #[async_trait]
 #[provides]
 impl SystemEventManager for SystemEventManagerImpl {
     fn emit_event(&self, event: SystemEvent) {
-        let writer = self.system_event_instances.0.write().unwrap();
-        match event {
-            SystemEvent::ComponentCreated(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::ComponentCreated).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::ComponentDeleted(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::ComponentDeleted).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::EntityTypeCreated(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::EntityTypeCreated).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::EntityTypeDeleted(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::EntityTypeDeleted).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::RelationTypeCreated(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::RelationTypeCreated).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::RelationTypeDeleted(name) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::RelationTypeDeleted).cloned() {
-                    entity_instance.set("event", json!(name));
-                }
-            }
-            SystemEvent::EntityInstanceCreated(id) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::EntityInstanceCreated).cloned() {
-                    entity_instance.set("event", json!(id));
-                }
-            }
-            SystemEvent::EntityInstanceDeleted(id) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::EntityInstanceDeleted).cloned() {
-                    entity_instance.set("event", json!(id));
-                }
-            }
-            SystemEvent::RelationInstanceCreated(edge_key) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::RelationInstanceCreated).cloned() {
-                    entity_instance.set("event", json!(edge_key));
-                }
-            }
-            SystemEvent::RelationInstanceDeleted(edge_key) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::RelationInstanceDeleted).cloned() {
-                    entity_instance.set("event", json!(edge_key));
-                }
-            }
-            SystemEvent::FlowCreated(id) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::FlowCreated).cloned() {
-                    entity_instance.set("event", json!(id));
-                }
-            }
-            SystemEvent::FlowDeleted(id) => {
-                if let Some(entity_instance) = writer.get(&SystemEventTypes::FlowDeleted).cloned() {
-                    entity_instance.set("event", json!(id));
-                }
+        self.emit_event_with_data(event, None, None);
+    }
+
+    fn emit_event_with_data(&self, event: SystemEvent, before: Option<Value>, after: Option<Value>) {
+        let event_type = event.event_type();
+        let subject = event.subject();
+        {
+            let mut writer = self.system_event_history.0.write().unwrap();
+            writer.sequence += 1;
+            let record = SystemEventRecord {
+                sequence: writer.sequence,
+                timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0),
+                event_type,
+                subject: subject.clone(),
+                source: get_event_source(),
+                before,
+                after,
+            };
+            if writer.records.len() >= SYSTEM_EVENT_HISTORY_CAPACITY {
+                writer.records.pop_front();
             }
+            writer.records.push_back(record);
+        }
+        let entity_instance = self.system_event_instances.0.read().unwrap().get(&event_type).cloned();
+        if let Some(entity_instance) = entity_instance {
+            entity_instance.set("event", subject);
         }
     }
 
@@ -100,6 +89,22 @@ impl SystemEventManager for SystemEventManagerImpl {
         let reader = self.system_event_instances.0.read().unwrap();
         reader.values().cloned().collect()
     }
+
+    fn get_system_event_instance(&self, event_type: SystemEventTypes) -> Option<Arc<ReactiveEntityInstance>> {
+        let reader = self.system_event_instances.0.read().unwrap();
+        reader.get(&event_type).cloned()
+    }
+
+    fn get_system_events(&self, since: Option<u64>, event_types: &[SystemEventTypes]) -> Vec<SystemEventRecord> {
+        let reader = self.system_event_history.0.read().unwrap();
+        reader
+            .records
+            .iter()
+            .filter(|record| since.map_or(true, |since| record.timestamp >= since))
+            .filter(|record| event_types.is_empty() || event_types.contains(&record.event_type))
+            .cloned()
+            .collect()
+    }
 }
 
 impl SystemEventManagerImpl {
@@ -147,11 +152,38 @@ impl SystemEventManagerImpl {
         );
         writer.insert(SystemEventTypes::FlowCreated, self.create_system_event_instance("/org/inexor/event/flow/created"));
         writer.insert(SystemEventTypes::FlowDeleted, self.create_system_event_instance("/org/inexor/event/flow/deleted"));
+        writer.insert(
+            SystemEventTypes::EntityComponentAdded,
+            self.create_system_event_instance("/org/inexor/event/instance/entity/component/added"),
+        );
+        writer.insert(
+            SystemEventTypes::EntityComponentRemoved,
+            self.create_system_event_instance("/org/inexor/event/instance/entity/component/removed"),
+        );
+        writer.insert(
+            SystemEventTypes::RelationComponentAdded,
+            self.create_system_event_instance("/org/inexor/event/instance/relation/component/added"),
+        );
+        writer.insert(
+            SystemEventTypes::RelationComponentRemoved,
+            self.create_system_event_instance("/org/inexor/event/instance/relation/component/removed"),
+        );
+        writer.insert(
+            SystemEventTypes::EntityTypeUpdated,
+            self.create_system_event_instance("/org/inexor/event/type/entity/updated"),
+        );
+        writer.insert(SystemEventTypes::FlowCommitted, self.create_system_event_instance("/org/inexor/event/flow/committed"));
+        writer.insert(SystemEventTypes::PluginLoaded, self.create_system_event_instance("/org/inexor/event/plugin/loaded"));
+        writer.insert(SystemEventTypes::PluginUnloaded, self.create_system_event_instance("/org/inexor/event/plugin/unloaded"));
+        writer.insert(SystemEventTypes::PluginFailed, self.create_system_event_instance("/org/inexor/event/plugin/failed"));
+        writer.insert(SystemEventTypes::ShutdownRequested, self.create_system_event_instance("/org/inexor/event/shutdown/requested"));
     }
 
     pub(crate) fn create_system_event_instance<S: Into<String>>(&self, label: S) -> Arc<ReactiveEntityInstance> {
+        let label = label.into();
         ReactiveEntityInstanceBuilder::new("system_event")
-            .property("label", json!(label.into()))
+            .id(Uuid::new_v5(&UUID_SYSTEM_EVENT, label.as_bytes()))
+            .property("label", json!(label))
             .property("event", json!(false))
             .get()
     }
@@ -1,39 +1,161 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 
 use crate::di::*;
 use async_trait::async_trait;
+use indradb::EdgeKey;
 use log::error;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::api::{EntityInstanceManager, FlowCreationError, FlowImportError, FlowManager, RelationInstanceManager};
+use crate::api::{EntityInstanceManager, EntityVertexManager, FlowCreationError, FlowImportError, FlowManager, RelationInstanceManager, FLOW_MEMBERS};
 use crate::model::Flow;
 
+/// The members of a flow as stored in the vertex of the wrapper entity instance.
+#[derive(Serialize, Deserialize, Debug)]
+struct FlowMembers {
+    #[serde(default = "String::new")]
+    name: String,
+
+    #[serde(default = "String::new")]
+    description: String,
+
+    entity_instances: Vec<Uuid>,
+
+    relation_instances: Vec<EdgeKey>,
+}
+
+impl From<&Flow> for FlowMembers {
+    fn from(flow: &Flow) -> Self {
+        FlowMembers {
+            name: flow.name.clone(),
+            description: flow.description.clone(),
+            entity_instances: flow.entity_instances.iter().map(|entity_instance| entity_instance.id).collect(),
+            relation_instances: flow
+                .relation_instances
+                .iter()
+                .filter_map(|relation_instance| relation_instance.get_key())
+                .collect(),
+        }
+    }
+}
+
 #[component]
 pub struct FlowManagerImpl {
+    entity_vertex_manager: Wrc<dyn EntityVertexManager>,
+
     entity_instance_manager: Wrc<dyn EntityInstanceManager>,
 
     relation_instance_manager: Wrc<dyn RelationInstanceManager>,
 }
 
-#[async_trait]
-#[provides]
-impl FlowManager for FlowManagerImpl {
-    fn create(&self, flow: Flow) -> Result<Flow, FlowCreationError> {
+impl FlowManagerImpl {
+    /// Creates the entity instances and the relation instances of the flow which doesn't exist
+    /// yet. The ids of the created instances are collected in order to be able to roll back.
+    fn create_members(
+        &self,
+        flow: &Flow,
+        created_entity_instances: &mut Vec<Uuid>,
+        created_relation_instances: &mut Vec<EdgeKey>,
+    ) -> Result<(), FlowCreationError> {
         for entity_instance in flow.entity_instances.iter() {
             if !self.entity_instance_manager.has(entity_instance.id) {
-                let _id = self.entity_instance_manager.create_from_instance(entity_instance.clone())?;
+                let id = self.entity_instance_manager.create_from_instance(entity_instance.clone())?;
+                created_entity_instances.push(id);
             }
         }
         for relation_instance in flow.relation_instances.iter() {
             let edge_key = relation_instance.get_key();
             if edge_key.is_some() && !self.relation_instance_manager.has(edge_key.unwrap()) {
-                let _id = self.relation_instance_manager.create_from_instance(relation_instance.clone())?;
+                let edge_key = self.relation_instance_manager.create_from_instance(relation_instance.clone())?;
+                created_relation_instances.push(edge_key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the members of the flow in the vertex of the wrapper entity instance.
+    fn store_members(&self, flow: &Flow) {
+        if !self.entity_vertex_manager.has(flow.id) {
+            return;
+        }
+        match serde_json::to_value(FlowMembers::from(flow)) {
+            Ok(flow_members) => {
+                let mut properties = HashMap::new();
+                properties.insert(FLOW_MEMBERS.to_string(), flow_members);
+                self.entity_vertex_manager.commit(flow.id, properties);
+            }
+            Err(e) => {
+                error!("Failed to store the members of flow {}: {}", flow.id, e);
+            }
+        }
+    }
+
+    /// Returns the members of the flow stored in the vertex of the wrapper entity instance.
+    fn get_members(&self, id: Uuid) -> Option<FlowMembers> {
+        self.entity_vertex_manager.get_properties(id).and_then(|vertex_properties| {
+            vertex_properties
+                .props
+                .into_iter()
+                .find(|property| property.name.as_str() == FLOW_MEMBERS)
+                .and_then(|property| serde_json::from_value(property.value).ok())
+        })
+    }
+}
+
+#[async_trait]
+#[provides]
+impl FlowManager for FlowManagerImpl {
+    fn get(&self, id: Uuid) -> Option<Flow> {
+        let flow_members = self.get_members(id)?;
+        let wrapper_entity_instance = self.entity_instance_manager.get(id)?;
+        Some(Flow {
+            id,
+            type_name: wrapper_entity_instance.type_name,
+            name: flow_members.name,
+            description: flow_members.description,
+            entity_instances: flow_members
+                .entity_instances
+                .into_iter()
+                .filter_map(|id| self.entity_instance_manager.get(id))
+                .collect(),
+            relation_instances: flow_members
+                .relation_instances
+                .into_iter()
+                .filter_map(|edge_key| self.relation_instance_manager.get(edge_key))
+                .collect(),
+        })
+    }
+
+    fn get_all(&self) -> Vec<Flow> {
+        self.entity_vertex_manager
+            .get_all_properties()
+            .into_iter()
+            .filter(|vertex_properties| vertex_properties.props.iter().any(|property| property.name.as_str() == FLOW_MEMBERS))
+            .filter_map(|vertex_properties| self.get(vertex_properties.vertex.id))
+            .collect()
+    }
+
+    fn create(&self, flow: Flow) -> Result<Flow, FlowCreationError> {
+        let mut created_entity_instances = Vec::new();
+        let mut created_relation_instances = Vec::new();
+        if let Err(e) = self.create_members(&flow, &mut created_entity_instances, &mut created_relation_instances) {
+            // Don't leave a partially created flow in the graph database
+            for edge_key in created_relation_instances.into_iter().rev() {
+                self.relation_instance_manager.delete(edge_key);
+            }
+            for id in created_entity_instances.into_iter().rev() {
+                self.entity_instance_manager.delete(id);
             }
+            return Err(e);
         }
+        self.store_members(&flow);
         Ok(flow)
     }
 
     fn commit(&self, flow: Flow) {
+        self.store_members(&flow);
         for entity_instance in flow.entity_instances {
             if self.entity_instance_manager.has(entity_instance.id) {
                 // The entity instance has been updated
@@ -1,16 +1,62 @@
+use std::sync::Arc;
+
 use crate::di::*;
 use async_trait::async_trait;
+use indradb::Datastore;
 use indradb::MemoryDatastore;
-use std::sync::Arc;
+use indradb::RocksdbDatastore;
+use log::{debug, error, info};
 
 use crate::api::GraphDatabase;
+use crate::api::GraphDatabaseError;
+use crate::api::Lifecycle;
+use crate::config::GraphDatabaseBackend;
+use crate::config::GraphDatabaseConfig;
 
 #[wrapper]
-pub struct InexorDatastore(Arc<MemoryDatastore>);
+pub struct InexorDatastore(Arc<dyn Datastore + Send + Sync>);
 
 #[provides]
 fn create_datastore() -> InexorDatastore {
-    InexorDatastore(Arc::new(MemoryDatastore::default()))
+    let graph_database_config = crate::config::get_graph_database_config().unwrap_or_else(|e| panic!("Failed to load graph database configuration: {}", e));
+    let datastore = create_datastore_from_config(&graph_database_config).unwrap_or_else(|e| panic!("{}", e));
+    InexorDatastore(datastore)
+}
+
+/// Creates the datastore for the configured backend. Fails if the configured datastore cannot be
+/// opened instead of silently running on a volatile datastore.
+pub(crate) fn create_datastore_from_config(graph_database_config: &GraphDatabaseConfig) -> Result<Arc<dyn Datastore + Send + Sync>, GraphDatabaseError> {
+    match graph_database_config.backend {
+        GraphDatabaseBackend::Memory => match graph_database_config.path.clone() {
+            Some(path) => {
+                let datastore = if std::path::Path::new(&path).exists() {
+                    debug!("Reading in-memory graph database from {}", path);
+                    MemoryDatastore::read(path.clone())
+                } else {
+                    debug!("Creating in-memory graph database persisted at {}", path);
+                    MemoryDatastore::create(path.clone())
+                };
+                match datastore {
+                    Ok(datastore) => Ok(Arc::new(datastore)),
+                    Err(e) => Err(GraphDatabaseError::OpenError(path, e.to_string())),
+                }
+            }
+            None => {
+                debug!("Using volatile in-memory graph database");
+                Ok(Arc::new(MemoryDatastore::default()))
+            }
+        },
+        GraphDatabaseBackend::Rocksdb => match graph_database_config.path.clone() {
+            Some(path) => match RocksdbDatastore::new(path.clone(), graph_database_config.max_open_files) {
+                Ok(datastore) => {
+                    debug!("Opened RocksDB graph database at {}", path);
+                    Ok(Arc::new(datastore))
+                }
+                Err(e) => Err(GraphDatabaseError::OpenError(path, e.to_string())),
+            },
+            None => Err(GraphDatabaseError::MissingPath),
+        },
+    }
 }
 
 #[component]
@@ -21,7 +67,26 @@ pub struct GraphDatabaseImpl {
 #[async_trait]
 #[provides]
 impl GraphDatabase for GraphDatabaseImpl {
-    fn get_datastore(&self) -> Arc<MemoryDatastore> {
+    fn get_datastore(&self) -> Arc<dyn Datastore + Send + Sync> {
         self.datastore.0.clone()
     }
+
+    fn sync(&self) {
+        if let Err(e) = self.datastore.0.sync() {
+            error!("Failed to sync graph database: {}", e);
+        }
+    }
+}
+
+impl Lifecycle for GraphDatabaseImpl {
+    fn init(&self) {}
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {
+        info!("Syncing graph database");
+        self.sync();
+    }
 }
@@ -0,0 +1,91 @@
+use async_graphql::Request;
+use async_graphql::Schema;
+use async_trait::async_trait;
+
+use crate::api::AccessControlManager;
+use crate::api::ComponentManager;
+use crate::api::EntityTypeManager;
+use crate::api::GraphQLQueryService;
+use crate::api::Identity;
+use crate::api::PropertyValidator;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveFlowManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::api::RelationTypeManager;
+use crate::api::SnapshotManager;
+use crate::api::SystemEventManager;
+use crate::api::TransactionManager;
+use crate::config::get_graphql_server_config;
+use crate::config::GraphQLSubscriptionConfig;
+use crate::di::*;
+use crate::graphql::InexorMutation;
+use crate::graphql::InexorQuery;
+use crate::graphql::InexorSchema;
+use crate::graphql::InexorSchemaBuilder;
+use crate::graphql::InexorSubscription;
+
+/// The backpressure configuration of the subscriptions is loaded once.
+#[wrapper]
+pub struct GraphQLSubscriptionConfigWrapper(GraphQLSubscriptionConfig);
+
+#[provides]
+fn create_graphql_subscription_config() -> GraphQLSubscriptionConfigWrapper {
+    GraphQLSubscriptionConfigWrapper(get_graphql_server_config().subscriptions.unwrap_or_default())
+}
+
+#[component]
+pub struct GraphQLQueryServiceImpl {
+    component_manager: Wrc<dyn ComponentManager>,
+
+    entity_type_manager: Wrc<dyn EntityTypeManager>,
+
+    relation_type_manager: Wrc<dyn RelationTypeManager>,
+
+    entity_instance_manager: Wrc<dyn ReactiveEntityInstanceManager>,
+
+    relation_instance_manager: Wrc<dyn ReactiveRelationInstanceManager>,
+
+    flow_manager: Wrc<dyn ReactiveFlowManager>,
+
+    snapshot_manager: Wrc<dyn SnapshotManager>,
+
+    access_control_manager: Wrc<dyn AccessControlManager>,
+
+    event_manager: Wrc<dyn SystemEventManager>,
+
+    transaction_manager: Wrc<dyn TransactionManager>,
+
+    property_validator: Wrc<dyn PropertyValidator>,
+
+    subscription_config: GraphQLSubscriptionConfigWrapper,
+}
+
+#[async_trait]
+#[provides]
+impl GraphQLQueryService for GraphQLQueryServiceImpl {
+    fn get_schema_builder(&self) -> InexorSchemaBuilder {
+        Schema::build(InexorQuery, InexorMutation, InexorSubscription)
+            .data(self.component_manager.clone())
+            .data(self.entity_type_manager.clone())
+            .data(self.relation_type_manager.clone())
+            .data(self.entity_instance_manager.clone())
+            .data(self.relation_instance_manager.clone())
+            .data(self.flow_manager.clone())
+            .data(self.snapshot_manager.clone())
+            .data(self.access_control_manager.clone())
+            .data(self.event_manager.clone())
+            .data(self.transaction_manager.clone())
+            .data(self.property_validator.clone())
+            .data(self.subscription_config.0.clone())
+    }
+
+    fn get_schema(&self) -> InexorSchema {
+        self.get_schema_builder().finish()
+    }
+
+    async fn query(&self, request: String) -> Result<String, serde_json::Error> {
+        let schema = self.get_schema();
+        let result = schema.execute(Request::new(request).data(Identity::system())).await;
+        serde_json::to_string(&result)
+    }
+}
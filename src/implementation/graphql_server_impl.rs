@@ -1,5 +1,3 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::mpsc::Receiver;
@@ -7,10 +5,11 @@ use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
-use actix_cors::Cors;
 use actix_http::body::BoxBody;
+use actix_web::dev::Service;
 use actix_web::{guard, post, web, App, HttpRequest, HttpResponse, HttpResponseBuilder, HttpServer, Result};
 use actix_web_extras::middleware::Condition;
+use async_graphql::Data;
 use async_graphql::Schema;
 use async_graphql_actix_web::GraphQLRequest;
 use async_graphql_actix_web::GraphQLResponse;
@@ -24,29 +23,32 @@ use log::debug;
 use log::error;
 use log::info;
 use log::warn;
-use rustls::Certificate;
-use rustls::PrivateKey;
-use rustls::ServerConfig;
-use rustls_pemfile::certs;
-use rustls_pemfile::pkcs8_private_keys;
 use serde::Deserialize;
 
+use crate::api::get_bearer_token;
+use crate::api::AccessControlManager;
+use crate::api::AuthManager;
 use crate::api::ComponentManager;
 use crate::api::EntityTypeManager;
+use crate::api::EventSourceFuture;
+use crate::api::GraphQLQueryService;
 use crate::api::GraphQLServer;
+use crate::api::Identity;
 use crate::api::Lifecycle;
+use crate::api::PluginRegistry;
+use crate::api::PropertyValidator;
 use crate::api::ReactiveEntityInstanceManager;
 use crate::api::ReactiveFlowManager;
 use crate::api::ReactiveRelationInstanceManager;
 use crate::api::RelationTypeManager;
+use crate::api::SystemEventManager;
+use crate::api::SystemEventSource;
 use crate::api::WebResourceManager;
 use crate::config::get_logger_middleware;
 use crate::di::*;
-use crate::graphql::InexorMutation;
-use crate::graphql::InexorQuery;
 use crate::graphql::InexorSchema;
-use crate::graphql::InexorSubscription;
 use crate::plugins::HttpBody;
+use crate::rest::auth::get_credentials;
 
 #[component]
 pub struct GraphQLServerImpl {
@@ -63,24 +65,58 @@ pub struct GraphQLServerImpl {
     flow_manager: Wrc<dyn ReactiveFlowManager>,
 
     web_resource_manager: Wrc<dyn WebResourceManager>,
+
+    graphql_query_service: Wrc<dyn GraphQLQueryService>,
+
+    plugin_registry: Wrc<dyn PluginRegistry>,
+
+    auth_manager: Wrc<dyn AuthManager>,
+
+    access_control_manager: Wrc<dyn AccessControlManager>,
+
+    system_event_manager: Wrc<dyn SystemEventManager>,
+
+    property_validator: Wrc<dyn PropertyValidator>,
 }
 
 #[post("/graphql")]
-async fn query_graphql(schema: web::Data<InexorSchema>, request: GraphQLRequest) -> GraphQLResponse {
-    schema.execute(request.into_inner()).await.into()
+async fn query_graphql(schema: web::Data<InexorSchema>, identity: Identity, request: GraphQLRequest) -> GraphQLResponse {
+    schema.execute(request.into_inner().data(identity)).await.into()
 }
 
-async fn subscription_websocket(schema: web::Data<InexorSchema>, request: HttpRequest, payload: web::Payload) -> Result<HttpResponse> {
-    // let mut data = Data::default();
-    // if let Some(token) = get_token_from_headers(request.headers()) {
-    //     data.insert(token);
-    // }
+async fn subscription_websocket(
+    schema: web::Data<InexorSchema>,
+    auth_manager: web::Data<Arc<dyn AuthManager>>,
+    request: HttpRequest,
+    payload: web::Payload,
+) -> Result<HttpResponse> {
+    // Browsers cannot send headers with the websocket handshake. In this case the credentials
+    // are expected in the payload of the connection_init message.
+    if let Some(credentials) = get_credentials(&request) {
+        let identity = auth_manager.authenticate(Some(credentials)).map_err(actix_web::error::ErrorUnauthorized)?;
+        let mut data = Data::default();
+        data.insert(identity);
+        return GraphQLSubscription::new(Schema::clone(&*schema)).with_data(data).start(&request, payload);
+    }
+    let auth_manager = auth_manager.get_ref().clone();
     GraphQLSubscription::new(Schema::clone(&*schema))
-        // .with_data(data)
-        // .on_connection_init(on_connection_init)
+        .on_connection_init(move |value| on_connection_init(auth_manager, value))
         .start(&request, payload)
 }
 
+/// Authenticates the credentials of the connection_init message of a websocket connection.
+/// The credentials are accepted as field `token` or as field `Authorization`.
+async fn on_connection_init(auth_manager: Arc<dyn AuthManager>, value: serde_json::Value) -> async_graphql::Result<Data> {
+    let credentials = ["token", "Authorization", "authorization"]
+        .iter()
+        .find_map(|key| value.get(key).and_then(|credentials| credentials.as_str()))
+        .map(get_bearer_token);
+    let identity = auth_manager.authenticate(credentials).map_err(|e| async_graphql::Error::new(e.to_string()))?;
+    let mut data = Data::default();
+    data.insert(identity);
+    Ok(data)
+}
+
 #[derive(Deserialize)]
 pub struct WebResourcePathInfo {
     web_resource_base_path: String,
@@ -167,21 +203,14 @@ fn convert_response(response: Response<HttpBody>) -> HttpResponse {
 #[provides]
 impl GraphQLServer for GraphQLServerImpl {
     fn get_schema(&self) -> InexorSchema {
-        Schema::build(InexorQuery, InexorMutation, InexorSubscription)
-            .data(self.component_manager.clone())
-            .data(self.entity_type_manager.clone())
-            .data(self.relation_type_manager.clone())
-            .data(self.entity_instance_manager.clone())
-            .data(self.relation_instance_manager.clone())
-            .data(self.flow_manager.clone())
-            .finish()
+        // The plugin management is not part of the schema which is available for plugins
+        self.graphql_query_service.get_schema_builder().data(self.plugin_registry.clone()).finish()
     }
 
-    // TODO: Extract to separate service: GraphQLQueryService <- Deno Integration
     async fn query(&self, request: String) -> Result<String, serde_json::Error> {
         info!("query");
         let schema = self.get_schema();
-        let result = schema.execute(request).await;
+        let result = schema.execute(async_graphql::Request::new(request).data(Identity::system())).await;
         let json = serde_json::to_string(&result);
         match json {
             Ok(result) => Ok(result),
@@ -193,7 +222,7 @@ impl GraphQLServer for GraphQLServerImpl {
         let schema = self.get_schema();
         let _thread = task::Builder::new().name(String::from("query")).spawn(async move {
             info!("query: {}", request.clone());
-            let result = schema.execute(request).await;
+            let result = schema.execute(async_graphql::Request::new(request).data(Identity::system())).await;
             let json = serde_json::to_string(&result);
             info!("query result: {}", json.unwrap());
         });
@@ -215,16 +244,38 @@ impl GraphQLServer for GraphQLServerImpl {
         let relation_instance_manager = web::Data::new(self.relation_instance_manager.clone());
         let flow_manager = web::Data::new(self.flow_manager.clone());
         let web_resource_manager = web::Data::new(self.web_resource_manager.clone());
+        let auth_manager = web::Data::new(self.auth_manager.clone());
+        let access_control_manager = web::Data::new(self.access_control_manager.clone());
+        let system_event_manager = web::Data::new(self.system_event_manager.clone());
+        let property_validator = web::Data::new(self.property_validator.clone());
         let schema_data = web::Data::new(schema);
 
         let system = actix::System::new(); // actix::System::new("inexor-graphql");
 
         let graphql_server_config = crate::config::graphql::get_graphql_server_config();
+        // Without a CORS policy only same-origin requests are permitted
+        let cors_policy = match graphql_server_config.cors.clone().unwrap_or_default().to_cors_policy() {
+            Ok(cors_policy) => cors_policy,
+            Err(e) => {
+                error!("Could not start HTTP/GraphQL server: {}", e);
+                return;
+            }
+        };
+        let subscription_config = web::Data::new(graphql_server_config.subscriptions.clone().unwrap_or_default());
 
         let mut http_server = HttpServer::new(move || {
             App::new()
-                .wrap(Cors::permissive())
+                .wrap(cors_policy.to_cors())
                 .wrap(Condition::from_option(get_logger_middleware()))
+                .wrap_fn(|request, service| {
+                    // Attribute the system events caused by the request to the API
+                    let source = if request.path().starts_with("/graphql") {
+                        SystemEventSource::GraphQL
+                    } else {
+                        SystemEventSource::Rest
+                    };
+                    EventSourceFuture::new(source, service.call(request))
+                })
                 .app_data(schema_data.clone())
                 .app_data(component_manager.clone())
                 .app_data(entity_type_manager.clone())
@@ -233,6 +284,11 @@ impl GraphQLServer for GraphQLServerImpl {
                 .app_data(relation_instance_manager.clone())
                 .app_data(flow_manager.clone())
                 .app_data(web_resource_manager.clone())
+                .app_data(auth_manager.clone())
+                .app_data(access_control_manager.clone())
+                .app_data(system_event_manager.clone())
+                .app_data(property_validator.clone())
+                .app_data(subscription_config.clone())
                 // GraphQL API
                 .service(query_graphql)
                 .service(
@@ -241,12 +297,8 @@ impl GraphQLServer for GraphQLServerImpl {
                         .guard(guard::Header("upgrade", "websocket"))
                         .to(subscription_websocket),
                 )
-                // REST API
-                .service(crate::rest::types::components::get_components)
-                .service(crate::rest::types::entities::get_entity_types)
-                .service(crate::rest::types::entities::get_entity_type)
-                .service(crate::rest::types::relations::get_relation_types)
-                .service(crate::rest::types::relations::get_relation_type)
+                // REST API and Server-Sent Events
+                .configure(crate::rest::configure_rest_services)
                 // Web Resource API
                 .service(web::resource("/{web_resource_base_path}/{path:.*}").route(web::get().to(handle_web_resource)))
                 .service(web::resource("/{path:.*}").route(web::get().to(handle_root_web_resource)))
@@ -262,18 +314,13 @@ impl GraphQLServer for GraphQLServerImpl {
         }
 
         let r_http_server = if graphql_server_config.secure.unwrap_or(false) {
-            let cert_file = &mut BufReader::new(File::open("./keys/cert.pem").unwrap());
-            let key_file = &mut BufReader::new(File::open("./keys/key.pem").unwrap());
-            let cert_chain = certs(cert_file).unwrap().into_iter().map(Certificate).collect();
-            let mut keys: Vec<PrivateKey> = pkcs8_private_keys(key_file).unwrap().into_iter().map(PrivateKey).collect();
-            if keys.is_empty() {
-                error!("Could not locate PKCS 8 private keys.");
-            }
-            let tls_config = ServerConfig::builder()
-                .with_safe_defaults()
-                .with_no_client_auth()
-                .with_single_cert(cert_chain, keys.remove(0))
-                .unwrap();
+            let tls_config = match graphql_server_config.tls.clone().unwrap_or_default().to_server_config() {
+                Ok(tls_config) => tls_config,
+                Err(e) => {
+                    error!("Could not start HTTP/GraphQL server: {}", e);
+                    return;
+                }
+            };
             debug!("Starting HTTP/GraphQL server on https://{}", graphql_server_config.to_string());
             http_server.bind_rustls(graphql_server_config.to_string(), tls_config)
         } else {
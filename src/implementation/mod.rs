@@ -1,3 +1,5 @@
+pub use access_control_manager_impl::*;
+pub use auth_manager_impl::*;
 pub use component_behaviour_manager_impl::*;
 pub use component_manager_impl::*;
 pub use entity_behaviour_manager_impl::*;
@@ -7,8 +9,11 @@ pub use entity_vertex_manager_impl::*;
 pub use event_manager_impl::*;
 pub use flow_manager_impl::*;
 pub use graph_database_impl::*;
+pub use graphql_query_service_impl::*;
 pub use graphql_server_impl::*;
 pub use plugin_registry_impl::*;
+pub use property_journal_impl::*;
+pub use property_validator_impl::*;
 pub use reactive_entity_instance_manager_impl::*;
 pub use reactive_flow_manager_impl::*;
 pub use reactive_relation_instance_manager_impl::*;
@@ -17,8 +22,12 @@ pub use relation_edge_manager_impl::*;
 pub use relation_instance_manager_impl::*;
 pub use relation_type_manager_impl::*;
 pub use shutdown_manager_impl::*;
+pub use snapshot_manager_impl::*;
+pub use transaction_manager_impl::*;
 pub use web_resource_manager_impl::*;
 
+pub mod access_control_manager_impl;
+pub mod auth_manager_impl;
 pub mod component_behaviour_manager_impl;
 pub mod component_manager_impl;
 pub mod entity_behaviour_manager_impl;
@@ -28,8 +37,11 @@ pub mod entity_vertex_manager_impl;
 pub mod event_manager_impl;
 pub mod flow_manager_impl;
 pub mod graph_database_impl;
+pub mod graphql_query_service_impl;
 pub mod graphql_server_impl;
 pub mod plugin_registry_impl;
+pub mod property_journal_impl;
+pub mod property_validator_impl;
 pub mod reactive_entity_instance_manager_impl;
 pub mod reactive_flow_manager_impl;
 pub mod reactive_relation_instance_manager_impl;
@@ -38,4 +50,6 @@ pub mod relation_edge_manager_impl;
 pub mod relation_instance_manager_impl;
 pub mod relation_type_manager_impl;
 pub mod shutdown_manager_impl;
+pub mod snapshot_manager_impl;
+pub mod transaction_manager_impl;
 pub mod web_resource_manager_impl;
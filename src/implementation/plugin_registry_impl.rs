@@ -1,22 +1,32 @@
 use std::collections::HashMap;
 use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::di::*;
 use async_trait::async_trait;
+use indradb::EdgeKey;
 use libloading::Library;
-use log::{debug, error};
+use log::{debug, error, info, warn};
+use serde_json::json;
+use uuid::Uuid;
 
 use crate::api::{
-    ComponentBehaviourManager, ComponentManager, EntityBehaviourManager, EntityTypeManager, Lifecycle, PluginRegistry, ReactiveEntityInstanceManager,
-    ReactiveFlowManager, ReactiveRelationInstanceManager, RelationBehaviourManager, RelationTypeManager, WebResourceManager,
+    with_event_source, ComponentBehaviourManager, ComponentManager, EntityBehaviourManager, EntityTypeManager, GraphQLQueryService, Lifecycle, PluginRegistry,
+    ReactiveEntityInstanceManager, ReactiveFlowManager, ReactiveRelationInstanceManager, RelationBehaviourManager, RelationTypeManager, ShutdownManager,
+    SystemEvent, SystemEventManager, SystemEventSource, WebResourceManager,
 };
 use crate::plugin::registrar::PluginRegistrar;
 use crate::plugin::{
-    ComponentManagerImpl, EntityInstanceManagerImpl, EntityTypeManagerImpl, FlowManagerImpl, PluginContextImpl, PluginProxy, PluginsConfig,
+    resolve_load_order, ComponentManagerImpl, EntityInstanceManagerImpl, EntityTypeManagerImpl, FlowManagerImpl, PluginConfig, PluginContextImpl,
+    PluginContributions, PluginDependencyError, PluginDeployConfig, PluginLoadError, PluginProxy, PluginState, PluginStatus, PluginsConfig,
     RelationInstanceManagerImpl, RelationTypeManagerImpl,
 };
-use crate::plugins::{Plugin, PluginDeclaration, PluginError, INEXOR_RGF_PLUGIN_VERSION, RUSTC_VERSION};
+#[cfg(feature = "plugin-api-next")]
+use crate::plugin::{GraphQLQueryExecutorImpl, GraphQueryManagerImpl, SystemEventManagerImpl};
+use crate::plugins::{FlowProvider, Plugin, PluginDeclaration, PluginError, INEXOR_RGF_PLUGIN_VERSION, RUSTC_VERSION};
 
 #[wrapper]
 pub struct PluginProxies(RwLock<HashMap<String, Arc<PluginProxy>>>);
@@ -34,6 +44,78 @@ fn provide_plugin_libraries() -> PluginLibraries {
     PluginLibraries(RwLock::new(Vec::new()))
 }
 
+#[wrapper]
+pub struct PluginContributionsStorage(RwLock<HashMap<String, PluginContributions>>);
+
+#[provides]
+fn provide_plugin_contributions() -> PluginContributionsStorage {
+    PluginContributionsStorage(RwLock::new(HashMap::new()))
+}
+
+/// The names of the initialized plugins in the order of initialization. The plugins are shut down
+/// in reverse order.
+#[wrapper]
+pub struct PluginLoadOrder(RwLock<Vec<String>>);
+
+#[provides]
+fn provide_plugin_load_order() -> PluginLoadOrder {
+    PluginLoadOrder(RwLock::new(Vec::new()))
+}
+
+/// The status of the configured and deployed plugins.
+#[wrapper]
+pub struct PluginStatuses(RwLock<HashMap<String, PluginStatus>>);
+
+#[provides]
+fn provide_plugin_statuses() -> PluginStatuses {
+    PluginStatuses(RwLock::new(HashMap::new()))
+}
+
+/// If true, the application shuts down if a plugin fails to load at startup.
+#[wrapper]
+pub struct PluginStrictMode(AtomicBool);
+
+#[provides]
+fn provide_plugin_strict_mode() -> PluginStrictMode {
+    PluginStrictMode(AtomicBool::new(false))
+}
+
+/// If true, the application has been started and the flows of plugins which are loaded at
+/// runtime have to be created by the registry.
+#[wrapper]
+pub struct PluginRegistryStarted(AtomicBool);
+
+#[provides]
+fn provide_plugin_registry_started() -> PluginRegistryStarted {
+    PluginRegistryStarted(AtomicBool::new(false))
+}
+
+/// The configurations of the plugins in `plugins.toml`.
+#[wrapper]
+pub struct PluginConfigs(RwLock<HashMap<String, PluginConfig>>);
+
+#[provides]
+fn provide_plugin_configs() -> PluginConfigs {
+    PluginConfigs(RwLock::new(HashMap::new()))
+}
+
+/// The state of the deploy directory.
+#[derive(Default)]
+pub struct PluginDeployState {
+    config: Option<PluginDeployConfig>,
+    last_scan: Option<Instant>,
+    /// The modification time of each deployed library and the names of the contained plugins.
+    libraries: HashMap<PathBuf, (SystemTime, Vec<String>)>,
+}
+
+#[wrapper]
+pub struct PluginDeployStateWrapper(RwLock<PluginDeployState>);
+
+#[provides]
+fn provide_plugin_deploy_state() -> PluginDeployStateWrapper {
+    PluginDeployStateWrapper(RwLock::new(PluginDeployState::default()))
+}
+
 #[component]
 pub struct PluginRegistryImpl {
     component_behaviour_manager: Wrc<dyn ComponentBehaviourManager>,
@@ -46,9 +128,457 @@ pub struct PluginRegistryImpl {
     reactive_relation_instance_manager: Wrc<dyn ReactiveRelationInstanceManager>,
     reactive_flow_manager: Wrc<dyn ReactiveFlowManager>,
     web_resource_manager: Wrc<dyn WebResourceManager>,
+    shutdown_manager: Wrc<dyn ShutdownManager>,
+    event_manager: Wrc<dyn SystemEventManager>,
+    // Only the plugin API revision of the feature `plugin-api-next` provides GraphQL queries to plugins
+    #[cfg_attr(not(feature = "plugin-api-next"), allow(dead_code))]
+    graphql_query_service: Wrc<dyn GraphQLQueryService>,
 
     pub plugins: PluginProxies,
     pub libraries: PluginLibraries,
+    pub contributions: PluginContributionsStorage,
+    load_order: PluginLoadOrder,
+    statuses: PluginStatuses,
+    strict: PluginStrictMode,
+    started: PluginRegistryStarted,
+    configs: PluginConfigs,
+    deploy: PluginDeployStateWrapper,
+}
+
+impl PluginRegistryImpl {
+    fn set_state(&self, name: String, path: String, state: PluginState) {
+        debug!("Plugin {} is {}", name, state);
+        self.statuses.0.write().unwrap().insert(name.clone(), PluginStatus::new(name, path, state));
+    }
+
+    /// Returns an error containing the reason if the plugin with the given name isn't initialized.
+    fn get_load_result(&self, name: String) -> Result<(), PluginError> {
+        match self.get_plugin_status(name.clone()) {
+            Some(status) if status.state == PluginState::Initialized => Ok(()),
+            Some(status) => match status.error {
+                Some(error) => Err(PluginError::Other { message: error.to_string() }),
+                None => Err(PluginError::Other {
+                    message: format!("Plugin {} is {}", name, status.state),
+                }),
+            },
+            None => Err(PluginError::PluginCreationError),
+        }
+    }
+
+    /// Returns true, if one of the configured or deployed plugins failed to load.
+    fn has_failed_plugins(&self) -> bool {
+        self.statuses.0.read().unwrap().values().any(|status| status.state == PluginState::Failed)
+    }
+
+    fn set_failed(&self, name: String, path: String, error: PluginLoadError) {
+        error!("Failed to load plugin {} from {}: {}", name, path, error);
+        let after = json!({ "path": path, "error": error.to_string() });
+        self.statuses
+            .0
+            .write()
+            .unwrap()
+            .insert(name.clone(), PluginStatus::failed(name.clone(), path, error));
+        self.event_manager.emit_event_with_data(SystemEvent::PluginFailed(name), None, Some(after));
+    }
+
+    /// Initializes the loaded plugin with the given name and registers its providers.
+    fn initialize_plugin(&self, name: String, path: String) {
+        let plugin_proxy = self.get(name.clone());
+        match plugin_proxy {
+            Some(plugin_proxy) => {
+                if let Err(e) = plugin_proxy.init() {
+                    // Don't keep a broken plugin, its dependents must not be loaded against it
+                    drop(plugin_proxy);
+                    self.remove_plugin(&name);
+                    self.set_failed(name, path, PluginLoadError::InitFailed { message: format!("{:?}", e) });
+                } else {
+                    let mut contributions = PluginContributions::new(path.clone());
+                    if let Ok(component_provider) = plugin_proxy.get_component_provider() {
+                        contributions.components = component_provider.get_components().into_iter().map(|component| component.name).collect();
+                        self.component_manager.add_provider(component_provider);
+                    }
+                    if let Ok(entity_type_provider) = plugin_proxy.get_entity_type_provider() {
+                        contributions.entity_types = entity_type_provider
+                            .get_entity_types()
+                            .into_iter()
+                            .map(|entity_type| entity_type.name)
+                            .collect();
+                        self.entity_type_manager.add_provider(entity_type_provider);
+                    }
+                    if let Ok(relation_type_provider) = plugin_proxy.get_relation_type_provider() {
+                        contributions.relation_types = relation_type_provider
+                            .get_relation_types()
+                            .into_iter()
+                            .map(|relation_type| relation_type.type_name)
+                            .collect();
+                        self.relation_type_manager.add_provider(relation_type_provider);
+                    }
+                    if let Ok(component_behaviour_provider) = plugin_proxy.get_component_behaviour_provider() {
+                        self.component_behaviour_manager.add_provider(component_behaviour_provider.clone());
+                        contributions.component_behaviour_provider = Some(component_behaviour_provider);
+                    }
+                    if let Ok(entity_behaviour_provider) = plugin_proxy.get_entity_behaviour_provider() {
+                        self.entity_behaviour_manager.add_provider(entity_behaviour_provider.clone());
+                        contributions.entity_behaviour_provider = Some(entity_behaviour_provider);
+                    }
+                    if let Ok(relation_behaviour_provider) = plugin_proxy.get_relation_behaviour_provider() {
+                        self.relation_behaviour_manager.add_provider(relation_behaviour_provider.clone());
+                        contributions.relation_behaviour_provider = Some(relation_behaviour_provider);
+                    }
+                    if let Ok(flow_provider) = plugin_proxy.get_flow_provider() {
+                        contributions.flows = flow_provider.get_flows().into_iter().map(|flow| flow.id).collect();
+                        self.reactive_flow_manager.add_provider(flow_provider.clone());
+                        // At startup the flows are created by the reactive flow manager
+                        if self.started.0.load(Ordering::Relaxed) {
+                            self.create_provided_flows(flow_provider.clone());
+                        }
+                        contributions.flow_provider = Some(flow_provider);
+                    }
+                    if let Ok(web_resource_provider) = plugin_proxy.get_web_resource_provider() {
+                        contributions.web_resources.push(web_resource_provider.get_base_path());
+                        self.web_resource_manager.add_provider(web_resource_provider);
+                    }
+                    // Apply the behaviours to the already existing instances (after a reload)
+                    self.add_behaviours_to_existing_instances(&contributions);
+                    self.contributions.0.write().unwrap().insert(name.clone(), contributions);
+                    self.load_order.0.write().unwrap().push(name.clone());
+                    let context = Arc::new(self.create_plugin_context(&name));
+                    let _ = plugin_proxy.set_context(context);
+                    // The providers stay registered, the plugin can be deactivated explicitly
+                    match plugin_proxy.post_init() {
+                        Ok(_) => {
+                            self.set_state(name.clone(), path.clone(), PluginState::Initialized);
+                            self.event_manager
+                                .emit_event_with_data(SystemEvent::PluginLoaded(name), None, Some(json!({ "path": path })));
+                        }
+                        Err(e) => self.set_failed(name, path, PluginLoadError::PostInitFailed { message: format!("{:?}", e) }),
+                    }
+                }
+            }
+            None => {
+                self.set_failed(name, path.clone(), PluginLoadError::PluginNotFound { path });
+            }
+        }
+    }
+
+    /// Creates the context which provides the managers of the application to the plugin.
+    #[cfg(not(feature = "plugin-api-next"))]
+    fn create_plugin_context(&self, _name: &str) -> PluginContextImpl {
+        PluginContextImpl::new(
+            Arc::new(ComponentManagerImpl::new(self.component_manager.clone())),
+            Arc::new(EntityTypeManagerImpl::new(self.entity_type_manager.clone())),
+            Arc::new(RelationTypeManagerImpl::new(self.relation_type_manager.clone())),
+            Arc::new(EntityInstanceManagerImpl::new(
+                self.entity_type_manager.clone(),
+                self.reactive_entity_instance_manager.clone(),
+            )),
+            Arc::new(RelationInstanceManagerImpl::new(
+                self.relation_type_manager.clone(),
+                self.reactive_relation_instance_manager.clone(),
+            )),
+            Arc::new(FlowManagerImpl::new(self.reactive_flow_manager.clone())),
+        )
+    }
+
+    /// Creates the context which provides the managers of the application, the system events, the
+    /// graph queries, the GraphQL queries and the settings of the plugin to the plugin.
+    #[cfg(feature = "plugin-api-next")]
+    fn create_plugin_context(&self, name: &str) -> PluginContextImpl {
+        let graph_query_manager = GraphQueryManagerImpl::new(
+            self.reactive_entity_instance_manager.clone(),
+            self.reactive_relation_instance_manager.clone(),
+            self.reactive_flow_manager.clone(),
+        );
+        PluginContextImpl::new(
+            Arc::new(ComponentManagerImpl::new(self.component_manager.clone())),
+            Arc::new(EntityTypeManagerImpl::new(self.entity_type_manager.clone())),
+            Arc::new(RelationTypeManagerImpl::new(self.relation_type_manager.clone())),
+            Arc::new(EntityInstanceManagerImpl::new(
+                self.entity_type_manager.clone(),
+                self.reactive_entity_instance_manager.clone(),
+            )),
+            Arc::new(RelationInstanceManagerImpl::new(
+                self.relation_type_manager.clone(),
+                self.reactive_relation_instance_manager.clone(),
+            )),
+            Arc::new(FlowManagerImpl::new(self.reactive_flow_manager.clone())),
+            Arc::new(SystemEventManagerImpl::new(self.event_manager.clone())),
+            Arc::new(graph_query_manager),
+            Arc::new(GraphQLQueryExecutorImpl::new(self.graphql_query_service.clone())),
+            self.get_plugin_config(name),
+        )
+    }
+
+    /// Returns the settings of the `[plugin.config]` table of the plugin with the given name.
+    #[cfg(feature = "plugin-api-next")]
+    fn get_plugin_config(&self, name: &str) -> Option<serde_json::Value> {
+        let config = self
+            .configs
+            .0
+            .read()
+            .unwrap()
+            .get(name)
+            .and_then(|plugin_config| plugin_config.config.clone())?;
+        match serde_json::to_value(config) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                error!("Failed to convert the configuration of plugin {}: {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Removes the proxy of the plugin with the given name and drops the library if it doesn't
+    /// contain any other loaded plugin.
+    fn remove_plugin(&self, name: &str) {
+        let plugin_proxy = match self.plugins.0.write().unwrap().remove(name) {
+            Some(plugin_proxy) => plugin_proxy,
+            None => return,
+        };
+        let library = plugin_proxy.lib.clone();
+        drop(plugin_proxy);
+        if !self
+            .plugins
+            .0
+            .read()
+            .unwrap()
+            .values()
+            .any(|plugin_proxy| Arc::ptr_eq(&plugin_proxy.lib, &library))
+        {
+            self.libraries.0.write().unwrap().retain(|l| !Arc::ptr_eq(l, &library));
+        }
+    }
+
+    /// Returns true, if the plugin with the given name has been initialized successfully.
+    fn is_initialized(&self, name: &str) -> bool {
+        self.statuses
+            .0
+            .read()
+            .unwrap()
+            .get(name)
+            .map(|status| status.state == PluginState::Initialized)
+            .unwrap_or(false)
+    }
+
+    /// Returns the dependencies of the plugin with the given name which are not initialized.
+    fn get_unmet_dependencies(&self, name: &str) -> Vec<String> {
+        match self.configs.0.read().unwrap().get(name) {
+            Some(plugin_config) => plugin_config
+                .dependencies
+                .iter()
+                .filter(|dependency| !self.is_initialized(dependency))
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the names of the loaded plugins which depend on the plugin with the given name.
+    fn get_dependents(&self, name: &str) -> Vec<String> {
+        self.configs
+            .0
+            .read()
+            .unwrap()
+            .values()
+            .filter(|plugin_config| plugin_config.dependencies.iter().any(|dependency| dependency == name) && self.has(plugin_config.name.clone()))
+            .map(|plugin_config| plugin_config.name.clone())
+            .collect()
+    }
+
+    /// Loads the library at the given path and initializes all contained plugins. Returns the
+    /// names of the contained plugins.
+    fn deploy_library(&self, path: String) -> Vec<String> {
+        let names = unsafe {
+            match self.load(path.clone()) {
+                Ok(names) => names,
+                Err(e) => {
+                    error!("Failed to deploy plugin library {}: {}", path, e);
+                    return Vec::new();
+                }
+            }
+        };
+        for name in names.iter() {
+            info!("Deployed plugin {} from {}", name, path);
+            self.set_state(name.clone(), path.clone(), PluginState::Loaded);
+            self.initialize_plugin(name.clone(), path.clone());
+        }
+        names
+    }
+
+    /// Unloads the plugins with the given names.
+    fn undeploy_plugins(&self, names: &[String]) {
+        for name in names.iter() {
+            if self.unload_plugin(name.clone()).is_ok() {
+                info!("Undeployed plugin {}", name);
+            }
+            self.statuses.0.write().unwrap().remove(name);
+        }
+    }
+
+    /// Returns the libraries in the deploy directory and their modification time.
+    fn scan_deploy_directory(&self, path: &str) -> HashMap<PathBuf, SystemTime> {
+        let mut libraries = HashMap::new();
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => return libraries,
+        };
+        for entry in entries.flatten() {
+            let library_path = entry.path();
+            if library_path.extension().and_then(OsStr::to_str) != Some(std::env::consts::DLL_EXTENSION) {
+                continue;
+            }
+            if let Ok(modified) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                libraries.insert(library_path, modified);
+            }
+        }
+        libraries
+    }
+
+    /// Creates the flows of the given flow provider which don't exist yet.
+    fn create_provided_flows(&self, flow_provider: Arc<dyn FlowProvider>) {
+        for flow in flow_provider.get_flows() {
+            if self.reactive_flow_manager.has(flow.id) {
+                continue;
+            }
+            debug!("Creating provided flow {}", flow.id);
+            let id = flow.id;
+            if let Err(e) = with_event_source(SystemEventSource::Provider, || self.reactive_flow_manager.create(flow)) {
+                error!("Failed to create provided flow {}: {}", id, e);
+            }
+        }
+    }
+
+    /// Deletes the flow with the given id and its entity and relation instances from the graph.
+    fn delete_provided_flow(&self, id: Uuid) {
+        if let Some(reactive_flow) = self.reactive_flow_manager.get(id) {
+            let edge_keys: Vec<EdgeKey> = reactive_flow.relation_instances.read().unwrap().keys().cloned().collect();
+            let ids: Vec<Uuid> = reactive_flow.entity_instances.read().unwrap().keys().cloned().collect();
+            self.reactive_flow_manager.delete(id);
+            // Reverse order: first relations then entities
+            for edge_key in edge_keys {
+                self.reactive_relation_instance_manager.delete(edge_key);
+            }
+            for id in ids {
+                self.reactive_entity_instance_manager.delete(id);
+            }
+        }
+    }
+
+    /// Adds the behaviours of the given plugin contributions to all existing reactive instances.
+    fn add_behaviours_to_existing_instances(&self, contributions: &PluginContributions) {
+        for entity_instance in self.reactive_entity_instance_manager.get_entity_instances() {
+            if let Some(component_behaviour_provider) = &contributions.component_behaviour_provider {
+                component_behaviour_provider.add_behaviours_to_entity(entity_instance.clone());
+            }
+            if let Some(entity_behaviour_provider) = &contributions.entity_behaviour_provider {
+                entity_behaviour_provider.add_behaviours(entity_instance.clone());
+            }
+        }
+        for relation_instance in self.reactive_relation_instance_manager.get_relation_instances() {
+            if let Some(component_behaviour_provider) = &contributions.component_behaviour_provider {
+                component_behaviour_provider.add_behaviours_to_relation(relation_instance.clone());
+            }
+            if let Some(relation_behaviour_provider) = &contributions.relation_behaviour_provider {
+                relation_behaviour_provider.add_behaviours(relation_instance.clone());
+            }
+        }
+    }
+
+    /// Removes the behaviours of the given plugin contributions from all existing reactive instances.
+    fn remove_behaviours_from_existing_instances(&self, contributions: &PluginContributions) {
+        for relation_instance in self.reactive_relation_instance_manager.get_relation_instances() {
+            if let Some(relation_behaviour_provider) = &contributions.relation_behaviour_provider {
+                relation_behaviour_provider.remove_behaviours(relation_instance.clone());
+            }
+            if let Some(component_behaviour_provider) = &contributions.component_behaviour_provider {
+                component_behaviour_provider.remove_behaviours_from_relation(relation_instance.clone());
+            }
+        }
+        for entity_instance in self.reactive_entity_instance_manager.get_entity_instances() {
+            if let Some(entity_behaviour_provider) = &contributions.entity_behaviour_provider {
+                entity_behaviour_provider.remove_behaviours(entity_instance.clone());
+            }
+            if let Some(component_behaviour_provider) = &contributions.component_behaviour_provider {
+                component_behaviour_provider.remove_behaviours_from_entity(entity_instance.clone());
+            }
+        }
+    }
+
+    /// Loads the library at the given path and registers the plugin with the given name or all
+    /// contained plugins. Returns the names of the registered plugins.
+    ///
+    /// # Safety
+    ///
+    /// The library must be implemented using the `plugin_declaration!()` macro.
+    unsafe fn load_library(&self, library_path: String, name: Option<&str>) -> Result<Vec<String>, PluginLoadError> {
+        debug!("Loading library {}", library_path.as_str());
+        if !std::path::Path::new(&library_path).exists() {
+            return Err(PluginLoadError::MissingFile { path: library_path });
+        }
+        // Load the library into memory
+        // <P: AsRef<OsStr>>
+        let library_path_os = OsStr::new(library_path.as_str());
+        let library = Library::new(library_path_os);
+        match library {
+            Ok(library) => {
+                let library = Arc::new(library);
+                // Get a pointer to the plugin_declaration symbol.
+                let decl = match library.get::<*mut PluginDeclaration>(b"plugin_declaration\0") {
+                    Ok(decl) => decl.read(),
+                    Err(_) => {
+                        return Err(PluginLoadError::MissingDeclaration { path: library_path });
+                    }
+                };
+                // version checks to prevent accidental ABI incompatibilities
+                if decl.rustc_version != RUSTC_VERSION {
+                    return Err(PluginLoadError::RustcVersionMismatch {
+                        path: library_path,
+                        version: decl.rustc_version.to_string(),
+                        expected: RUSTC_VERSION.to_string(),
+                    });
+                }
+                if decl.inexor_rgf_plugin_version != INEXOR_RGF_PLUGIN_VERSION {
+                    return Err(PluginLoadError::ApiVersionMismatch {
+                        path: library_path,
+                        version: decl.inexor_rgf_plugin_version.to_string(),
+                        expected: INEXOR_RGF_PLUGIN_VERSION.to_string(),
+                    });
+                }
+
+                let mut registrar = PluginRegistrar::new(Arc::clone(&library));
+
+                (decl.register)(&mut registrar);
+
+                // add the requested plugins to the plugins map, the proxies of plugins which are
+                // already loaded from the same library must not be replaced
+                if let Some(name) = name {
+                    if !registrar.plugins.contains_key(name) {
+                        return Err(PluginLoadError::PluginNotFound { path: library_path });
+                    }
+                }
+                let mut names = Vec::new();
+                {
+                    let mut plugins = self.plugins.0.write().unwrap();
+                    for (plugin_name, plugin_proxy) in registrar.plugins {
+                        if name.map_or(true, |name| name == plugin_name) && !plugins.contains_key(&plugin_name) {
+                            plugins.insert(plugin_name.clone(), plugin_proxy);
+                            names.push(plugin_name);
+                        }
+                    }
+                }
+                // and make sure PluginRegistry keeps a reference to the library
+                if !names.is_empty() {
+                    self.libraries.0.write().unwrap().push(library);
+                }
+
+                Ok(names)
+            }
+            Err(e) => Err(PluginLoadError::LibraryError {
+                path: library_path,
+                message: e.to_string(),
+            }),
+        }
+    }
 }
 
 #[async_trait]
@@ -71,11 +601,26 @@ impl PluginRegistry for PluginRegistryImpl {
                 let plugins_config: Result<PluginsConfig, _> = toml::from_str(&toml_string);
                 match plugins_config {
                     Ok(plugins_config) => {
-                        for plugin_config in plugins_config.plugin.iter() {
-                            if plugin_config.active {
-                                self.load_plugin(plugin_config.name.clone(), plugin_config.path.clone());
+                        {
+                            let mut configs = self.configs.0.write().unwrap();
+                            for plugin_config in plugins_config.plugin.iter() {
+                                configs.insert(plugin_config.name.clone(), plugin_config.clone());
+                            }
+                        }
+                        self.strict.0.store(plugins_config.strict, Ordering::Relaxed);
+                        let (load_order, errors) = resolve_load_order(&plugins_config.plugin);
+                        for plugin_config in plugins_config.plugin.iter().filter(|plugin_config| !plugin_config.active) {
+                            self.set_state(plugin_config.name.clone(), plugin_config.path.clone(), PluginState::Deactivated);
+                        }
+                        for e in errors {
+                            if let Some(plugin_config) = plugins_config.plugin.iter().find(|plugin_config| plugin_config.name == e.name()) {
+                                self.set_failed(plugin_config.name.clone(), plugin_config.path.clone(), PluginLoadError::UnmetDependencies(e));
                             }
                         }
+                        for plugin_config in load_order {
+                            self.load_plugin(plugin_config.name.clone(), plugin_config.path.clone());
+                        }
+                        self.deploy.0.write().unwrap().config = plugins_config.deploy.filter(|deploy_config| deploy_config.enabled);
                     }
                     Err(_) => {
                         error!("Failed to load plugin configuration from {}: Invalid TOML:", "./config/plugins.toml");
@@ -89,87 +634,215 @@ impl PluginRegistry for PluginRegistryImpl {
     }
 
     fn load_plugin(&self, name: String, path: String) {
+        // A dependency may have failed to load
+        let unmet_dependencies = self.get_unmet_dependencies(&name);
+        if !unmet_dependencies.is_empty() {
+            let error = PluginDependencyError::UnmetDependencies {
+                name: name.clone(),
+                dependencies: unmet_dependencies,
+            };
+            self.set_failed(name, path, PluginLoadError::UnmetDependencies(error));
+            return;
+        }
         unsafe {
-            let result = self.load(path.clone());
-            if result.is_err() {
-                error!("Failed to load plugin {} from {}", name, path);
+            if let Err(e) = self.load_library(path.clone(), Some(&name)) {
+                self.set_failed(name, path, e);
                 return;
             }
-            let plugin_proxy = self.get(name.clone());
-            match plugin_proxy {
-                Some(plugin_proxy) => {
-                    if plugin_proxy.init().is_ok() {
-                        if let Ok(component_provider) = plugin_proxy.get_component_provider() {
-                            self.component_manager.add_provider(component_provider);
-                        }
-                        if let Ok(entity_type_provider) = plugin_proxy.get_entity_type_provider() {
-                            self.entity_type_manager.add_provider(entity_type_provider);
-                        }
-                        if let Ok(relation_type_provider) = plugin_proxy.get_relation_type_provider() {
-                            self.relation_type_manager.add_provider(relation_type_provider);
-                        }
-                        if let Ok(component_behaviour_provider) = plugin_proxy.get_component_behaviour_provider() {
-                            self.component_behaviour_manager.add_provider(component_behaviour_provider);
-                        }
-                        if let Ok(entity_behaviour_provider) = plugin_proxy.get_entity_behaviour_provider() {
-                            self.entity_behaviour_manager.add_provider(entity_behaviour_provider);
-                        }
-                        if let Ok(relation_behaviour_provider) = plugin_proxy.get_relation_behaviour_provider() {
-                            self.relation_behaviour_manager.add_provider(relation_behaviour_provider);
-                        }
-                        if let Ok(flow_provider) = plugin_proxy.get_flow_provider() {
-                            self.reactive_flow_manager.add_provider(flow_provider);
-                        }
-                        if let Ok(web_resource_provider) = plugin_proxy.get_web_resource_provider() {
-                            self.web_resource_manager.add_provider(web_resource_provider);
-                        }
-                        let component_manager = ComponentManagerImpl::new(self.component_manager.clone());
-                        let entity_type_manager = EntityTypeManagerImpl::new(self.entity_type_manager.clone());
-                        let relation_type_manager = RelationTypeManagerImpl::new(self.relation_type_manager.clone());
-                        let entity_instance_manager =
-                            EntityInstanceManagerImpl::new(self.entity_type_manager.clone(), self.reactive_entity_instance_manager.clone());
-                        let relation_instance_manager =
-                            RelationInstanceManagerImpl::new(self.relation_type_manager.clone(), self.reactive_relation_instance_manager.clone());
-                        let flow_manager = FlowManagerImpl::new(self.reactive_flow_manager.clone());
-                        let plugin_context = PluginContextImpl::new(
-                            Arc::new(component_manager),
-                            Arc::new(entity_type_manager),
-                            Arc::new(relation_type_manager),
-                            Arc::new(entity_instance_manager),
-                            Arc::new(relation_instance_manager),
-                            Arc::new(flow_manager),
-                        );
-                        let context = Arc::new(plugin_context);
-                        let _ = plugin_proxy.set_context(context);
-                        let _ = plugin_proxy.post_init();
-                    }
-                }
-                None => {
-                    error!("Failed to initialize plugin {} from {}", name, path);
-                    // TODO: Handle error: plugin with name not found
+        }
+        self.set_state(name.clone(), path.clone(), PluginState::Loaded);
+        self.initialize_plugin(name, path);
+    }
+
+    fn unload_plugin(&self, name: String) -> Result<(), PluginError> {
+        let plugin_proxy = match self.get(name.clone()) {
+            Some(plugin_proxy) => plugin_proxy,
+            None => {
+                error!("Failed to unload plugin {}: Not found", name);
+                return Err(PluginError::Other {
+                    message: format!("Plugin {} not found", name),
+                });
+            }
+        };
+        debug!("Unloading plugin {}", name);
+        let dependents = self.get_dependents(&name);
+        if !dependents.is_empty() {
+            warn!("Unloading plugin {} which is required by {}", name, dependents.join(", "));
+        }
+        if let Err(err) = plugin_proxy.pre_shutdown() {
+            error!("Failed to shutdown plugin {}: {:?}", name, err);
+        }
+        let contributions = self.contributions.0.write().unwrap().remove(&name);
+        if let Some(contributions) = contributions {
+            // The flows of the plugin are created again when the plugin gets loaded again
+            for id in contributions.flows.iter() {
+                self.delete_provided_flow(*id);
+            }
+            // The behaviours contain code of the library and have to be removed before the library gets dropped
+            self.remove_behaviours_from_existing_instances(&contributions);
+            if let Some(component_behaviour_provider) = contributions.component_behaviour_provider {
+                self.component_behaviour_manager.remove_provider(component_behaviour_provider);
+            }
+            if let Some(entity_behaviour_provider) = contributions.entity_behaviour_provider {
+                self.entity_behaviour_manager.remove_provider(entity_behaviour_provider);
+            }
+            if let Some(relation_behaviour_provider) = contributions.relation_behaviour_provider {
+                self.relation_behaviour_manager.remove_provider(relation_behaviour_provider);
+            }
+            if let Some(flow_provider) = contributions.flow_provider {
+                self.reactive_flow_manager.remove_provider(flow_provider);
+            }
+            for base_path in contributions.web_resources {
+                self.web_resource_manager.remove_provider(base_path);
+            }
+            for relation_type in contributions.relation_types {
+                self.relation_type_manager.delete(relation_type);
+            }
+            for entity_type in contributions.entity_types {
+                self.entity_type_manager.delete(entity_type);
+            }
+            for component in contributions.components {
+                self.component_manager.delete(component);
+            }
+        }
+        if let Err(err) = plugin_proxy.shutdown() {
+            error!("Failed to shutdown plugin {}: {:?}", name, err);
+        }
+        drop(plugin_proxy);
+        self.remove_plugin(&name);
+        self.load_order.0.write().unwrap().retain(|n| n != &name);
+        if let Some(status) = self.statuses.0.write().unwrap().get_mut(&name) {
+            status.state = PluginState::Deactivated;
+        }
+        self.event_manager.emit_event(SystemEvent::PluginUnloaded(name));
+        Ok(())
+    }
+
+    fn reload_plugin(&self, name: String) -> Result<(), PluginError> {
+        let path = match self.contributions.0.read().unwrap().get(&name) {
+            Some(contributions) => contributions.path.clone(),
+            None => {
+                error!("Failed to reload plugin {}: Not found", name);
+                return Err(PluginError::Other {
+                    message: format!("Plugin {} not found", name),
+                });
+            }
+        };
+        debug!("Reloading plugin {} from {}", name, path);
+        self.unload_plugin(name.clone())?;
+        self.load_plugin(name.clone(), path);
+        self.get_load_result(name)
+    }
+
+    fn activate_plugin(&self, name: String) -> Result<(), PluginError> {
+        let status = match self.get_plugin_status(name.clone()) {
+            Some(status) => status,
+            None => {
+                error!("Failed to activate plugin {}: Not found", name);
+                return Err(PluginError::Other {
+                    message: format!("Plugin {} not found", name),
+                });
+            }
+        };
+        debug!("Activating plugin {} from {}", name, status.path);
+        match status.state {
+            PluginState::Initialized => return Ok(()),
+            // The library has been loaded but the plugin hasn't been initialized yet
+            PluginState::Loaded if self.has(name.clone()) => self.initialize_plugin(name.clone(), status.path),
+            _ => {
+                // A plugin which failed to post-initialize is still loaded and has to be unloaded first
+                if self.has(name.clone()) {
+                    self.unload_plugin(name.clone())?;
                 }
+                self.load_plugin(name.clone(), status.path);
             }
         }
+        self.get_load_result(name)
+    }
+
+    fn deactivate_plugin(&self, name: String) -> Result<(), PluginError> {
+        debug!("Deactivating plugin {}", name);
+        self.unload_plugin(name)
+    }
+
+    fn get_plugin_statuses(&self) -> Vec<PluginStatus> {
+        let mut statuses: Vec<PluginStatus> = self.statuses.0.read().unwrap().values().cloned().collect();
+        statuses.sort_by(|a, b| a.name.cmp(&b.name));
+        statuses
+    }
+
+    fn get_plugin_status(&self, name: String) -> Option<PluginStatus> {
+        self.statuses.0.read().unwrap().get(&name).cloned()
+    }
+
+    fn get_contributions(&self, name: String) -> Option<PluginContributions> {
+        self.contributions.0.read().unwrap().get(&name).cloned()
     }
 
     fn unload_plugins(&self) {
-        // TODO: Implement an unloading mechanism (that is safe)
-        // TODO: Also implement an reloading mechanism (that is safe)
-        // TODO: Also implement an deploy mechanism (dropping a dynamically linked library into a specific folder -> load plugin automatically)
-        // TODO: Also implement a file watcher (when the library file has been overwritten -> unload old version, load new reload library)
-        // Shutdown all plugins
+        // Shutdown all plugins in reverse order of initialization, so that no plugin is shut down
+        // before the plugins which depend on it
         let reader = self.plugins.0.read().unwrap();
-        // TODO: correct (reverse) order
-        for (name, plugin) in reader.iter() {
-            if let Err(err) = plugin.pre_shutdown() {
-                error!("Failed to shutdown plugin {}: {:?}", name, err);
+        let mut shutdown_order: Vec<String> = self.load_order.0.read().unwrap().iter().rev().cloned().collect();
+        // Plugins which failed to initialize
+        shutdown_order.extend(reader.keys().filter(|name| !shutdown_order.contains(name)).cloned().collect::<Vec<String>>());
+        for name in shutdown_order.iter() {
+            if let Some(plugin) = reader.get(name) {
+                if let Err(err) = plugin.pre_shutdown() {
+                    error!("Failed to shutdown plugin {}: {:?}", name, err);
+                }
+            }
+        }
+        for name in shutdown_order.iter() {
+            if let Some(plugin) = reader.get(name) {
+                if let Err(err) = plugin.shutdown() {
+                    error!("Failed to shutdown plugin {}: {:?}", name, err);
+                }
+                self.event_manager.emit_event(SystemEvent::PluginUnloaded(name.clone()));
+            }
+        }
+    }
+
+    fn deploy_plugins(&self) {
+        let deploy_config = {
+            let mut deploy = self.deploy.0.write().unwrap();
+            let deploy_config = match deploy.config.clone() {
+                Some(deploy_config) => deploy_config,
+                None => return,
+            };
+            if let Some(last_scan) = deploy.last_scan {
+                if last_scan.elapsed() < Duration::from_millis(deploy_config.interval) {
+                    return;
+                }
+            }
+            deploy.last_scan = Some(Instant::now());
+            deploy_config
+        };
+        let libraries = self.scan_deploy_directory(&deploy_config.path);
+        let deployed: HashMap<PathBuf, (SystemTime, Vec<String>)> = self.deploy.0.read().unwrap().libraries.clone();
+        // Deleted libraries
+        for (library_path, (_, names)) in deployed.iter() {
+            if !libraries.contains_key(library_path) {
+                debug!("Plugin library {} has been deleted", library_path.display());
+                self.undeploy_plugins(names);
+                self.deploy.0.write().unwrap().libraries.remove(library_path);
             }
         }
-        // TODO: correct (reverse) order
-        for (name, plugin) in reader.iter() {
-            if let Err(err) = plugin.shutdown() {
-                error!("Failed to shutdown plugin {}: {:?}", name, err);
+        // New and overwritten libraries
+        for (library_path, modified) in libraries {
+            match deployed.get(&library_path) {
+                Some((deployed_modified, _)) if *deployed_modified == modified => continue,
+                Some((_, names)) => {
+                    debug!("Plugin library {} has been overwritten", library_path.display());
+                    self.undeploy_plugins(names);
+                }
+                None => {
+                    debug!("Plugin library {} has been added", library_path.display());
+                }
             }
+            let names = self.deploy_library(library_path.to_string_lossy().to_string());
+            // Remember the library even if it failed to load, in order to not retry until it gets overwritten
+            self.deploy.0.write().unwrap().libraries.insert(library_path, (modified, names));
         }
     }
 
@@ -182,59 +855,8 @@ impl PluginRegistry for PluginRegistryImpl {
     /// [`plugins_core::plugin_declaration!()`] macro. Trying manually implement
     /// a plugin without going through that macro will result in undefined
     /// behaviour.
-    unsafe fn load(&self, library_path: String) -> Result<(), PluginError> {
-        debug!("Loading library {}", library_path.as_str());
-        // Load the library into memory
-        // <P: AsRef<OsStr>>
-        let library_path_os = OsStr::new(library_path.as_str());
-        let library = Library::new(library_path_os);
-        match library {
-            Ok(library) => {
-                let library = Arc::new(library);
-                // Get a pointer to the plugin_declaration symbol.
-                let decl = library.get::<*mut PluginDeclaration>(b"plugin_declaration\0")?.read();
-                // version checks to prevent accidental ABI incompatibilities
-                if decl.rustc_version != RUSTC_VERSION {
-                    error!(
-                        "Cannot load plugin {} because of a compiler version mismatch: rustc {} (expected: {})",
-                        library_path.as_str(),
-                        decl.rustc_version,
-                        RUSTC_VERSION
-                    );
-                    // error!("Plugin {} Version mismatch: rustc {} expected {}", library_path.clone(), decl.rustc_version, RUSTC_VERSION);
-                    return Err(PluginError::Other {
-                        message: String::from("Version mismatch: rustc"),
-                    });
-                }
-                if decl.inexor_rgf_plugin_version != INEXOR_RGF_PLUGIN_VERSION {
-                    error!(
-                        "Cannot load plugin {} because of an API version mismatch: inexor_rgf_core_plugins {} (expected: {})",
-                        library_path.as_str(),
-                        decl.inexor_rgf_plugin_version,
-                        INEXOR_RGF_PLUGIN_VERSION
-                    );
-                    return Err(PluginError::Other {
-                        message: String::from("Version mismatch: inexor_rgf_core_plugins"),
-                    });
-                }
-
-                let mut registrar = PluginRegistrar::new(Arc::clone(&library));
-
-                (decl.register)(&mut registrar);
-
-                // add all loaded plugins to the plugins map
-                self.plugins.0.write().unwrap().extend(registrar.plugins);
-                // self.plugins.extend(registrar.plugins);
-                // and make sure PluginRegistry keeps a reference to the library
-                self.libraries.0.write().unwrap().push(library);
-
-                Ok(())
-            }
-            Err(e) => {
-                error!("Failed to load dynamic library: {}", e.to_string());
-                Err(PluginError::PluginCreationError)
-            }
-        }
+    unsafe fn load(&self, library_path: String) -> Result<Vec<String>, PluginLoadError> {
+        self.load_library(library_path, None)
     }
 
     fn plugin_init(&self, name: String) -> Result<(), PluginError> {
@@ -297,9 +919,16 @@ impl PluginRegistry for PluginRegistryImpl {
 impl Lifecycle for PluginRegistryImpl {
     fn init(&self) {
         self.load_plugins();
+        self.deploy_plugins();
+        if self.strict.0.load(Ordering::Relaxed) && self.has_failed_plugins() {
+            error!("Shutting down because at least one plugin failed to load (strict mode)");
+            self.shutdown_manager.do_shutdown();
+        }
     }
 
-    fn post_init(&self) {}
+    fn post_init(&self) {
+        self.started.0.store(true, Ordering::Relaxed);
+    }
 
     fn pre_shutdown(&self) {}
 
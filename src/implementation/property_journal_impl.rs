@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::EntityVertexManager;
+use crate::api::Lifecycle;
+use crate::api::PropertyJournal;
+use crate::api::PropertyJournalEntry;
+use crate::api::UUID_PROPERTY_JOURNAL;
+use crate::config::PropertyJournalConfig;
+use crate::di::*;
+use crate::model::ReactiveEntityInstance;
+
+/// The uncommitted property changes and the journal file. The state is shared with the
+/// observers of the property instances and the journal thread.
+pub struct PropertyJournalState {
+    config: PropertyJournalConfig,
+    entries: RwLock<HashMap<(Uuid, String), PropertyJournalEntry>>,
+    writer: Mutex<Option<BufWriter<File>>>,
+    /// The number of entries in the journal file, including the entries of committed instances.
+    lines: AtomicUsize,
+    running: AtomicBool,
+}
+
+impl PropertyJournalState {
+    pub fn new(config: PropertyJournalConfig) -> PropertyJournalState {
+        PropertyJournalState {
+            config,
+            entries: RwLock::new(HashMap::new()),
+            writer: Mutex::new(None),
+            lines: AtomicUsize::new(0),
+            running: AtomicBool::new(false),
+        }
+    }
+
+    /// Opens the journal file for appending.
+    pub fn open(&self) {
+        if !self.config.enabled {
+            return;
+        }
+        let mut writer = self.writer.lock().unwrap();
+        let lines = File::open(&self.config.path).map(|file| BufReader::new(file).lines().count()).unwrap_or(0);
+        self.lines.store(lines, Ordering::Relaxed);
+        *writer = open_journal(&self.config.path);
+    }
+
+    /// Appends the entry to the buffer of the journal file. The buffer is flushed periodically.
+    pub fn append(&self, id: Uuid, property: String, value: Value) {
+        if !self.config.enabled {
+            return;
+        }
+        let entry = PropertyJournalEntry {
+            id,
+            property: property.clone(),
+            value,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default(),
+        };
+        // Hold the writer while recording the entry, otherwise a concurrent compaction could drop it
+        let mut writer = self.writer.lock().unwrap();
+        if let Some(writer) = writer.as_mut() {
+            match write_entry(writer, &entry) {
+                Ok(_) => {
+                    self.lines.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => error!("Failed to append property change of {}.{} to the journal: {}", id, property, e),
+            }
+        }
+        self.entries.write().unwrap().insert((id, property), entry);
+    }
+
+    /// Removes the entries of the given entity instance. The entries remain in the journal file
+    /// until the next compaction.
+    pub fn remove(&self, id: Uuid) {
+        self.entries.write().unwrap().retain(|(entry_id, _), _| *entry_id != id);
+    }
+
+    /// Removes all entries. The entries remain in the journal file until the next compaction.
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+    }
+
+    /// Returns the uncommitted entries ordered by time.
+    pub fn get_entries(&self) -> Vec<PropertyJournalEntry> {
+        let mut entries: Vec<PropertyJournalEntry> = self.entries.read().unwrap().values().cloned().collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+
+    /// Writes the buffered entries to disk.
+    pub fn flush(&self) {
+        if let Some(writer) = self.writer.lock().unwrap().as_mut() {
+            if let Err(e) = writer.flush() {
+                error!("Failed to flush the journal {}: {}", self.config.path, e);
+            }
+        }
+    }
+
+    /// Replaces the journal file with a journal file which only contains the uncommitted
+    /// entries. Does nothing if the journal file doesn't contain entries of committed instances.
+    ///
+    /// The new journal file is written next to the journal file and renamed afterwards, so that
+    /// a crash during the compaction leaves either the old or the new journal file.
+    pub fn compact(&self) {
+        if !self.config.enabled {
+            return;
+        }
+        let mut writer = self.writer.lock().unwrap();
+        let entries = self.get_entries();
+        // The journal is closed or doesn't contain entries of committed instances
+        if writer.is_none() || self.lines.load(Ordering::Relaxed) == entries.len() {
+            return;
+        }
+        let compacted_path = format!("{}.compact", self.config.path);
+        match write_journal(&compacted_path, &entries).and_then(|_| std::fs::rename(&compacted_path, &self.config.path)) {
+            Ok(_) => {
+                debug!("Compacted the journal {} to {} entries", self.config.path, entries.len());
+                self.lines.store(entries.len(), Ordering::Relaxed);
+                // The buffer of the previous journal file is obsolete
+                *writer = open_journal(&self.config.path);
+            }
+            Err(e) => {
+                error!("Failed to compact the journal {}: {}", self.config.path, e);
+                let _ = std::fs::remove_file(&compacted_path);
+            }
+        }
+    }
+
+    /// Flushes and closes the journal file.
+    pub fn close(&self) {
+        self.flush();
+        *self.writer.lock().unwrap() = None;
+    }
+}
+
+fn write_entry<W: Write>(writer: &mut W, entry: &PropertyJournalEntry) -> std::io::Result<()> {
+    let line = serde_json::to_string(entry)?;
+    writeln!(writer, "{}", line)
+}
+
+fn write_journal(path: &str, entries: &[PropertyJournalEntry]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for entry in entries.iter() {
+        write_entry(&mut writer, entry)?;
+    }
+    writer.flush()?;
+    writer.get_ref().sync_all()
+}
+
+fn open_journal(path: &str) -> Option<BufWriter<File>> {
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => Some(BufWriter::new(file)),
+        Err(e) => {
+            error!("Failed to open the journal {}: {}", path, e);
+            None
+        }
+    }
+}
+
+/// Reads the journal file and returns the latest value of each property. Invalid entries are
+/// skipped, because the last entry might be incomplete after a crash.
+pub fn read_journal(path: &str) -> HashMap<(Uuid, String), PropertyJournalEntry> {
+    let mut latest_values = HashMap::<(Uuid, String), PropertyJournalEntry>::new();
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return latest_values,
+    };
+    for line in BufReader::new(file).lines().flatten() {
+        match serde_json::from_str::<PropertyJournalEntry>(&line) {
+            Ok(entry) => {
+                latest_values.insert((entry.id, entry.property.clone()), entry);
+            }
+            Err(e) => warn!("Skipping invalid journal entry in {}: {}", path, e),
+        }
+    }
+    latest_values
+}
+
+/// Writes the latest values of the journal file to the graph database.
+pub fn replay_journal(path: &str, entity_vertex_manager: &dyn EntityVertexManager) {
+    let latest_values = read_journal(path);
+    if latest_values.is_empty() {
+        return;
+    }
+    info!("Replaying {} uncommitted property changes from {}", latest_values.len(), path);
+    for ((id, property), entry) in latest_values {
+        if !entity_vertex_manager.has(id) {
+            debug!("Skipping journal entry of the non-existent entity instance {}", id);
+            continue;
+        }
+        let mut properties = HashMap::new();
+        properties.insert(property, entry.value);
+        entity_vertex_manager.commit(id, properties);
+    }
+}
+
+#[wrapper]
+pub struct PropertyJournalStateWrapper(Arc<PropertyJournalState>);
+
+#[provides]
+fn create_property_journal_state() -> PropertyJournalStateWrapper {
+    let config = crate::config::get_property_journal_config().unwrap_or_else(|e| panic!("Failed to load journal configuration: {}", e));
+    PropertyJournalStateWrapper(Arc::new(PropertyJournalState::new(config)))
+}
+
+#[component]
+pub struct PropertyJournalImpl {
+    entity_vertex_manager: Wrc<dyn EntityVertexManager>,
+
+    state: PropertyJournalStateWrapper,
+}
+
+#[async_trait]
+#[provides]
+impl PropertyJournal for PropertyJournalImpl {
+    fn observe(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) {
+        if !self.state.0.config.enabled {
+            return;
+        }
+        let id = reactive_entity_instance.id;
+        for property_instance in reactive_entity_instance.properties.iter() {
+            let property_name = property_instance.key().clone();
+            let state = self.state.0.clone();
+            property_instance.stream.read().unwrap().observe_with_handle(
+                move |value: &Value| {
+                    state.append(id, property_name.clone(), value.clone());
+                },
+                UUID_PROPERTY_JOURNAL.as_u128(),
+            );
+        }
+    }
+
+    fn unobserve(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) {
+        if !self.state.0.config.enabled {
+            return;
+        }
+        for property_instance in reactive_entity_instance.properties.iter() {
+            property_instance.stream.read().unwrap().remove(UUID_PROPERTY_JOURNAL.as_u128());
+        }
+    }
+
+    fn append(&self, id: Uuid, property: String, value: Value) {
+        self.state.0.append(id, property, value);
+    }
+
+    fn get_entries(&self) -> Vec<PropertyJournalEntry> {
+        self.state.0.get_entries()
+    }
+
+    fn compact(&self, id: Uuid) {
+        self.state.0.remove(id);
+    }
+
+    fn compact_all(&self) {
+        self.state.0.clear();
+        self.state.0.compact();
+    }
+
+    fn flush(&self) {
+        self.state.0.flush();
+    }
+
+    fn replay(&self) {
+        replay_journal(&self.state.0.config.path, &*self.entity_vertex_manager);
+    }
+}
+
+impl PropertyJournalImpl {
+    /// Starts a thread which flushes the journal and compacts the journal file periodically.
+    fn start_scheduler(&self) {
+        let state = self.state.0.clone();
+        let flush_interval = Duration::from_millis(state.config.flush_interval);
+        let compaction_interval = Duration::from_secs(state.config.compaction_interval);
+        state.running.store(true, Ordering::Relaxed);
+        let result = thread::Builder::new().name(String::from("journal")).spawn(move || {
+            let mut last_compaction = Instant::now();
+            while state.running.load(Ordering::Relaxed) {
+                thread::sleep(flush_interval);
+                state.flush();
+                if last_compaction.elapsed() >= compaction_interval {
+                    state.compact();
+                    last_compaction = Instant::now();
+                }
+            }
+        });
+        if let Err(e) = result {
+            error!("Failed to start the journal thread: {}", e);
+        }
+    }
+}
+
+impl Lifecycle for PropertyJournalImpl {
+    fn init(&self) {
+        if !self.state.0.config.enabled {
+            return;
+        }
+        // The replayed changes are part of the graph database now
+        self.replay();
+        self.state.0.open();
+        self.compact_all();
+        self.start_scheduler();
+    }
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {
+        self.state.0.running.store(false, Ordering::Relaxed);
+    }
+
+    fn shutdown(&self) {
+        self.state.0.close();
+    }
+}
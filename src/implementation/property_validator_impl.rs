@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::api::validate_new_properties;
+use crate::api::validate_properties;
+use crate::api::ComponentManager;
+use crate::api::EntityTypeManager;
+use crate::api::Lifecycle;
+use crate::api::PropertyValidationError;
+use crate::api::PropertyValidator;
+use crate::api::RelationTypeManager;
+use crate::config::get_property_validation_config;
+use crate::config::PropertyValidationConfig;
+use crate::di::*;
+use crate::model::PropertyType;
+use crate::model::ReactiveEntityInstance;
+use crate::model::ReactiveRelationInstance;
+
+#[wrapper]
+pub struct PropertyValidationConfigWrapper(PropertyValidationConfig);
+
+#[provides]
+fn create_property_validation_config() -> PropertyValidationConfigWrapper {
+    PropertyValidationConfigWrapper(get_property_validation_config().unwrap_or_else(|e| panic!("Failed to load property validation configuration: {}", e)))
+}
+
+#[component]
+pub struct PropertyValidatorImpl {
+    component_manager: Wrc<dyn ComponentManager>,
+
+    entity_type_manager: Wrc<dyn EntityTypeManager>,
+
+    relation_type_manager: Wrc<dyn RelationTypeManager>,
+
+    config: PropertyValidationConfigWrapper,
+}
+
+impl PropertyValidatorImpl {
+    /// Appends the property types of the given components.
+    fn with_components(&self, mut property_types: Vec<PropertyType>, components: &[String]) -> Vec<PropertyType> {
+        for component in components.iter() {
+            if let Some(mut component) = self.component_manager.get(component.clone()) {
+                property_types.append(&mut component.properties);
+            }
+        }
+        property_types
+    }
+}
+
+#[async_trait]
+#[provides]
+impl PropertyValidator for PropertyValidatorImpl {
+    fn is_strict(&self) -> bool {
+        self.config.0.strict
+    }
+
+    fn get_entity_instance_property_types(&self, type_name: String, components: &[String]) -> Vec<PropertyType> {
+        let property_types = self
+            .entity_type_manager
+            .get(type_name)
+            .map(|entity_type| entity_type.properties)
+            .unwrap_or_default();
+        self.with_components(property_types, components)
+    }
+
+    fn get_relation_instance_property_types(&self, type_name: String, components: &[String]) -> Vec<PropertyType> {
+        let property_types = self
+            .relation_type_manager
+            .get_starts_with(type_name)
+            .map(|relation_type| relation_type.properties)
+            .unwrap_or_default();
+        self.with_components(property_types, components)
+    }
+
+    fn validate_new_entity_instance(
+        &self,
+        type_name: String,
+        components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError> {
+        validate_new_properties(&self.get_entity_instance_property_types(type_name, components), properties, self.is_strict())
+    }
+
+    fn validate_new_relation_instance(
+        &self,
+        type_name: String,
+        components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError> {
+        validate_new_properties(&self.get_relation_instance_property_types(type_name, components), properties, self.is_strict())
+    }
+
+    fn validate_entity_instance(
+        &self,
+        entity_instance: &ReactiveEntityInstance,
+        add_components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError> {
+        let mut components: Vec<String> = entity_instance.components.iter().map(|component| component.key().clone()).collect();
+        components.extend(add_components.iter().cloned());
+        validate_properties(
+            &self.get_entity_instance_property_types(entity_instance.type_name.clone(), &components),
+            properties,
+            self.is_strict(),
+        )
+    }
+
+    fn validate_relation_instance(
+        &self,
+        relation_instance: &ReactiveRelationInstance,
+        add_components: &[String],
+        properties: &HashMap<String, Value>,
+    ) -> Result<(), PropertyValidationError> {
+        let mut components: Vec<String> = relation_instance.components.iter().map(|component| component.key().clone()).collect();
+        components.extend(add_components.iter().cloned());
+        validate_properties(
+            &self.get_relation_instance_property_types(relation_instance.type_name.clone(), &components),
+            properties,
+            self.is_strict(),
+        )
+    }
+}
+
+impl Lifecycle for PropertyValidatorImpl {
+    fn init(&self) {}
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
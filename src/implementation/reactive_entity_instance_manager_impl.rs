@@ -2,17 +2,19 @@ use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
+use log::{debug, error, warn};
 use path_tree::PathTree;
-use serde_json::json;
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::api::get_default_value;
 use crate::api::ComponentBehaviourManager;
 use crate::api::ComponentManager;
 use crate::api::EntityBehaviourManager;
 use crate::api::EntityInstanceManager;
 use crate::api::EntityTypeManager;
 use crate::api::Lifecycle;
+use crate::api::PropertyJournal;
 use crate::api::ReactiveEntityInstanceCreationError;
 use crate::api::ReactiveEntityInstanceImportError;
 use crate::api::ReactiveEntityInstanceManager;
@@ -54,6 +56,8 @@ pub struct ReactiveEntityInstanceManagerImpl {
 
     entity_behaviour_manager: Wrc<dyn EntityBehaviourManager>,
 
+    property_journal: Wrc<dyn PropertyJournal>,
+
     reactive_entity_instances: ReactiveEntityInstances,
 
     label_path_tree: LabelPathTree,
@@ -139,13 +143,17 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
 
     fn create_reactive_instance(&self, entity_instance: EntityInstance) -> Result<Arc<ReactiveEntityInstance>, ReactiveEntityInstanceCreationError> {
         let reactive_entity_instance = Arc::new(ReactiveEntityInstance::from(entity_instance));
-        self.register_reactive_instance(reactive_entity_instance.clone());
+        self.register_reactive_instance(reactive_entity_instance.clone())?;
         Ok(reactive_entity_instance)
     }
 
-    fn register_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) {
-        // TODO: propagate error if create wasn't successful
-        let _result = self.entity_instance_manager.create_from_instance(reactive_entity_instance.clone().into());
+    fn register_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) -> Result<(), ReactiveEntityInstanceCreationError> {
+        // Store the entity instance unless it has been stored already (create, restore)
+        if !self.entity_instance_manager.has(reactive_entity_instance.id) {
+            self.entity_instance_manager
+                .create_from_instance(reactive_entity_instance.clone().into())
+                .map_err(ReactiveEntityInstanceCreationError::EntityInstanceCreationError)?;
+        }
         self.reactive_entity_instances
             .0
             .write()
@@ -165,25 +173,30 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
         self.component_behaviour_manager.add_behaviours_to_entity(reactive_entity_instance.clone());
         // Add entity behaviours
         self.entity_behaviour_manager.add_behaviours(reactive_entity_instance.clone());
+        // Record property changes until the next commit
+        self.property_journal.observe(reactive_entity_instance.clone());
         // Register label
-        if let Some(value) = reactive_entity_instance.get("label") {
-            if !value.is_string() {
-                return;
-            }
+        if let Some(label) = reactive_entity_instance.get("label").and_then(|value| value.as_str().map(String::from)) {
             let mut writer = self.label_path_tree.0.write().unwrap();
-            writer.insert(value.as_str().unwrap(), reactive_entity_instance.id);
+            writer.insert(&label, reactive_entity_instance.id);
         }
-        self.event_manager.emit_event(SystemEvent::EntityInstanceCreated(reactive_entity_instance.id))
+        let after = serde_json::to_value(EntityInstance::from(reactive_entity_instance.clone())).ok();
+        self.event_manager
+            .emit_event_with_data(SystemEvent::EntityInstanceCreated(reactive_entity_instance.id), None, after);
+        Ok(())
     }
 
-    fn register_or_merge_reactive_instance(&self, reactive_entity_instance: Arc<ReactiveEntityInstance>) -> Arc<ReactiveEntityInstance> {
+    fn register_or_merge_reactive_instance(
+        &self,
+        reactive_entity_instance: Arc<ReactiveEntityInstance>,
+    ) -> Result<Arc<ReactiveEntityInstance>, ReactiveEntityInstanceCreationError> {
         if !self.has(reactive_entity_instance.id) {
             // No instance exists with the given uuid: register as new instance and return it
-            self.register_reactive_instance(reactive_entity_instance.clone());
-            reactive_entity_instance
+            self.register_reactive_instance(reactive_entity_instance.clone())?;
+            Ok(reactive_entity_instance)
         } else {
             // Instance with the given uuid exists: don't register but return the existing instance instead
-            self.get(reactive_entity_instance.id).unwrap()
+            Ok(self.get(reactive_entity_instance.id).unwrap())
         }
     }
 
@@ -191,18 +204,23 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
         if let Some(component) = self.component_manager.get(component_name.clone()) {
             if let Some(reactive_entity_instance) = self.get(id) {
                 // Add component
-                reactive_entity_instance.add_component(component_name);
+                reactive_entity_instance.add_component(component_name.clone());
                 // Add component properties which doesn't exist yet
                 for property in component.properties.iter() {
                     let property_name = property.name.clone();
                     if !reactive_entity_instance.properties.contains_key(property_name.as_str()) {
-                        let property_instance = ReactivePropertyInstance::new(reactive_entity_instance.id, property_name.clone(), json!(0));
+                        let property_instance = ReactivePropertyInstance::new(reactive_entity_instance.id, property_name.clone(), get_default_value(property));
                         reactive_entity_instance.properties.insert(property_name, property_instance);
                     }
                 }
+                // Record property changes of the added properties
+                self.property_journal.observe(reactive_entity_instance.clone());
                 // Add component behaviours
                 self.component_behaviour_manager
-                    .add_behaviours_to_entity_component(reactive_entity_instance, component);
+                    .add_behaviours_to_entity_component(reactive_entity_instance.clone(), component);
+                let after = serde_json::to_value(EntityInstance::from(reactive_entity_instance)).ok();
+                self.event_manager
+                    .emit_event_with_data(SystemEvent::EntityComponentAdded(id, component_name), None, after);
             }
         }
     }
@@ -211,11 +229,14 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
         if let Some(component) = self.component_manager.get(component_name.clone()) {
             if let Some(reactive_entity_instance) = self.get(id) {
                 // Remove component
-                reactive_entity_instance.remove_component(component_name);
+                reactive_entity_instance.remove_component(component_name.clone());
                 // We do not remove properties because we cannot asure that the removal is intended
                 // Remove component behaviours
                 self.component_behaviour_manager
-                    .remove_behaviours_from_entity_component(reactive_entity_instance, component);
+                    .remove_behaviours_from_entity_component(reactive_entity_instance.clone(), component);
+                let after = serde_json::to_value(EntityInstance::from(reactive_entity_instance)).ok();
+                self.event_manager
+                    .emit_event_with_data(SystemEvent::EntityComponentRemoved(id, component_name), None, after);
             }
         }
     }
@@ -223,19 +244,24 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
     fn commit(&self, id: Uuid) {
         if let Some(reactive_entity_instance) = self.get(id) {
             self.entity_instance_manager.commit(reactive_entity_instance.into());
+            self.property_journal.compact(id);
         }
     }
 
     // TODO: Important: Check if the entity is part of relations
     // TODO: Return true only if the entity instance has been deleted successfully
     fn delete(&self, id: Uuid) {
+        let before = self
+            .get(id)
+            .and_then(|entity_instance| serde_json::to_value(EntityInstance::from(entity_instance)).ok());
         if self.has(id) {
             // TODO: check for relations
             self.unregister_reactive_instance(id);
         }
         // TODO: remove label
         self.entity_instance_manager.delete(id);
-        self.event_manager.emit_event(SystemEvent::EntityInstanceDeleted(id))
+        self.property_journal.compact(id);
+        self.event_manager.emit_event_with_data(SystemEvent::EntityInstanceDeleted(id), before, None)
     }
 
     // TODO: fn delete_and_delete_relations(&self, id: Uuid) {}
@@ -243,6 +269,7 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
     fn unregister_reactive_instance(&self, id: Uuid) {
         match self.get(id) {
             Some(entity_instance) => {
+                self.property_journal.unobserve(entity_instance.clone());
                 self.entity_behaviour_manager.remove_behaviours(entity_instance);
             }
             None => {
@@ -274,13 +301,38 @@ impl ReactiveEntityInstanceManager for ReactiveEntityInstanceManagerImpl {
     }
 }
 
+impl ReactiveEntityInstanceManagerImpl {
+    /// Restores the reactive entity instances from the graph database. The components of the
+    /// entity type and the behaviours are applied during registration.
+    fn restore_reactive_instances(&self) {
+        for entity_instance in self.entity_instance_manager.get_all() {
+            if self.reactive_entity_instances.0.read().unwrap().contains_key(&entity_instance.id) {
+                continue;
+            }
+            if !self.entity_type_manager.has(entity_instance.type_name.clone()) {
+                warn!("Failed to restore entity instance {}: Unknown entity type {}", entity_instance.id, entity_instance.type_name);
+                continue;
+            }
+            debug!("Restoring entity instance {}", entity_instance.id);
+            let id = entity_instance.id;
+            if let Err(e) = self.register_reactive_instance(Arc::new(ReactiveEntityInstance::from(entity_instance))) {
+                warn!("Failed to restore entity instance {}: {}", id, e);
+            }
+        }
+    }
+}
+
 impl Lifecycle for ReactiveEntityInstanceManagerImpl {
     fn init(&self) {}
 
     fn post_init(&self) {
         for event_instance in self.event_manager.get_system_event_instances() {
-            self.register_reactive_instance(event_instance);
+            let id = event_instance.id;
+            if let Err(e) = self.register_reactive_instance(event_instance) {
+                error!("Failed to register system event instance {}: {}", id, e);
+            }
         }
+        self.restore_reactive_instances();
     }
 
     fn pre_shutdown(&self) {
@@ -11,6 +11,7 @@ use log::{debug, error};
 use path_tree::PathTree;
 use uuid::Uuid;
 
+use crate::api::with_event_source;
 use crate::api::FlowManager;
 use crate::api::Lifecycle;
 use crate::api::ReactiveEntityInstanceManager;
@@ -20,6 +21,7 @@ use crate::api::ReactiveFlowManager;
 use crate::api::ReactiveRelationInstanceManager;
 use crate::api::SystemEvent;
 use crate::api::SystemEventManager;
+use crate::api::SystemEventSource;
 use crate::di::*;
 use crate::model::Flow;
 use crate::model::PropertyInstanceGetter;
@@ -94,11 +96,19 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
 
     // fn create(&self, type_name: String, properties: HashMap<String, Value, RandomState>) -> Result<Arc<ReactiveFlow>, ReactiveFlowCreationError> {
     fn create(&self, flow: Flow) -> Result<Arc<ReactiveFlow>, ReactiveFlowCreationError> {
-        let reactive_flow = ReactiveFlow::try_from(flow);
+        if self.has(flow.id) {
+            return Err(ReactiveFlowCreationError::UuidTaken(flow.id));
+        }
+        let reactive_flow = ReactiveFlow::try_from(flow.clone());
         if reactive_flow.is_err() {
             return Err(ReactiveFlowCreationError::ReactiveFlowConstructionError(reactive_flow.err().unwrap()));
         }
         let reactive_flow = reactive_flow.unwrap();
+        // Store the flow in the graph database in order to restore the flow at the next startup.
+        // The reactive instances are registered only if the flow has been stored.
+        if let Err(e) = self.flow_manager.create(flow) {
+            return Err(ReactiveFlowCreationError::FlowCreationError(e));
+        }
         let reactive_flow = Arc::new(reactive_flow);
         self.register_flow_and_reactive_instances(reactive_flow.clone());
         Ok(reactive_flow)
@@ -119,11 +129,19 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
                 let mut entity_instances = reactive_flow.entity_instances.write().unwrap();
                 let mut replaced_entity_instances = HashMap::<Uuid, Arc<ReactiveEntityInstance>>::new();
                 for (uuid, entity_instance) in entity_instances.iter() {
-                    let entity_instance = self
+                    match self
                         .reactive_entity_instance_manager
-                        .register_or_merge_reactive_instance(entity_instance.clone());
-                    // Replace the entity instance with the actual registered instance instead
-                    replaced_entity_instances.insert(*uuid, entity_instance);
+                        .register_or_merge_reactive_instance(entity_instance.clone())
+                    {
+                        Ok(entity_instance) => {
+                            // Replace the entity instance with the actual registered instance instead
+                            replaced_entity_instances.insert(*uuid, entity_instance);
+                        }
+                        Err(e) => {
+                            error!("Failed to register entity instance {} of flow {}: {}", uuid, reactive_flow.id, e);
+                            replaced_entity_instances.insert(*uuid, entity_instance.clone());
+                        }
+                    }
                 }
 
                 // Step 2: Replace the entity instances of the flow with the actual registered entity instances
@@ -159,11 +177,19 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
                 // Step 5: Register all (recreated) relation instances (if not already registered by edge_key)
                 let mut replaced_relation_instances = HashMap::<EdgeKey, Arc<ReactiveRelationInstance>>::new();
                 for (edge_key, relation_instance) in relation_instances.iter() {
-                    let relation_instance = self
+                    match self
                         .reactive_relation_instance_manager
-                        .register_or_merge_reactive_instance(relation_instance.clone());
-                    // Replace the relation instance with the actual registered instance
-                    replaced_relation_instances.insert(edge_key.clone(), relation_instance);
+                        .register_or_merge_reactive_instance(relation_instance.clone())
+                    {
+                        Ok(relation_instance) => {
+                            // Replace the relation instance with the actual registered instance
+                            replaced_relation_instances.insert(edge_key.clone(), relation_instance);
+                        }
+                        Err(e) => {
+                            error!("Failed to register relation instance {:?} of flow {}: {}", edge_key, reactive_flow.id, e);
+                            replaced_relation_instances.insert(edge_key.clone(), relation_instance.clone());
+                        }
+                    }
                 }
 
                 // Step 6: Replace the relation instances of the flow with the actual registered relation instances
@@ -179,7 +205,9 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
     fn register_flow(&self, reactive_flow: Arc<ReactiveFlow>) {
         if !self.reactive_entity_instance_manager.has(reactive_flow.id) {
             if let Some(wrapper_entity_instance) = reactive_flow.get_entity(reactive_flow.id) {
-                self.reactive_entity_instance_manager.register_reactive_instance(wrapper_entity_instance);
+                if let Err(e) = self.reactive_entity_instance_manager.register_reactive_instance(wrapper_entity_instance) {
+                    error!("Failed to register the wrapper entity instance of flow {}: {}", reactive_flow.id, e);
+                }
             }
         }
         self.reactive_flows.0.write().unwrap().insert(reactive_flow.id, reactive_flow.clone());
@@ -190,7 +218,8 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
                 writer.insert(label, reactive_flow.id);
             }
         }
-        self.event_manager.emit_event(SystemEvent::FlowCreated(reactive_flow.id))
+        let after = Flow::try_from(reactive_flow.clone()).ok().and_then(|flow| serde_json::to_value(flow).ok());
+        self.event_manager.emit_event_with_data(SystemEvent::FlowCreated(reactive_flow.id), None, after)
     }
 
     // TODO: how to detect if the flow has removed an entity? => remove behaviour
@@ -212,7 +241,9 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
             // Register added entities
             for id in reactive_flow.entities_added.read().unwrap().iter() {
                 if let Some(entity_instance) = reactive_flow.get_entity(*id) {
-                    self.reactive_entity_instance_manager.register_reactive_instance(entity_instance.clone());
+                    if let Err(e) = self.reactive_entity_instance_manager.register_reactive_instance(entity_instance.clone()) {
+                        error!("Failed to register entity instance {} of flow {}: {}", id, reactive_flow.id, e);
+                    }
                 }
             }
             reactive_flow.entities_added.write().unwrap().clear();
@@ -220,7 +251,9 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
             // Register added relations
             for edge_key in reactive_flow.relations_added.read().unwrap().iter() {
                 if let Some(relation_instance) = reactive_flow.get_relation(edge_key.clone()) {
-                    self.reactive_relation_instance_manager.register_reactive_instance(relation_instance.clone());
+                    if let Err(e) = self.reactive_relation_instance_manager.register_reactive_instance(relation_instance.clone()) {
+                        error!("Failed to register relation instance {:?} of flow {}: {}", edge_key, reactive_flow.id, e);
+                    }
                 }
             }
             reactive_flow.relations_added.write().unwrap().clear();
@@ -241,7 +274,9 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
             // }
 
             if let Ok(flow) = Flow::try_from(reactive_flow) {
+                let after = serde_json::to_value(&flow).ok();
                 self.flow_manager.commit(flow);
+                self.event_manager.emit_event_with_data(SystemEvent::FlowCommitted(id), None, after);
             }
         }
     }
@@ -249,6 +284,7 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
     fn delete(&self, id: Uuid) {
         if self.has(id) {
             let reactive_flow = self.get(id).unwrap();
+            let before = Flow::try_from(reactive_flow.clone()).ok().and_then(|flow| serde_json::to_value(flow).ok());
             for (_, entity_instance) in reactive_flow.entity_instances.read().unwrap().iter() {
                 self.reactive_entity_instance_manager.unregister_reactive_instance(entity_instance.id);
             }
@@ -259,7 +295,7 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
             }
             self.reactive_flows.0.write().unwrap().remove(&id);
             // TODO: remove label
-            self.event_manager.emit_event(SystemEvent::FlowDeleted(id))
+            self.event_manager.emit_event_with_data(SystemEvent::FlowDeleted(id), before, None)
         }
     }
 
@@ -284,6 +320,47 @@ impl ReactiveFlowManager for ReactiveFlowManagerImpl {
     fn add_provider(&self, provider: Arc<dyn FlowProvider>) {
         self.flow_providers.0.write().unwrap().push(provider);
     }
+
+    fn remove_provider(&self, provider: Arc<dyn FlowProvider>) {
+        self.flow_providers
+            .0
+            .write()
+            .unwrap()
+            .retain(|p| Arc::as_ptr(p) as *const () != Arc::as_ptr(&provider) as *const ());
+    }
+}
+
+impl ReactiveFlowManagerImpl {
+    /// Restores the reactive flows from the graph database. A flow is restored after all flows
+    /// which are contained in it, so that nested flows are restored inner first.
+    fn restore_flows(&self) {
+        let mut pending = self.flow_manager.get_all();
+        while !pending.is_empty() {
+            // A flow is ready if none of its entity instances is the wrapper of another pending flow
+            let (ready, blocked): (Vec<Flow>, Vec<Flow>) = pending.iter().cloned().partition(|flow| {
+                !pending
+                    .iter()
+                    .any(|other| other.id != flow.id && flow.entity_instances.iter().any(|entity_instance| entity_instance.id == other.id))
+            });
+            if ready.is_empty() {
+                for flow in blocked.iter() {
+                    error!("Failed to restore flow {}: The flow is part of a cycle of nested flows", flow.id);
+                }
+                return;
+            }
+            for flow in ready.into_iter() {
+                if self.has(flow.id) {
+                    continue;
+                }
+                debug!("Restoring flow {}", flow.id);
+                match ReactiveFlow::try_from(flow.clone()) {
+                    Ok(reactive_flow) => self.register_flow_and_reactive_instances(Arc::new(reactive_flow)),
+                    Err(e) => error!("Failed to restore flow {}: {}", flow.id, e),
+                }
+            }
+            pending = blocked;
+        }
+    }
 }
 
 impl Lifecycle for ReactiveFlowManagerImpl {
@@ -292,7 +369,7 @@ impl Lifecycle for ReactiveFlowManagerImpl {
         for flow in self.flow_providers.0.read().unwrap().iter() {
             for flow in flow.get_flows() {
                 debug!("Creating provided flow {}", flow.id);
-                let reactive_flow = self.create(flow.clone());
+                let reactive_flow = with_event_source(SystemEventSource::Provider, || self.create(flow.clone()));
                 match reactive_flow {
                     Ok(reactive_flow) => {
                         let created_flow: Result<Flow, _> = reactive_flow.try_into();
@@ -314,7 +391,9 @@ impl Lifecycle for ReactiveFlowManagerImpl {
         }
     }
 
-    fn post_init(&self) {}
+    fn post_init(&self) {
+        self.restore_flows();
+    }
 
     fn pre_shutdown(&self) {}
 
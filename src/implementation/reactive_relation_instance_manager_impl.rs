@@ -5,12 +5,14 @@ use std::sync::RwLock;
 
 use async_trait::async_trait;
 use indradb::EdgeKey;
-use serde_json::json;
+use log::{debug, warn};
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::api::get_default_value;
 use crate::api::ComponentBehaviourManager;
 use crate::api::ComponentManager;
+use crate::api::Lifecycle;
 use crate::api::ReactiveEntityInstanceManager;
 use crate::api::ReactiveRelationInstanceCreationError;
 use crate::api::ReactiveRelationInstanceImportError;
@@ -134,78 +136,97 @@ impl ReactiveRelationInstanceManager for ReactiveRelationInstanceManagerImpl {
         }
 
         let reactive_relation_instance = Arc::new(ReactiveRelationInstance::from_instance(outbound, inbound, relation_instance));
-        self.register_reactive_instance(reactive_relation_instance.clone());
+        self.register_reactive_instance(reactive_relation_instance.clone())?;
         Ok(reactive_relation_instance)
     }
 
-    fn register_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>) {
-        if let Some(edge_key) = reactive_relation_instance.get_key() {
-            // TODO: propagate error if create wasn't successful
-            let _result = self.relation_instance_manager.create_from_instance(reactive_relation_instance.clone().into());
-            self.reactive_relation_instances
-                .0
-                .write()
-                .unwrap()
-                .insert(edge_key.clone(), reactive_relation_instance.clone());
-            // Apply all components that are predefined in the relation type
-            if let Some(components) = self
-                .relation_type_manager
-                .get(reactive_relation_instance.type_name.clone())
-                .map(|entity_type| entity_type.components)
-            {
-                components.iter().for_each(|component| {
-                    reactive_relation_instance.components.insert(component.clone());
-                });
-            }
-            // Add component behaviours
-            self.component_behaviour_manager.add_behaviours_to_relation(reactive_relation_instance.clone());
-            // Add relation behaviours
-            self.relation_behaviour_manager.add_behaviours(reactive_relation_instance);
-            self.event_manager.emit_event(SystemEvent::RelationInstanceCreated(edge_key))
+    fn register_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>) -> Result<(), ReactiveRelationInstanceCreationError> {
+        let edge_key = reactive_relation_instance
+            .get_key()
+            .ok_or(ReactiveRelationInstanceCreationError::InvalidEdgeKey)?;
+        // Store the relation instance unless it has been stored already (create, restore)
+        if !self.relation_instance_manager.has(edge_key.clone()) {
+            self.relation_instance_manager
+                .create_from_instance(reactive_relation_instance.clone().into())
+                .map_err(ReactiveRelationInstanceCreationError::RelationInstanceCreationError)?;
+        }
+        self.reactive_relation_instances
+            .0
+            .write()
+            .unwrap()
+            .insert(edge_key.clone(), reactive_relation_instance.clone());
+        // Apply all components that are predefined in the relation type
+        if let Some(components) = self
+            .relation_type_manager
+            .get(reactive_relation_instance.type_name.clone())
+            .map(|entity_type| entity_type.components)
+        {
+            components.iter().for_each(|component| {
+                reactive_relation_instance.components.insert(component.clone());
+            });
         }
+        // Add component behaviours
+        self.component_behaviour_manager.add_behaviours_to_relation(reactive_relation_instance.clone());
+        // Add relation behaviours
+        self.relation_behaviour_manager.add_behaviours(reactive_relation_instance.clone());
+        let after = serde_json::to_value(RelationInstance::from(reactive_relation_instance)).ok();
+        self.event_manager
+            .emit_event_with_data(SystemEvent::RelationInstanceCreated(edge_key), None, after);
+        Ok(())
     }
 
-    fn register_or_merge_reactive_instance(&self, reactive_relation_instance: Arc<ReactiveRelationInstance>) -> Arc<ReactiveRelationInstance> {
-        let edge_key = reactive_relation_instance.get_key().unwrap();
+    fn register_or_merge_reactive_instance(
+        &self,
+        reactive_relation_instance: Arc<ReactiveRelationInstance>,
+    ) -> Result<Arc<ReactiveRelationInstance>, ReactiveRelationInstanceCreationError> {
+        let edge_key = reactive_relation_instance
+            .get_key()
+            .ok_or(ReactiveRelationInstanceCreationError::InvalidEdgeKey)?;
         if !self.has(edge_key.clone()) {
             // No instance exists with the given edge key
-            self.register_reactive_instance(reactive_relation_instance.clone());
-            reactive_relation_instance
+            self.register_reactive_instance(reactive_relation_instance.clone())?;
+            Ok(reactive_relation_instance)
         } else {
             // Instance with the given edge key exists. Don't register but return the existing reactive instance instead
-            self.get(edge_key).unwrap()
+            Ok(self.get(edge_key).unwrap())
         }
     }
 
     fn add_component(&self, edge_key: EdgeKey, component_name: String) {
         if let Some(component) = self.component_manager.get(component_name.clone()) {
-            if let Some(reactive_relation_instance) = self.get(edge_key) {
+            if let Some(reactive_relation_instance) = self.get(edge_key.clone()) {
                 // Add component
-                reactive_relation_instance.add_component(component_name);
+                reactive_relation_instance.add_component(component_name.clone());
                 // Add component properties which doesn't exist yet
                 for property in component.properties.iter() {
                     let property_name = property.name.clone();
                     if !reactive_relation_instance.properties.contains_key(property_name.as_str()) {
-                        let property_instance = ReactivePropertyInstance::new(Uuid::new_v4(), property_name.clone(), json!(0));
+                        let property_instance = ReactivePropertyInstance::new(Uuid::new_v4(), property_name.clone(), get_default_value(property));
                         reactive_relation_instance.properties.insert(property_name, property_instance);
                     }
                 }
                 // Add component behaviours
                 self.component_behaviour_manager
-                    .add_behaviours_to_relation_component(reactive_relation_instance, component);
+                    .add_behaviours_to_relation_component(reactive_relation_instance.clone(), component);
+                let after = serde_json::to_value(RelationInstance::from(reactive_relation_instance)).ok();
+                self.event_manager
+                    .emit_event_with_data(SystemEvent::RelationComponentAdded(edge_key, component_name), None, after);
             }
         }
     }
 
     fn remove_component(&self, edge_key: EdgeKey, component_name: String) {
         if let Some(component) = self.component_manager.get(component_name.clone()) {
-            if let Some(reactive_relation_instance) = self.get(edge_key) {
+            if let Some(reactive_relation_instance) = self.get(edge_key.clone()) {
                 // Remove component
-                reactive_relation_instance.remove_component(component_name);
+                reactive_relation_instance.remove_component(component_name.clone());
                 // We do not remove properties because we cannot asure that the removal is intended
                 // Remove component behaviours
                 self.component_behaviour_manager
-                    .remove_behaviours_from_relation_component(reactive_relation_instance, component);
+                    .remove_behaviours_from_relation_component(reactive_relation_instance.clone(), component);
+                let after = serde_json::to_value(RelationInstance::from(reactive_relation_instance)).ok();
+                self.event_manager
+                    .emit_event_with_data(SystemEvent::RelationComponentRemoved(edge_key, component_name), None, after);
             }
         }
     }
@@ -217,11 +238,15 @@ impl ReactiveRelationInstanceManager for ReactiveRelationInstanceManagerImpl {
     }
 
     fn delete(&self, edge_key: EdgeKey) -> bool {
+        let before = self
+            .get(edge_key.clone())
+            .and_then(|relation_instance| serde_json::to_value(RelationInstance::from(relation_instance)).ok());
         if self.has(edge_key.clone()) {
             self.unregister_reactive_instance(edge_key.clone());
         }
         let result = self.relation_instance_manager.delete(edge_key.clone());
-        self.event_manager.emit_event(SystemEvent::RelationInstanceDeleted(edge_key));
+        self.event_manager
+            .emit_event_with_data(SystemEvent::RelationInstanceDeleted(edge_key), before, None);
         result
     }
 
@@ -254,3 +279,34 @@ impl ReactiveRelationInstanceManager for ReactiveRelationInstanceManagerImpl {
         }
     }
 }
+
+impl ReactiveRelationInstanceManagerImpl {
+    /// Restores the reactive relation instances from the graph database. The relation instances
+    /// are restored after the entity instances because the outbound and inbound entity instances
+    /// must exist.
+    fn restore_reactive_instances(&self) {
+        for relation_instance in self.relation_instance_manager.get_all() {
+            if let Some(edge_key) = relation_instance.get_key() {
+                if self.reactive_relation_instances.0.read().unwrap().contains_key(&edge_key) {
+                    continue;
+                }
+                debug!("Restoring relation instance {:?}", edge_key);
+                if let Err(e) = self.create_reactive_instance(relation_instance) {
+                    warn!("Failed to restore relation instance {:?}: {}", edge_key, e);
+                }
+            }
+        }
+    }
+}
+
+impl Lifecycle for ReactiveRelationInstanceManagerImpl {
+    fn init(&self) {}
+
+    fn post_init(&self) {
+        self.restore_reactive_instances();
+    }
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
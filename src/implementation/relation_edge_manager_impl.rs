@@ -3,11 +3,13 @@ use std::collections::HashMap;
 
 use crate::di::*;
 use async_trait::async_trait;
-use indradb::{Datastore, Edge, EdgeKey, EdgeProperties, EdgeQueryExt, Identifier, SpecificEdgeQuery, SpecificVertexQuery, VertexQueryExt};
+use indradb::{Edge, EdgeKey, EdgeProperties, EdgeQueryExt, Identifier, RangeVertexQuery, SpecificEdgeQuery, SpecificVertexQuery, VertexQueryExt};
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::api::{GraphDatabase, RelationEdgeCreationError, RelationEdgeManager, RelationTypeManager};
+use crate::api::{
+    get_default_value, get_missing_required_property, validate_properties, GraphDatabase, RelationEdgeCreationError, RelationEdgeManager, RelationTypeManager,
+};
 
 // This service operates on the graph database.
 
@@ -70,12 +72,25 @@ impl RelationEdgeManager for RelationEdgeManagerImpl {
         None
     }
 
+    fn get_all_properties(&self) -> Vec<EdgeProperties> {
+        self.graph_database
+            .get_datastore()
+            .get_all_edge_properties(RangeVertexQuery::new().outbound().into())
+            .unwrap_or_default()
+    }
+
     fn create(&self, edge_key: EdgeKey, properties: HashMap<String, Value>) -> Result<EdgeKey, RelationEdgeCreationError> {
         let type_name = edge_key.t.to_string();
         if !self.relation_type_manager.has_starts_with(type_name.clone()) {
             return Err(RelationEdgeCreationError::RelationTypeMissing(type_name));
         }
         let relation_type = self.relation_type_manager.get_starts_with(type_name).unwrap();
+        if let Some(property_name) = get_missing_required_property(&relation_type.properties, &properties) {
+            return Err(RelationEdgeCreationError::MissingRequiredProperty(property_name));
+        }
+        if let Err(e) = validate_properties(&relation_type.properties, &properties, false) {
+            return Err(RelationEdgeCreationError::InvalidProperties(e));
+        }
 
         let datastore = self.graph_database.get_datastore();
         let result = datastore.create_edge(&edge_key);
@@ -85,15 +100,13 @@ impl RelationEdgeManager for RelationEdgeManagerImpl {
         }
         let edge_query = SpecificEdgeQuery::single(edge_key.clone());
         for property_type in relation_type.properties {
+            let value = properties
+                .get(&property_type.name)
+                .cloned()
+                .unwrap_or_else(|| get_default_value(&property_type));
             let property_name = property_type.name;
-            let value = properties.get(&*property_name.clone());
-            if value.is_none() {
-                // Missing required property
-                return Err(RelationEdgeCreationError::MissingRequiredProperty(property_name));
-            }
-            let value = value.unwrap();
             let property_query = edge_query.clone().property(Identifier::new(property_name).unwrap());
-            let property_result = datastore.set_edge_properties(property_query, value.clone());
+            let property_result = datastore.set_edge_properties(property_query, value);
             if property_result.is_err() {
                 // Should not happen when using indradb::InternalMemoryDatastore
                 return Err(RelationEdgeCreationError::GraphDatabaseError(property_result.err().unwrap()));
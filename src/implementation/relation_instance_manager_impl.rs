@@ -30,6 +30,14 @@ impl RelationInstanceManager for RelationInstanceManagerImpl {
         self.relation_edge_manager.get_properties(edge_key).map(RelationInstance::from)
     }
 
+    fn get_all(&self) -> Vec<RelationInstance> {
+        self.relation_edge_manager
+            .get_all_properties()
+            .into_iter()
+            .map(RelationInstance::from)
+            .collect()
+    }
+
     fn get_by_outbound_entity(&self, outbound_entity_id: Uuid) -> Vec<RelationInstance> {
         self.relation_edge_manager
             .get_by_outbound_entity(outbound_entity_id)
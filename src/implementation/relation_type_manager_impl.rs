@@ -8,6 +8,8 @@ use indradb::Identifier;
 use log::{debug, error, warn};
 use wildmatch::WildMatch;
 
+use crate::api::compile_patterns;
+use crate::api::with_event_source;
 use crate::api::ComponentManager;
 use crate::api::EntityTypeManager;
 use crate::api::Lifecycle;
@@ -15,6 +17,7 @@ use crate::api::RelationTypeImportError;
 use crate::api::RelationTypeManager;
 use crate::api::SystemEvent;
 use crate::api::SystemEventManager;
+use crate::api::SystemEventSource;
 use crate::model::Extension;
 use crate::model::PropertyType;
 use crate::model::RelationType;
@@ -81,9 +84,15 @@ impl RelationTypeManager for RelationTypeManagerImpl {
             }
         }
 
+        if let Err(e) = compile_patterns(&relation_type.properties) {
+            error!("Relation type {} not registered: {}", relation_type.type_name.clone(), e);
+            return;
+        }
+
         let event = SystemEvent::RelationTypeCreated(relation_type.type_name.clone());
+        let after = serde_json::to_value(&relation_type).ok();
         self.relation_types.0.write().unwrap().push(relation_type);
-        self.event_manager.emit_event(event);
+        self.event_manager.emit_event_with_data(event, None, after);
         // TODO: Result
     }
 
@@ -165,12 +174,13 @@ impl RelationTypeManager for RelationTypeManagerImpl {
 
     fn delete(&self, type_name: String) {
         let event = SystemEvent::RelationTypeDeleted(type_name.clone());
+        let before = self.get(type_name.clone()).and_then(|relation_type| serde_json::to_value(&relation_type).ok());
         self.relation_types
             .0
             .write()
             .unwrap()
             .retain(|relation_type| relation_type.type_name != type_name);
-        self.event_manager.emit_event(event);
+        self.event_manager.emit_event_with_data(event, before, None);
     }
 
     fn import(&self, path: String) -> Result<RelationType, RelationTypeImportError> {
@@ -197,7 +207,7 @@ impl RelationTypeManager for RelationTypeManagerImpl {
     fn add_provider(&self, relation_type_provider: Arc<dyn RelationTypeProvider>) {
         for relation_type in relation_type_provider.get_relation_types() {
             debug!("Registering relation type: {}", relation_type.type_name);
-            self.register(relation_type);
+            with_event_source(SystemEventSource::Provider, || self.register(relation_type));
         }
     }
 }
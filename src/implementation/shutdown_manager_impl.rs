@@ -6,6 +6,7 @@ use crate::builder::EntityTypeBuilder;
 use crate::builder::ReactiveEntityInstanceBuilder;
 use crate::di::*;
 use async_trait::async_trait;
+use log::error;
 use serde_json::json;
 use tokio::task;
 
@@ -13,6 +14,8 @@ use crate::api::EntityTypeManager;
 use crate::api::Lifecycle;
 use crate::api::ReactiveEntityInstanceManager;
 use crate::api::ShutdownManager;
+use crate::api::SystemEvent;
+use crate::api::SystemEventManager;
 use crate::api::LABEL;
 use crate::api::SHUTDOWN;
 use crate::api::TRIGGER;
@@ -31,6 +34,7 @@ fn create_shutdown_state() -> ShutdownStateContainer {
 pub struct ShutdownManagerImpl {
     entity_type_manager: Wrc<dyn EntityTypeManager>,
     reactive_entity_instance_manager: Wrc<dyn ReactiveEntityInstanceManager>,
+    event_manager: Wrc<dyn SystemEventManager>,
 
     shutdown_state: ShutdownStateContainer,
 }
@@ -39,6 +43,7 @@ pub struct ShutdownManagerImpl {
 #[provides]
 impl ShutdownManager for ShutdownManagerImpl {
     fn do_shutdown(&self) {
+        self.event_manager.emit_event(SystemEvent::ShutdownRequested);
         let mut guard = self.shutdown_state.0.write().unwrap();
         *guard = true;
     }
@@ -59,11 +64,15 @@ impl Lifecycle for ShutdownManagerImpl {
             .get();
         entity_instance.components.insert("labeled".to_owned());
         entity_instance.components.insert("action".to_owned());
-        self.reactive_entity_instance_manager.register_reactive_instance(entity_instance.clone());
+        if let Err(e) = self.reactive_entity_instance_manager.register_reactive_instance(entity_instance.clone()) {
+            error!("Failed to register the shutdown entity instance: {}", e);
+        }
         let shutdown_state = self.shutdown_state.0.clone();
+        let event_manager = self.event_manager.clone();
         entity_instance.properties.get(TRIGGER).unwrap().stream.read().unwrap().observe_with_handle(
             move |v| {
                 if v.is_boolean() && v.as_bool().unwrap() {
+                    event_manager.emit_event(SystemEvent::ShutdownRequested);
                     let mut guard = shutdown_state.write().unwrap();
                     *guard = true;
                 }
@@ -71,13 +80,16 @@ impl Lifecycle for ShutdownManagerImpl {
             UUID_SHUTDOWN_TRIGGER.as_u128(),
         );
         let shutdown_state = self.shutdown_state.0.clone();
+        let event_manager = self.event_manager.clone();
         entity_instance.properties.get(SHUTDOWN).unwrap().stream.read().unwrap().observe_with_handle(
             move |v| {
                 if v.is_boolean() && v.as_bool().unwrap() {
+                    event_manager.emit_event(SystemEvent::ShutdownRequested);
                     let mut guard = shutdown_state.write().unwrap();
                     *guard = true;
                 }
                 if v.is_number() {
+                    event_manager.emit_event(SystemEvent::ShutdownRequested);
                     let shutdown_in_seconds = time::Duration::from_secs(v.as_u64().unwrap());
                     let shutdown_state_deferred = shutdown_state.clone();
                     task::spawn(async move {
@@ -0,0 +1,294 @@
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::path::Component;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use async_trait::async_trait;
+use log::{debug, error, info, warn};
+
+use crate::api::ComponentManager;
+use crate::api::EntityTypeManager;
+use crate::api::Lifecycle;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveFlowManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::api::RelationTypeManager;
+use crate::api::Snapshot;
+use crate::api::SnapshotError;
+use crate::api::SnapshotFormat;
+use crate::api::SnapshotManager;
+use crate::api::SNAPSHOT_VERSION;
+use crate::config::get_snapshot_config;
+use crate::config::SnapshotConfig;
+use crate::di::*;
+use crate::model::EntityInstance;
+use crate::model::Flow;
+use crate::model::RelationInstance;
+
+#[wrapper]
+pub struct SnapshotConfigWrapper(SnapshotConfig);
+
+#[provides]
+fn create_snapshot_config() -> SnapshotConfigWrapper {
+    SnapshotConfigWrapper(get_snapshot_config().unwrap_or_else(|e| panic!("Failed to load snapshot configuration: {}", e)))
+}
+
+#[wrapper]
+pub struct SnapshotSchedulerRunning(Arc<AtomicBool>);
+
+#[provides]
+fn create_snapshot_scheduler_running() -> SnapshotSchedulerRunning {
+    SnapshotSchedulerRunning(Arc::new(AtomicBool::new(false)))
+}
+
+/// The managers which provide the contents of a snapshot. The periodic snapshot thread owns a
+/// copy of the sources.
+#[derive(Clone)]
+struct SnapshotSources {
+    component_manager: Arc<dyn ComponentManager>,
+    entity_type_manager: Arc<dyn EntityTypeManager>,
+    relation_type_manager: Arc<dyn RelationTypeManager>,
+    reactive_entity_instance_manager: Arc<dyn ReactiveEntityInstanceManager>,
+    reactive_relation_instance_manager: Arc<dyn ReactiveRelationInstanceManager>,
+    reactive_flow_manager: Arc<dyn ReactiveFlowManager>,
+}
+
+impl SnapshotSources {
+    fn create_snapshot(&self) -> Snapshot {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default(),
+            components: self.component_manager.get_components(),
+            entity_types: self.entity_type_manager.get_entity_types(),
+            relation_types: self.relation_type_manager.get_relation_types(),
+            entity_instances: self
+                .reactive_entity_instance_manager
+                .get_entity_instances()
+                .into_iter()
+                .map(EntityInstance::from)
+                .collect(),
+            relation_instances: self
+                .reactive_relation_instance_manager
+                .get_relation_instances()
+                .into_iter()
+                .map(RelationInstance::from)
+                .collect(),
+            flows: self
+                .reactive_flow_manager
+                .get_all()
+                .into_iter()
+                .filter_map(|reactive_flow| Flow::try_from(reactive_flow).ok())
+                .collect(),
+        }
+    }
+
+    fn export(&self, path: String) -> Result<Snapshot, SnapshotError> {
+        let snapshot = self.create_snapshot();
+        write_snapshot(&snapshot, path)?;
+        Ok(snapshot)
+    }
+}
+
+/// Writes the snapshot to the given path. The format is derived from the file extension.
+fn write_snapshot(snapshot: &Snapshot, path: String) -> Result<(), SnapshotError> {
+    if let Some(parent) = std::path::Path::new(&path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let writer = BufWriter::new(File::create(path.clone())?);
+    match SnapshotFormat::from_path(&path) {
+        SnapshotFormat::Json => serde_json::to_writer_pretty(writer, snapshot)?,
+        SnapshotFormat::Cbor => serde_cbor::to_writer(writer, snapshot)?,
+    }
+    Ok(())
+}
+
+/// Reads the snapshot from the given path. The format is derived from the file extension.
+fn read_snapshot(path: String) -> Result<Snapshot, SnapshotError> {
+    let reader = BufReader::new(File::open(path.clone())?);
+    let snapshot: Snapshot = match SnapshotFormat::from_path(&path) {
+        SnapshotFormat::Json => serde_json::from_reader(reader)?,
+        SnapshotFormat::Cbor => serde_cbor::from_reader(reader)?,
+    };
+    if snapshot.version != SNAPSHOT_VERSION {
+        return Err(SnapshotError::UnsupportedVersion(snapshot.version));
+    }
+    Ok(snapshot)
+}
+
+#[component]
+pub struct SnapshotManagerImpl {
+    component_manager: Wrc<dyn ComponentManager>,
+
+    entity_type_manager: Wrc<dyn EntityTypeManager>,
+
+    relation_type_manager: Wrc<dyn RelationTypeManager>,
+
+    reactive_entity_instance_manager: Wrc<dyn ReactiveEntityInstanceManager>,
+
+    reactive_relation_instance_manager: Wrc<dyn ReactiveRelationInstanceManager>,
+
+    reactive_flow_manager: Wrc<dyn ReactiveFlowManager>,
+
+    config: SnapshotConfigWrapper,
+
+    running: SnapshotSchedulerRunning,
+}
+
+impl SnapshotManagerImpl {
+    fn get_sources(&self) -> SnapshotSources {
+        SnapshotSources {
+            component_manager: self.component_manager.clone(),
+            entity_type_manager: self.entity_type_manager.clone(),
+            relation_type_manager: self.relation_type_manager.clone(),
+            reactive_entity_instance_manager: self.reactive_entity_instance_manager.clone(),
+            reactive_relation_instance_manager: self.reactive_relation_instance_manager.clone(),
+            reactive_flow_manager: self.reactive_flow_manager.clone(),
+        }
+    }
+
+    /// Starts a thread which writes a snapshot to the given path in the given interval.
+    fn start_scheduler(&self, path: String, interval: Duration) {
+        let sources = self.get_sources();
+        let running = self.running.0.clone();
+        running.store(true, Ordering::Relaxed);
+        let result = thread::Builder::new().name(String::from("snapshot")).spawn(move || {
+            let mut last_snapshot = Instant::now();
+            while running.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(100));
+                if last_snapshot.elapsed() < interval {
+                    continue;
+                }
+                match sources.export(path.clone()) {
+                    Ok(_) => debug!("Wrote snapshot to {}", path),
+                    Err(e) => error!("Failed to write snapshot to {}: {}", path, e),
+                }
+                last_snapshot = Instant::now();
+            }
+        });
+        if let Err(e) = result {
+            error!("Failed to start the snapshot thread: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+#[provides]
+impl SnapshotManager for SnapshotManagerImpl {
+    fn create_snapshot(&self) -> Snapshot {
+        self.get_sources().create_snapshot()
+    }
+
+    fn restore_snapshot(&self, snapshot: Snapshot) -> Result<(), SnapshotError> {
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(snapshot.version));
+        }
+        // The type system first, because the instances depend on it
+        for component in snapshot.components {
+            if !self.component_manager.has(component.name.clone()) {
+                self.component_manager.register(component);
+            }
+        }
+        for entity_type in snapshot.entity_types {
+            if !self.entity_type_manager.has(entity_type.name.clone()) {
+                self.entity_type_manager.register(entity_type);
+            }
+        }
+        for relation_type in snapshot.relation_types {
+            if !self.relation_type_manager.has(relation_type.type_name.clone()) {
+                self.relation_type_manager.register(relation_type);
+            }
+        }
+        // The entity instances before the relation instances, because the relation instances
+        // depend on their outbound and inbound entity instances
+        for entity_instance in snapshot.entity_instances {
+            if self.reactive_entity_instance_manager.has(entity_instance.id) {
+                continue;
+            }
+            let id = entity_instance.id;
+            if let Err(e) = self.reactive_entity_instance_manager.create_reactive_instance(entity_instance) {
+                warn!("Failed to restore entity instance {} from snapshot: {}", id, e);
+            }
+        }
+        for relation_instance in snapshot.relation_instances {
+            let edge_key = match relation_instance.get_key() {
+                Some(edge_key) => edge_key,
+                None => continue,
+            };
+            if self.reactive_relation_instance_manager.has(edge_key.clone()) {
+                continue;
+            }
+            if let Err(e) = self.reactive_relation_instance_manager.create_reactive_instance(relation_instance) {
+                warn!("Failed to restore relation instance {:?} from snapshot: {}", edge_key, e);
+            }
+        }
+        for flow in snapshot.flows {
+            if self.reactive_flow_manager.has(flow.id) {
+                continue;
+            }
+            let id = flow.id;
+            if let Err(e) = self.reactive_flow_manager.create(flow) {
+                warn!("Failed to restore flow {} from snapshot: {}", id, e);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_snapshot_path(&self, name: Option<String>) -> Result<String, SnapshotError> {
+        let name = match name {
+            Some(name) => name,
+            None => return Ok(self.config.0.path.clone()),
+        };
+        let mut components = Path::new(&name).components();
+        if !matches!((components.next(), components.next()), (Some(Component::Normal(_)), None)) {
+            return Err(SnapshotError::InvalidName(name));
+        }
+        let directory = Path::new(&self.config.0.path).parent().unwrap_or_else(|| Path::new(""));
+        Ok(directory.join(name).to_string_lossy().to_string())
+    }
+
+    fn export(&self, path: String) -> Result<Snapshot, SnapshotError> {
+        self.get_sources().export(path)
+    }
+
+    fn import(&self, path: String) -> Result<Snapshot, SnapshotError> {
+        let snapshot = read_snapshot(path)?;
+        self.restore_snapshot(snapshot.clone())?;
+        Ok(snapshot)
+    }
+}
+
+impl Lifecycle for SnapshotManagerImpl {
+    fn init(&self) {}
+
+    fn post_init(&self) {
+        let snapshot_config = &self.config.0;
+        if snapshot_config.restore_on_startup && std::path::Path::new(&snapshot_config.path).exists() {
+            info!("Restoring snapshot from {}", snapshot_config.path);
+            if let Err(e) = self.import(snapshot_config.path.clone()) {
+                error!("Failed to restore snapshot from {}: {}", snapshot_config.path, e);
+            }
+        }
+        if let Some(interval) = snapshot_config.interval {
+            if interval > 0 {
+                info!("Writing a snapshot to {} every {} seconds", snapshot_config.path, interval);
+                self.start_scheduler(snapshot_config.path.clone(), Duration::from_secs(interval));
+            }
+        }
+    }
+
+    fn pre_shutdown(&self) {
+        self.running.0.store(false, Ordering::Relaxed);
+    }
+
+    fn shutdown(&self) {}
+}
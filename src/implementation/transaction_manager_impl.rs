@@ -0,0 +1,652 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use indradb::EdgeKey;
+use log::{debug, error};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::with_default_values;
+use crate::api::ComponentManager;
+use crate::api::EntityTypeManager;
+use crate::api::FlowManager;
+use crate::api::Lifecycle;
+use crate::api::PropertyValidator;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveFlowManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::api::RelationTypeManager;
+use crate::api::TransactionError;
+use crate::api::TransactionManager;
+use crate::api::TransactionOperation;
+use crate::api::TransactionResult;
+use crate::di::*;
+use crate::model::EntityInstance;
+use crate::model::Flow;
+use crate::model::PropertyInstanceGetter;
+use crate::model::PropertyInstanceSetter;
+use crate::model::RelationInstance;
+
+#[wrapper]
+pub struct TransactionLock(Mutex<()>);
+
+#[provides]
+fn create_transaction_lock() -> TransactionLock {
+    TransactionLock(Mutex::new(()))
+}
+
+/// Reverts an applied operation.
+enum UndoOperation {
+    DeleteEntityInstance(Uuid),
+    RestoreEntityInstance {
+        entity_instance: EntityInstance,
+        components: Vec<String>,
+    },
+    RestoreEntityInstanceState {
+        id: Uuid,
+        added_components: Vec<String>,
+        removed_components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+    DeleteRelationInstance(EdgeKey),
+    RestoreRelationInstance {
+        relation_instance: RelationInstance,
+        components: Vec<String>,
+    },
+    RestoreRelationInstanceState {
+        edge_key: EdgeKey,
+        added_components: Vec<String>,
+        removed_components: Vec<String>,
+        properties: HashMap<String, Value>,
+    },
+    DeleteFlow(Flow),
+    RestoreFlow(Flow),
+}
+
+/// The instances and flows which exist after the already validated operations of a
+/// transaction would have been applied.
+#[derive(Default)]
+struct TransactionState {
+    created_entity_instances: HashSet<Uuid>,
+    deleted_entity_instances: HashSet<Uuid>,
+    created_relation_instances: HashSet<EdgeKey>,
+    deleted_relation_instances: HashSet<EdgeKey>,
+    created_flows: HashSet<Uuid>,
+    deleted_flows: HashSet<Uuid>,
+    /// The property names of the entity instances which are created or updated.
+    entity_instance_properties: HashMap<Uuid, HashSet<String>>,
+    /// The property names of the relation instances which are created or updated.
+    relation_instance_properties: HashMap<EdgeKey, HashSet<String>>,
+}
+
+#[component]
+pub struct TransactionManagerImpl {
+    component_manager: Wrc<dyn ComponentManager>,
+    entity_type_manager: Wrc<dyn EntityTypeManager>,
+    relation_type_manager: Wrc<dyn RelationTypeManager>,
+    reactive_entity_instance_manager: Wrc<dyn ReactiveEntityInstanceManager>,
+    reactive_relation_instance_manager: Wrc<dyn ReactiveRelationInstanceManager>,
+    reactive_flow_manager: Wrc<dyn ReactiveFlowManager>,
+    flow_manager: Wrc<dyn FlowManager>,
+    property_validator: Wrc<dyn PropertyValidator>,
+
+    lock: TransactionLock,
+}
+
+impl TransactionManagerImpl {
+    fn has_entity_instance(&self, state: &TransactionState, id: Uuid) -> bool {
+        state.created_entity_instances.contains(&id) || (!state.deleted_entity_instances.contains(&id) && self.reactive_entity_instance_manager.has(id))
+    }
+
+    fn has_relation_instance(&self, state: &TransactionState, edge_key: &EdgeKey) -> bool {
+        state.created_relation_instances.contains(edge_key)
+            || (!state.deleted_relation_instances.contains(edge_key) && self.reactive_relation_instance_manager.has(edge_key.clone()))
+    }
+
+    fn has_flow(&self, state: &TransactionState, id: Uuid) -> bool {
+        state.created_flows.contains(&id) || (!state.deleted_flows.contains(&id) && self.reactive_flow_manager.has(id))
+    }
+
+    fn validate_components(&self, components: &[String]) -> Result<(), String> {
+        match components.iter().find(|component| !self.component_manager.has((*component).clone())) {
+            Some(component) => Err(format!("Component {} does not exist", component)),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the names of the properties of the given components.
+    fn component_property_names(&self, components: &[String]) -> HashSet<String> {
+        components
+            .iter()
+            .filter_map(|component| self.component_manager.get(component.clone()))
+            .flat_map(|component| component.properties.into_iter().map(|property| property.name))
+            .collect()
+    }
+
+    /// Returns the names of the properties of the entity instance after the previous operations.
+    fn entity_instance_property_names(&self, state: &TransactionState, id: Uuid) -> HashSet<String> {
+        match state.entity_instance_properties.get(&id) {
+            Some(property_names) => property_names.clone(),
+            None => self
+                .reactive_entity_instance_manager
+                .get(id)
+                .map(|entity_instance| entity_instance.properties.iter().map(|property| property.key().clone()).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the names of the properties of the relation instance after the previous operations.
+    fn relation_instance_property_names(&self, state: &TransactionState, edge_key: &EdgeKey) -> HashSet<String> {
+        match state.relation_instance_properties.get(edge_key) {
+            Some(property_names) => property_names.clone(),
+            None => self
+                .reactive_relation_instance_manager
+                .get(edge_key.clone())
+                .map(|relation_instance| relation_instance.properties.iter().map(|property| property.key().clone()).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Rejects properties which the instance doesn't have.
+    fn validate_property_names(property_names: &HashSet<String>, properties: &HashMap<String, Value>) -> Result<(), String> {
+        match properties.keys().find(|name| !property_names.contains(*name)) {
+            Some(name) => Err(format!("Property {} does not exist", name)),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates the operation against the state after the previous operations. Assigns a
+    /// random id to entity instances which are created without an id.
+    fn validate(&self, state: &mut TransactionState, operation: TransactionOperation) -> Result<TransactionOperation, String> {
+        match operation {
+            TransactionOperation::CreateEntityInstance {
+                type_name,
+                id,
+                components,
+                properties,
+            } => {
+                if !self.entity_type_manager.has(type_name.clone()) {
+                    return Err(format!("Entity type {} does not exist", type_name));
+                }
+                let id = id.unwrap_or_else(Uuid::new_v4);
+                if self.has_entity_instance(state, id) {
+                    return Err(format!("Entity instance {} already exists", id));
+                }
+                self.validate_components(&components)?;
+                self.property_validator
+                    .validate_new_entity_instance(type_name.clone(), &components, &properties)
+                    .map_err(|e| e.to_string())?;
+                let mut property_names: HashSet<String> = self
+                    .entity_type_manager
+                    .get(type_name.clone())
+                    .map(|entity_type| entity_type.properties.into_iter().map(|property| property.name).collect())
+                    .unwrap_or_default();
+                property_names.extend(self.component_property_names(&components));
+                property_names.extend(properties.keys().cloned());
+                state.created_entity_instances.insert(id);
+                state.entity_instance_properties.insert(id, property_names);
+                Ok(TransactionOperation::CreateEntityInstance {
+                    type_name,
+                    id: Some(id),
+                    components,
+                    properties,
+                })
+            }
+            TransactionOperation::UpdateEntityInstance {
+                id,
+                add_components,
+                remove_components,
+                properties,
+            } => {
+                if !self.has_entity_instance(state, id) {
+                    return Err(format!("Entity instance {} does not exist", id));
+                }
+                self.validate_components(&add_components)?;
+                self.validate_components(&remove_components)?;
+                let mut property_names = self.entity_instance_property_names(state, id);
+                property_names.extend(self.component_property_names(&add_components));
+                Self::validate_property_names(&property_names, &properties)?;
+                state.entity_instance_properties.insert(id, property_names);
+                Ok(TransactionOperation::UpdateEntityInstance {
+                    id,
+                    add_components,
+                    remove_components,
+                    properties,
+                })
+            }
+            TransactionOperation::DeleteEntityInstance { id } => {
+                if !self.has_entity_instance(state, id) {
+                    return Err(format!("Entity instance {} does not exist", id));
+                }
+                let mut edge_keys: Vec<EdgeKey> = self
+                    .reactive_relation_instance_manager
+                    .get_by_outbound_entity(id)
+                    .into_iter()
+                    .chain(self.reactive_relation_instance_manager.get_by_inbound_entity(id).into_iter())
+                    .filter_map(|relation_instance| relation_instance.get_key())
+                    .collect();
+                edge_keys.extend(state.created_relation_instances.iter().cloned());
+                if let Some(edge_key) = edge_keys
+                    .iter()
+                    .filter(|edge_key| edge_key.outbound_id == id || edge_key.inbound_id == id)
+                    .find(|edge_key| self.has_relation_instance(state, edge_key))
+                {
+                    return Err(format!("Entity instance {} is connected by the relation instance {:?}", id, edge_key));
+                }
+                state.created_entity_instances.remove(&id);
+                state.deleted_entity_instances.insert(id);
+                state.entity_instance_properties.remove(&id);
+                Ok(TransactionOperation::DeleteEntityInstance { id })
+            }
+            TransactionOperation::CreateRelationInstance {
+                edge_key,
+                components,
+                properties,
+            } => {
+                if self.relation_type_manager.get_starts_with(edge_key.t.to_string()).is_none() {
+                    return Err(format!("Relation type {} does not exist", edge_key.t.as_str()));
+                }
+                if !self.has_entity_instance(state, edge_key.outbound_id) {
+                    return Err(format!("Outbound entity instance {} does not exist", edge_key.outbound_id));
+                }
+                if !self.has_entity_instance(state, edge_key.inbound_id) {
+                    return Err(format!("Inbound entity instance {} does not exist", edge_key.inbound_id));
+                }
+                if self.has_relation_instance(state, &edge_key) {
+                    return Err(format!("Relation instance {:?} already exists", edge_key));
+                }
+                self.validate_components(&components)?;
+                self.property_validator
+                    .validate_new_relation_instance(edge_key.t.to_string(), &components, &properties)
+                    .map_err(|e| e.to_string())?;
+                let mut property_names: HashSet<String> = self
+                    .relation_type_manager
+                    .get_starts_with(edge_key.t.to_string())
+                    .map(|relation_type| relation_type.properties.into_iter().map(|property| property.name).collect())
+                    .unwrap_or_default();
+                property_names.extend(self.component_property_names(&components));
+                property_names.extend(properties.keys().cloned());
+                state.created_relation_instances.insert(edge_key.clone());
+                state.relation_instance_properties.insert(edge_key.clone(), property_names);
+                Ok(TransactionOperation::CreateRelationInstance {
+                    edge_key,
+                    components,
+                    properties,
+                })
+            }
+            TransactionOperation::UpdateRelationInstance {
+                edge_key,
+                add_components,
+                remove_components,
+                properties,
+            } => {
+                if !self.has_relation_instance(state, &edge_key) {
+                    return Err(format!("Relation instance {:?} does not exist", edge_key));
+                }
+                self.validate_components(&add_components)?;
+                self.validate_components(&remove_components)?;
+                let mut property_names = self.relation_instance_property_names(state, &edge_key);
+                property_names.extend(self.component_property_names(&add_components));
+                Self::validate_property_names(&property_names, &properties)?;
+                state.relation_instance_properties.insert(edge_key.clone(), property_names);
+                Ok(TransactionOperation::UpdateRelationInstance {
+                    edge_key,
+                    add_components,
+                    remove_components,
+                    properties,
+                })
+            }
+            TransactionOperation::DeleteRelationInstance { edge_key } => {
+                if !self.has_relation_instance(state, &edge_key) {
+                    return Err(format!("Relation instance {:?} does not exist", edge_key));
+                }
+                state.created_relation_instances.remove(&edge_key);
+                state.deleted_relation_instances.insert(edge_key.clone());
+                state.relation_instance_properties.remove(&edge_key);
+                Ok(TransactionOperation::DeleteRelationInstance { edge_key })
+            }
+            TransactionOperation::CreateFlow { flow } => {
+                if self.has_flow(state, flow.id) {
+                    return Err(format!("Flow {} already exists", flow.id));
+                }
+                if !flow.entity_instances.iter().any(|entity_instance| entity_instance.id == flow.id) {
+                    return Err(format!("Flow {} doesn't contain the wrapper entity instance", flow.id));
+                }
+                if let Some(entity_instance) = flow
+                    .entity_instances
+                    .iter()
+                    .find(|entity_instance| self.has_entity_instance(state, entity_instance.id))
+                {
+                    return Err(format!("Entity instance {} already exists", entity_instance.id));
+                }
+                if let Some(edge_key) = flow
+                    .relation_instances
+                    .iter()
+                    .filter_map(|relation_instance| relation_instance.get_key())
+                    .find(|edge_key| self.has_relation_instance(state, edge_key))
+                {
+                    return Err(format!("Relation instance {:?} already exists", edge_key));
+                }
+                state.created_flows.insert(flow.id);
+                for entity_instance in flow.entity_instances.iter() {
+                    state.created_entity_instances.insert(entity_instance.id);
+                }
+                for edge_key in flow.relation_instances.iter().filter_map(|relation_instance| relation_instance.get_key()) {
+                    state.created_relation_instances.insert(edge_key);
+                }
+                Ok(TransactionOperation::CreateFlow { flow })
+            }
+            TransactionOperation::DeleteFlow { id } => {
+                if !self.has_flow(state, id) {
+                    return Err(format!("Flow {} does not exist", id));
+                }
+                state.created_flows.remove(&id);
+                state.deleted_flows.insert(id);
+                Ok(TransactionOperation::DeleteFlow { id })
+            }
+        }
+    }
+
+    /// Applies the validated operation and returns the operation which reverts it.
+    fn apply(&self, operation: TransactionOperation, result: &mut TransactionResult) -> Result<UndoOperation, String> {
+        match operation {
+            TransactionOperation::CreateEntityInstance {
+                type_name,
+                id,
+                components,
+                mut properties,
+            } => {
+                let id = id.unwrap_or_else(Uuid::new_v4);
+                if let Some(entity_type) = self.entity_type_manager.get(type_name.clone()) {
+                    properties = with_default_values(&entity_type.properties, properties);
+                }
+                let entity_instance = self
+                    .reactive_entity_instance_manager
+                    .create_with_id(type_name, id, properties)
+                    .map_err(|e| e.to_string())?;
+                for component in components {
+                    self.reactive_entity_instance_manager.add_component(id, component);
+                }
+                result.entity_instances.push(entity_instance);
+                Ok(UndoOperation::DeleteEntityInstance(id))
+            }
+            TransactionOperation::UpdateEntityInstance {
+                id,
+                add_components,
+                remove_components,
+                properties,
+            } => {
+                let entity_instance = self
+                    .reactive_entity_instance_manager
+                    .get(id)
+                    .ok_or_else(|| format!("Entity instance {} does not exist", id))?;
+                self.property_validator
+                    .validate_entity_instance(&entity_instance, &add_components, &properties)
+                    .map_err(|e| e.to_string())?;
+                let added_components: Vec<String> = add_components
+                    .into_iter()
+                    .filter(|component| !entity_instance.components.contains(component))
+                    .collect();
+                let removed_components: Vec<String> = remove_components
+                    .into_iter()
+                    .filter(|component| entity_instance.components.contains(component))
+                    .collect();
+                for component in added_components.iter() {
+                    self.reactive_entity_instance_manager.add_component(id, component.clone());
+                }
+                for component in removed_components.iter() {
+                    self.reactive_entity_instance_manager.remove_component(id, component.clone());
+                }
+                let mut previous_properties = HashMap::new();
+                for (name, value) in properties {
+                    if let Some(previous_value) = entity_instance.get(name.clone()) {
+                        previous_properties.insert(name.clone(), previous_value);
+                        entity_instance.set_no_propagate(name, value);
+                    }
+                }
+                entity_instance.tick();
+                result.entity_instances.push(entity_instance);
+                Ok(UndoOperation::RestoreEntityInstanceState {
+                    id,
+                    added_components,
+                    removed_components,
+                    properties: previous_properties,
+                })
+            }
+            TransactionOperation::DeleteEntityInstance { id } => {
+                let entity_instance = self
+                    .reactive_entity_instance_manager
+                    .get(id)
+                    .ok_or_else(|| format!("Entity instance {} does not exist", id))?;
+                let components = entity_instance.components.iter().map(|component| component.key().clone()).collect();
+                let entity_instance = EntityInstance::from(entity_instance);
+                self.reactive_entity_instance_manager.delete(id);
+                if self.reactive_entity_instance_manager.has(id) {
+                    return Err(format!("Failed to delete entity instance {}", id));
+                }
+                Ok(UndoOperation::RestoreEntityInstance { entity_instance, components })
+            }
+            TransactionOperation::CreateRelationInstance {
+                edge_key,
+                components,
+                mut properties,
+            } => {
+                if let Some(relation_type) = self.relation_type_manager.get_starts_with(edge_key.t.to_string()) {
+                    properties = with_default_values(&relation_type.properties, properties);
+                }
+                let relation_instance = self
+                    .reactive_relation_instance_manager
+                    .create(edge_key.clone(), properties)
+                    .map_err(|e| e.to_string())?;
+                for component in components {
+                    self.reactive_relation_instance_manager.add_component(edge_key.clone(), component);
+                }
+                result.relation_instances.push(relation_instance);
+                Ok(UndoOperation::DeleteRelationInstance(edge_key))
+            }
+            TransactionOperation::UpdateRelationInstance {
+                edge_key,
+                add_components,
+                remove_components,
+                properties,
+            } => {
+                let relation_instance = self
+                    .reactive_relation_instance_manager
+                    .get(edge_key.clone())
+                    .ok_or_else(|| format!("Relation instance {:?} does not exist", edge_key))?;
+                self.property_validator
+                    .validate_relation_instance(&relation_instance, &add_components, &properties)
+                    .map_err(|e| e.to_string())?;
+                let added_components: Vec<String> = add_components
+                    .into_iter()
+                    .filter(|component| !relation_instance.components.contains(component))
+                    .collect();
+                let removed_components: Vec<String> = remove_components
+                    .into_iter()
+                    .filter(|component| relation_instance.components.contains(component))
+                    .collect();
+                for component in added_components.iter() {
+                    self.reactive_relation_instance_manager.add_component(edge_key.clone(), component.clone());
+                }
+                for component in removed_components.iter() {
+                    self.reactive_relation_instance_manager.remove_component(edge_key.clone(), component.clone());
+                }
+                let mut previous_properties = HashMap::new();
+                for (name, value) in properties {
+                    if let Some(previous_value) = relation_instance.get(name.clone()) {
+                        previous_properties.insert(name.clone(), previous_value);
+                        relation_instance.set_no_propagate(name, value);
+                    }
+                }
+                relation_instance.tick();
+                result.relation_instances.push(relation_instance);
+                Ok(UndoOperation::RestoreRelationInstanceState {
+                    edge_key,
+                    added_components,
+                    removed_components,
+                    properties: previous_properties,
+                })
+            }
+            TransactionOperation::DeleteRelationInstance { edge_key } => {
+                let relation_instance = self
+                    .reactive_relation_instance_manager
+                    .get(edge_key.clone())
+                    .ok_or_else(|| format!("Relation instance {:?} does not exist", edge_key))?;
+                let components = relation_instance.components.iter().map(|component| component.key().clone()).collect();
+                let relation_instance = RelationInstance::from(relation_instance);
+                if !self.reactive_relation_instance_manager.delete(edge_key.clone()) {
+                    return Err(format!("Failed to delete relation instance {:?}", edge_key));
+                }
+                Ok(UndoOperation::RestoreRelationInstance { relation_instance, components })
+            }
+            TransactionOperation::CreateFlow { flow } => {
+                let reactive_flow = self.reactive_flow_manager.create(flow.clone()).map_err(|e| e.to_string())?;
+                result.flows.push(reactive_flow);
+                Ok(UndoOperation::DeleteFlow(flow))
+            }
+            TransactionOperation::DeleteFlow { id } => {
+                let reactive_flow = self.reactive_flow_manager.get(id).ok_or_else(|| format!("Flow {} does not exist", id))?;
+                let flow = Flow::try_from(reactive_flow).map_err(|_| format!("Failed to export flow {}", id))?;
+                self.reactive_flow_manager.delete(id);
+                Ok(UndoOperation::RestoreFlow(flow))
+            }
+        }
+    }
+
+    fn undo(&self, operation: UndoOperation) {
+        match operation {
+            UndoOperation::DeleteEntityInstance(id) => self.reactive_entity_instance_manager.delete(id),
+            UndoOperation::RestoreEntityInstance { entity_instance, components } => {
+                let id = entity_instance.id;
+                match self
+                    .reactive_entity_instance_manager
+                    .create_with_id(entity_instance.type_name, id, entity_instance.properties)
+                {
+                    Ok(_) => {
+                        for component in components {
+                            self.reactive_entity_instance_manager.add_component(id, component);
+                        }
+                    }
+                    Err(e) => error!("Failed to restore entity instance {}: {}", id, e),
+                }
+            }
+            UndoOperation::RestoreEntityInstanceState {
+                id,
+                added_components,
+                removed_components,
+                properties,
+            } => {
+                if let Some(entity_instance) = self.reactive_entity_instance_manager.get(id) {
+                    for (name, value) in properties {
+                        entity_instance.set_no_propagate(name, value);
+                    }
+                    for component in removed_components {
+                        self.reactive_entity_instance_manager.add_component(id, component);
+                    }
+                    for component in added_components {
+                        self.reactive_entity_instance_manager.remove_component(id, component);
+                    }
+                    entity_instance.tick();
+                }
+            }
+            UndoOperation::DeleteRelationInstance(edge_key) => {
+                self.reactive_relation_instance_manager.delete(edge_key);
+            }
+            UndoOperation::RestoreRelationInstance { relation_instance, components } => {
+                let edge_key = relation_instance.get_key();
+                match self.reactive_relation_instance_manager.create_reactive_instance(relation_instance) {
+                    Ok(_) => {
+                        if let Some(edge_key) = edge_key {
+                            for component in components {
+                                self.reactive_relation_instance_manager.add_component(edge_key.clone(), component);
+                            }
+                        }
+                    }
+                    Err(e) => error!("Failed to restore relation instance {:?}: {}", edge_key, e),
+                }
+            }
+            UndoOperation::RestoreRelationInstanceState {
+                edge_key,
+                added_components,
+                removed_components,
+                properties,
+            } => {
+                if let Some(relation_instance) = self.reactive_relation_instance_manager.get(edge_key.clone()) {
+                    for (name, value) in properties {
+                        relation_instance.set_no_propagate(name, value);
+                    }
+                    for component in removed_components {
+                        self.reactive_relation_instance_manager.add_component(edge_key.clone(), component);
+                    }
+                    for component in added_components {
+                        self.reactive_relation_instance_manager.remove_component(edge_key.clone(), component);
+                    }
+                    relation_instance.tick();
+                }
+            }
+            UndoOperation::DeleteFlow(flow) => {
+                self.reactive_flow_manager.delete(flow.id);
+                // Reverse order: first relations then entities
+                for edge_key in flow.relation_instances.iter().filter_map(|relation_instance| relation_instance.get_key()) {
+                    self.reactive_relation_instance_manager.delete(edge_key);
+                }
+                for entity_instance in flow.entity_instances.iter() {
+                    self.reactive_entity_instance_manager.delete(entity_instance.id);
+                }
+                self.flow_manager.delete(flow);
+            }
+            UndoOperation::RestoreFlow(flow) => {
+                let id = flow.id;
+                if let Err(e) = self.reactive_flow_manager.create(flow) {
+                    error!("Failed to restore flow {}: {}", id, e);
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+#[provides]
+impl TransactionManager for TransactionManagerImpl {
+    fn execute(&self, operations: Vec<TransactionOperation>) -> Result<TransactionResult, TransactionError> {
+        let _guard = self.lock.0.lock().unwrap();
+
+        let mut state = TransactionState::default();
+        let mut validated_operations = Vec::new();
+        for (index, operation) in operations.into_iter().enumerate() {
+            let operation = self
+                .validate(&mut state, operation)
+                .map_err(|message| TransactionError::Invalid { index, message })?;
+            validated_operations.push(operation);
+        }
+
+        let mut result = TransactionResult::default();
+        let mut undo_operations = Vec::new();
+        for (index, operation) in validated_operations.into_iter().enumerate() {
+            match self.apply(operation, &mut result) {
+                Ok(undo_operation) => undo_operations.push(undo_operation),
+                Err(message) => {
+                    debug!("Rolling back {} operations of the transaction: {}", undo_operations.len(), message);
+                    for undo_operation in undo_operations.into_iter().rev() {
+                        self.undo(undo_operation);
+                    }
+                    return Err(TransactionError::Failed { index, message });
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl Lifecycle for TransactionManagerImpl {
+    fn init(&self) {}
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
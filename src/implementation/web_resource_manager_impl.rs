@@ -53,6 +53,11 @@ impl WebResourceManager for WebResourceManagerImpl {
         debug!("Registering web resource provider with base path: {}", base_path);
         self.web_resource_providers.0.write().unwrap().insert(base_path, web_resource_provider.clone());
     }
+
+    fn remove_provider(&self, base_path: String) {
+        debug!("Unregistering web resource provider with base path: {}", base_path);
+        self.web_resource_providers.0.write().unwrap().remove(base_path.as_str());
+    }
 }
 
 impl Lifecycle for WebResourceManagerImpl {
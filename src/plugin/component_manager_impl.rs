@@ -1,3 +1,4 @@
+use crate::api::{with_event_source, SystemEventSource};
 use crate::model::{Component, PropertyType};
 use crate::plugins::component_manager::ComponentCreationError;
 use crate::plugins::ComponentManager;
@@ -30,15 +31,15 @@ impl ComponentManager for ComponentManagerImpl {
     }
 
     fn create(&self, name: String, properties: Vec<PropertyType>) {
-        self.component_manager.create(name, properties)
+        with_event_source(SystemEventSource::Plugin, || self.component_manager.create(name, properties))
     }
 
     fn delete(&self, name: String) {
-        self.component_manager.delete(name)
+        with_event_source(SystemEventSource::Plugin, || self.component_manager.delete(name))
     }
 
     fn import(&self, path: String) {
-        self.component_manager.import(path)
+        with_event_source(SystemEventSource::Plugin, || self.component_manager.import(path))
     }
 
     fn export(&self, name: String, path: String) {
@@ -2,12 +2,46 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PluginsConfig {
+    /// If true, the application shuts down if a plugin fails to load or to initialize.
+    #[serde(default)]
+    pub strict: bool,
+
+    /// The deploy directory.
+    pub deploy: Option<PluginDeployConfig>,
+
+    #[serde(default = "Vec::new")]
     pub plugin: Vec<PluginConfig>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PluginConfig {
     pub name: String,
     pub active: bool,
     pub path: String,
+
+    /// The names of the plugins which have to be loaded before this plugin.
+    #[serde(default = "Vec::new")]
+    pub dependencies: Vec<String>,
+
+    /// The settings of the plugin (the table `[plugin.config]`). The settings are passed to the
+    /// plugin via the plugin context.
+    pub config: Option<toml::Value>,
+}
+
+/// Libraries which are dropped into the deploy directory are loaded automatically. Overwriting
+/// a library reloads the plugin and deleting a library unloads the plugin.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PluginDeployConfig {
+    pub enabled: bool,
+
+    /// The path of the deploy directory.
+    pub path: String,
+
+    /// The interval in milliseconds in which the deploy directory is scanned.
+    #[serde(default = "default_deploy_interval")]
+    pub interval: u64,
+}
+
+fn default_deploy_interval() -> u64 {
+    1000
 }
@@ -1,4 +1,9 @@
 use crate::plugins::{ComponentManager, EntityInstanceManager, EntityTypeManager, FlowManager, PluginContext, RelationInstanceManager, RelationTypeManager};
+// Requires the unreleased revision of the plugin API
+#[cfg(feature = "plugin-api-next")]
+use crate::plugins::{GraphQLQueryExecutor, GraphQueryManager, SystemEventManager};
+#[cfg(feature = "plugin-api-next")]
+use serde_json::Value;
 use std::sync::Arc;
 
 pub struct PluginContextImpl {
@@ -8,9 +13,18 @@ pub struct PluginContextImpl {
     entity_instance_manager: Arc<dyn EntityInstanceManager>,
     relation_instance_manager: Arc<dyn RelationInstanceManager>,
     flow_manager: Arc<dyn FlowManager>,
+    #[cfg(feature = "plugin-api-next")]
+    system_event_manager: Arc<dyn SystemEventManager>,
+    #[cfg(feature = "plugin-api-next")]
+    graph_query_manager: Arc<dyn GraphQueryManager>,
+    #[cfg(feature = "plugin-api-next")]
+    graphql_query_executor: Arc<dyn GraphQLQueryExecutor>,
+    #[cfg(feature = "plugin-api-next")]
+    config: Option<Value>,
 }
 
 impl PluginContextImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         component_manager: Arc<dyn ComponentManager>,
         entity_type_manager: Arc<dyn EntityTypeManager>,
@@ -18,6 +32,10 @@ impl PluginContextImpl {
         entity_instance_manager: Arc<dyn EntityInstanceManager>,
         relation_instance_manager: Arc<dyn RelationInstanceManager>,
         flow_manager: Arc<dyn FlowManager>,
+        #[cfg(feature = "plugin-api-next")] system_event_manager: Arc<dyn SystemEventManager>,
+        #[cfg(feature = "plugin-api-next")] graph_query_manager: Arc<dyn GraphQueryManager>,
+        #[cfg(feature = "plugin-api-next")] graphql_query_executor: Arc<dyn GraphQLQueryExecutor>,
+        #[cfg(feature = "plugin-api-next")] config: Option<Value>,
     ) -> Self {
         PluginContextImpl {
             component_manager,
@@ -26,6 +44,14 @@ impl PluginContextImpl {
             entity_instance_manager,
             relation_instance_manager,
             flow_manager,
+            #[cfg(feature = "plugin-api-next")]
+            system_event_manager,
+            #[cfg(feature = "plugin-api-next")]
+            graph_query_manager,
+            #[cfg(feature = "plugin-api-next")]
+            graphql_query_executor,
+            #[cfg(feature = "plugin-api-next")]
+            config,
         }
     }
 }
@@ -54,4 +80,24 @@ impl PluginContext for PluginContextImpl {
     fn get_flow_manager(&self) -> Arc<dyn FlowManager> {
         self.flow_manager.clone()
     }
+
+    #[cfg(feature = "plugin-api-next")]
+    fn get_system_event_manager(&self) -> Arc<dyn SystemEventManager> {
+        self.system_event_manager.clone()
+    }
+
+    #[cfg(feature = "plugin-api-next")]
+    fn get_graph_query_manager(&self) -> Arc<dyn GraphQueryManager> {
+        self.graph_query_manager.clone()
+    }
+
+    #[cfg(feature = "plugin-api-next")]
+    fn get_graphql_query_executor(&self) -> Arc<dyn GraphQLQueryExecutor> {
+        self.graphql_query_executor.clone()
+    }
+
+    #[cfg(feature = "plugin-api-next")]
+    fn get_config(&self) -> Option<Value> {
+        self.config.clone()
+    }
 }
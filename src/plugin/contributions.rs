@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::plugins::{ComponentBehaviourProvider, EntityBehaviourProvider, FlowProvider, RelationBehaviourProvider};
+
+/// The types, flows, web resources and providers which have been contributed by a plugin. They
+/// are removed from the respective managers when the plugin gets unloaded.
+#[derive(Default, Clone)]
+pub struct PluginContributions {
+    /// The path of the library which contains the plugin.
+    pub path: String,
+
+    /// The names of the components.
+    pub components: Vec<String>,
+
+    /// The names of the entity types.
+    pub entity_types: Vec<String>,
+
+    /// The names of the relation types.
+    pub relation_types: Vec<String>,
+
+    /// The ids of the flows.
+    pub flows: Vec<Uuid>,
+
+    /// The base paths of the web resources.
+    pub web_resources: Vec<String>,
+
+    pub component_behaviour_provider: Option<Arc<dyn ComponentBehaviourProvider>>,
+
+    pub entity_behaviour_provider: Option<Arc<dyn EntityBehaviourProvider>>,
+
+    pub relation_behaviour_provider: Option<Arc<dyn RelationBehaviourProvider>>,
+
+    pub flow_provider: Option<Arc<dyn FlowProvider>>,
+}
+
+impl PluginContributions {
+    pub fn new(path: String) -> Self {
+        PluginContributions { path, ..Default::default() }
+    }
+}
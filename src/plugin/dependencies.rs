@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::plugin::config::PluginConfig;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginDependencyError {
+    /// The plugin depends on plugins which are not configured, not active or not loaded.
+    UnmetDependencies { name: String, dependencies: Vec<String> },
+
+    /// The plugin depends (directly or indirectly) on itself.
+    CyclicDependency { name: String },
+}
+
+impl PluginDependencyError {
+    /// Returns the name of the refused plugin.
+    pub fn name(&self) -> &str {
+        match self {
+            PluginDependencyError::UnmetDependencies { name, .. } => name,
+            PluginDependencyError::CyclicDependency { name } => name,
+        }
+    }
+}
+
+impl fmt::Display for PluginDependencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            PluginDependencyError::UnmetDependencies { name, dependencies } => {
+                write!(f, "Plugin {} has unmet dependencies: {}", name, dependencies.join(", "))
+            }
+            PluginDependencyError::CyclicDependency { name } => write!(f, "Plugin {} has a cyclic dependency", name),
+        }
+    }
+}
+
+/// Computes the load order of the active plugins: each plugin is loaded after its dependencies.
+/// Plugins without dependencies between each other are loaded in the order of definition.
+///
+/// Plugins with dependencies which are not configured or not active are refused, as well as
+/// plugins which depend on refused plugins and plugins with cyclic dependencies.
+pub fn resolve_load_order(plugins: &[PluginConfig]) -> (Vec<&PluginConfig>, Vec<PluginDependencyError>) {
+    let mut errors = Vec::new();
+    // Refuse plugins with unmet dependencies until no more plugins are refused
+    let mut remaining: Vec<&PluginConfig> = plugins.iter().filter(|plugin| plugin.active).collect();
+    loop {
+        let names: HashSet<&String> = remaining.iter().map(|plugin| &plugin.name).collect();
+        let (resolvable, refused): (Vec<&PluginConfig>, Vec<&PluginConfig>) = remaining
+            .iter()
+            .partition(|plugin| plugin.dependencies.iter().all(|dependency| names.contains(dependency)));
+        if refused.is_empty() {
+            break;
+        }
+        for plugin in refused {
+            errors.push(PluginDependencyError::UnmetDependencies {
+                name: plugin.name.clone(),
+                dependencies: plugin.dependencies.iter().filter(|dependency| !names.contains(dependency)).cloned().collect(),
+            });
+        }
+        remaining = resolvable;
+    }
+    // Topological sort which keeps the order of definition if possible
+    let mut ordered: Vec<&PluginConfig> = Vec::new();
+    let mut loaded: HashSet<&String> = HashSet::new();
+    while !remaining.is_empty() {
+        match remaining
+            .iter()
+            .position(|plugin| plugin.dependencies.iter().all(|dependency| loaded.contains(dependency)))
+        {
+            Some(index) => {
+                let plugin = remaining.remove(index);
+                loaded.insert(&plugin.name);
+                ordered.push(plugin);
+            }
+            None => {
+                // All remaining plugins are part of or depend on a cycle
+                for plugin in remaining.drain(..) {
+                    errors.push(PluginDependencyError::CyclicDependency { name: plugin.name.clone() });
+                }
+            }
+        }
+    }
+    (ordered, errors)
+}
@@ -1,4 +1,4 @@
-use crate::api::{EntityTypeManager, ReactiveEntityInstanceManager};
+use crate::api::{with_default_values, with_event_source, EntityTypeManager, ReactiveEntityInstanceManager, SystemEventSource};
 use crate::model::{EntityInstance, ReactiveEntityInstance};
 use crate::plugins::entity_instance_manager::EntityInstanceCreationError;
 use crate::plugins::EntityInstanceManager;
@@ -49,14 +49,11 @@ impl EntityInstanceManager for EntityInstanceManagerImpl {
         match entity_type {
             Some(entity_type) => {
                 let mut entity_instance = entity_instance;
-                for property in entity_type.properties.iter() {
-                    if !entity_instance.properties.contains_key(&property.name) {
-                        entity_instance.properties.insert(property.name.clone(), property.data_type.default_value());
-                    }
-                }
-                let reactive_entity_instance =
+                entity_instance.properties = with_default_values(&entity_type.properties, entity_instance.properties);
+                let reactive_entity_instance = with_event_source(SystemEventSource::Plugin, || {
                     self.reactive_entity_instance_manager
-                        .create_with_id(entity_instance.type_name, entity_instance.id, entity_instance.properties);
+                        .create_with_id(entity_instance.type_name, entity_instance.id, entity_instance.properties)
+                });
                 match reactive_entity_instance {
                     Ok(reactive_entity_instance) => Ok(reactive_entity_instance),
                     Err(_) => Err(EntityInstanceCreationError::Failed),
@@ -67,14 +64,14 @@ impl EntityInstanceManager for EntityInstanceManagerImpl {
     }
 
     fn add_component(&self, id: Uuid, component: String) {
-        self.reactive_entity_instance_manager.add_component(id, component);
+        with_event_source(SystemEventSource::Plugin, || self.reactive_entity_instance_manager.add_component(id, component));
     }
 
     fn remove_component(&self, id: Uuid, component: String) {
-        self.reactive_entity_instance_manager.remove_component(id, component);
+        with_event_source(SystemEventSource::Plugin, || self.reactive_entity_instance_manager.remove_component(id, component));
     }
 
     fn delete(&self, id: Uuid) {
-        self.reactive_entity_instance_manager.delete(id);
+        with_event_source(SystemEventSource::Plugin, || self.reactive_entity_instance_manager.delete(id));
     }
 }
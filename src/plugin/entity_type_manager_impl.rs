@@ -1,3 +1,4 @@
+use crate::api::{with_event_source, SystemEventSource};
 use crate::model::{EntityType, Extension, PropertyType};
 use crate::plugins::entity_type_manager::EntityTypeCreationError;
 use crate::plugins::EntityTypeManager;
@@ -30,15 +31,15 @@ impl EntityTypeManager for EntityTypeManagerImpl {
     }
 
     fn create(&self, name: String, group: String, components: Vec<String>, properties: Vec<PropertyType>, extensions: Vec<Extension>) {
-        self.entity_type_manager.create(name, group, components, properties, extensions)
+        with_event_source(SystemEventSource::Plugin, || self.entity_type_manager.create(name, group, components, properties, extensions))
     }
 
     fn delete(&self, name: String) {
-        self.entity_type_manager.delete(name)
+        with_event_source(SystemEventSource::Plugin, || self.entity_type_manager.delete(name))
     }
 
     fn import(&self, path: String) {
-        let _result = self.entity_type_manager.import(path);
+        let _result = with_event_source(SystemEventSource::Plugin, || self.entity_type_manager.import(path));
     }
 
     fn export(&self, name: String, path: String) {
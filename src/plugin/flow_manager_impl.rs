@@ -1,4 +1,4 @@
-use crate::api::ReactiveFlowManager;
+use crate::api::{with_event_source, ReactiveFlowManager, SystemEventSource};
 use crate::model::{Flow, ReactiveFlow};
 use crate::plugins::flow_manager::FlowCreationError;
 use crate::plugins::FlowManager;
@@ -28,7 +28,7 @@ impl FlowManager for FlowManagerImpl {
     }
 
     fn create(&self, flow: Flow) -> Result<Arc<ReactiveFlow>, FlowCreationError> {
-        let reactive_flow = self.reactive_flow_manager.create(flow);
+        let reactive_flow = with_event_source(SystemEventSource::Plugin, || self.reactive_flow_manager.create(flow));
         match reactive_flow {
             Ok(reactive_flow) => Ok(reactive_flow),
             Err(_) => Err(FlowCreationError::Failed),
@@ -36,6 +36,6 @@ impl FlowManager for FlowManagerImpl {
     }
 
     fn delete(&self, id: Uuid) {
-        self.reactive_flow_manager.delete(id);
+        with_event_source(SystemEventSource::Plugin, || self.reactive_flow_manager.delete(id));
     }
 }
@@ -0,0 +1,82 @@
+use crate::api::{ReactiveEntityInstanceManager, ReactiveFlowManager, ReactiveRelationInstanceManager};
+use crate::model::{ReactiveEntityInstance, ReactiveFlow, ReactiveRelationInstance};
+use crate::plugins::GraphQueryManager;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GraphQueryManagerImpl {
+    reactive_entity_instance_manager: Arc<dyn ReactiveEntityInstanceManager>,
+    reactive_relation_instance_manager: Arc<dyn ReactiveRelationInstanceManager>,
+    reactive_flow_manager: Arc<dyn ReactiveFlowManager>,
+}
+
+impl GraphQueryManagerImpl {
+    pub fn new(
+        reactive_entity_instance_manager: Arc<dyn ReactiveEntityInstanceManager>,
+        reactive_relation_instance_manager: Arc<dyn ReactiveRelationInstanceManager>,
+        reactive_flow_manager: Arc<dyn ReactiveFlowManager>,
+    ) -> Self {
+        Self {
+            reactive_entity_instance_manager,
+            reactive_relation_instance_manager,
+            reactive_flow_manager,
+        }
+    }
+}
+
+/// Returns true, if the relation instance is of the given relation type. Relation instances
+/// may have a type name which starts with the name of the relation type.
+fn is_of_type(relation_instance: &ReactiveRelationInstance, type_name: &Option<String>) -> bool {
+    match type_name {
+        Some(type_name) => relation_instance.type_name.starts_with(type_name.as_str()),
+        None => true,
+    }
+}
+
+impl GraphQueryManager for GraphQueryManagerImpl {
+    fn get_entity_instance_by_label(&self, label: String) -> Option<Arc<ReactiveEntityInstance>> {
+        self.reactive_entity_instance_manager.get_by_label(label)
+    }
+
+    fn get_outbound_relations(&self, id: Uuid, type_name: Option<String>) -> Vec<Arc<ReactiveRelationInstance>> {
+        self.reactive_relation_instance_manager
+            .get_by_outbound_entity(id)
+            .into_iter()
+            .filter(|relation_instance| is_of_type(relation_instance, &type_name))
+            .collect()
+    }
+
+    fn get_inbound_relations(&self, id: Uuid, type_name: Option<String>) -> Vec<Arc<ReactiveRelationInstance>> {
+        self.reactive_relation_instance_manager
+            .get_by_inbound_entity(id)
+            .into_iter()
+            .filter(|relation_instance| is_of_type(relation_instance, &type_name))
+            .collect()
+    }
+
+    fn get_outbound_entities(&self, id: Uuid, type_name: Option<String>) -> Vec<Arc<ReactiveEntityInstance>> {
+        self.get_outbound_relations(id, type_name)
+            .into_iter()
+            .map(|relation_instance| relation_instance.inbound.clone())
+            .collect()
+    }
+
+    fn get_inbound_entities(&self, id: Uuid, type_name: Option<String>) -> Vec<Arc<ReactiveEntityInstance>> {
+        self.get_inbound_relations(id, type_name)
+            .into_iter()
+            .map(|relation_instance| relation_instance.outbound.clone())
+            .collect()
+    }
+
+    fn get_flow_by_label(&self, label: String) -> Option<Arc<ReactiveFlow>> {
+        self.reactive_flow_manager.get_by_label(label)
+    }
+
+    fn get_flows_by_type(&self, type_name: String) -> Vec<Arc<ReactiveFlow>> {
+        self.reactive_flow_manager
+            .get_all()
+            .into_iter()
+            .filter(|flow| flow.type_name == type_name)
+            .collect()
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+
+use log::error;
+use serde::de::Error;
+
+use crate::api::GraphQLQueryService;
+use crate::plugins::GraphQLQueryExecutor;
+
+/// A GraphQL request and the channel which receives the response.
+type GraphQLQueryJob = (String, mpsc::Sender<Result<String, serde_json::Error>>);
+
+/// Executes the GraphQL requests of a plugin on a dedicated thread.
+///
+/// Plugins call the executor synchronously, often from within a task of the async runtime.
+/// Blocking on the query future on the calling thread could deadlock the runtime, therefore the
+/// query future is driven by the executor thread while the calling thread waits for the response.
+/// The executor thread ends when the executor is dropped.
+pub struct GraphQLQueryExecutorImpl {
+    sender: Mutex<mpsc::Sender<GraphQLQueryJob>>,
+}
+
+impl GraphQLQueryExecutorImpl {
+    pub fn new(graphql_query_service: Arc<dyn GraphQLQueryService>) -> Self {
+        let (sender, receiver) = mpsc::channel::<GraphQLQueryJob>();
+        let result = thread::Builder::new().name(String::from("graphql-query-executor")).spawn(move || {
+            for (request, response_sender) in receiver {
+                let response = futures::executor::block_on(graphql_query_service.query(request));
+                let _ = response_sender.send(response);
+            }
+        });
+        if let Err(e) = result {
+            error!("Failed to start the GraphQL query executor thread: {}", e);
+        }
+        Self { sender: Mutex::new(sender) }
+    }
+}
+
+impl GraphQLQueryExecutor for GraphQLQueryExecutorImpl {
+    fn execute(&self, request: String) -> Result<String, serde_json::Error> {
+        let (response_sender, response_receiver) = mpsc::channel();
+        self.sender
+            .lock()
+            .unwrap()
+            .send((request, response_sender))
+            .map_err(|_| serde_json::Error::custom("The GraphQL query executor is not running"))?;
+        response_receiver
+            .recv()
+            .map_err(|_| serde_json::Error::custom("The GraphQL query executor stopped without a response"))?
+    }
+}
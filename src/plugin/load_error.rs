@@ -0,0 +1,86 @@
+use std::fmt;
+
+use strum_macros::Display;
+
+use crate::plugin::PluginDependencyError;
+
+/// The kind of error which prevented a plugin from being loaded or initialized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Display)]
+pub enum PluginLoadErrorKind {
+    MissingFile,
+    LibraryError,
+    MissingDeclaration,
+    PluginNotFound,
+    RustcVersionMismatch,
+    ApiVersionMismatch,
+    UnmetDependencies,
+    InitFailed,
+    PostInitFailed,
+}
+
+/// The reason why a plugin failed to load or to initialize.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginLoadError {
+    /// The library file doesn't exist.
+    MissingFile { path: String },
+
+    /// The library exists but couldn't be loaded.
+    LibraryError { path: String, message: String },
+
+    /// The library doesn't export the `plugin_declaration` symbol.
+    MissingDeclaration { path: String },
+
+    /// The library doesn't contain a plugin with the expected name.
+    PluginNotFound { path: String },
+
+    /// The library has been compiled with another version of rustc.
+    RustcVersionMismatch { path: String, version: String, expected: String },
+
+    /// The library has been compiled against another version of the plugin API.
+    ApiVersionMismatch { path: String, version: String, expected: String },
+
+    /// The plugin depends on plugins which are not loaded.
+    UnmetDependencies(PluginDependencyError),
+
+    /// The plugin failed to initialize.
+    InitFailed { message: String },
+
+    /// The plugin failed to post-initialize.
+    PostInitFailed { message: String },
+}
+
+impl PluginLoadError {
+    pub fn kind(&self) -> PluginLoadErrorKind {
+        match self {
+            PluginLoadError::MissingFile { .. } => PluginLoadErrorKind::MissingFile,
+            PluginLoadError::LibraryError { .. } => PluginLoadErrorKind::LibraryError,
+            PluginLoadError::MissingDeclaration { .. } => PluginLoadErrorKind::MissingDeclaration,
+            PluginLoadError::PluginNotFound { .. } => PluginLoadErrorKind::PluginNotFound,
+            PluginLoadError::RustcVersionMismatch { .. } => PluginLoadErrorKind::RustcVersionMismatch,
+            PluginLoadError::ApiVersionMismatch { .. } => PluginLoadErrorKind::ApiVersionMismatch,
+            PluginLoadError::UnmetDependencies(_) => PluginLoadErrorKind::UnmetDependencies,
+            PluginLoadError::InitFailed { .. } => PluginLoadErrorKind::InitFailed,
+            PluginLoadError::PostInitFailed { .. } => PluginLoadErrorKind::PostInitFailed,
+        }
+    }
+}
+
+impl fmt::Display for PluginLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            PluginLoadError::MissingFile { path } => write!(f, "The library {} doesn't exist", path),
+            PluginLoadError::LibraryError { path, message } => write!(f, "Failed to load the library {}: {}", path, message),
+            PluginLoadError::MissingDeclaration { path } => write!(f, "The library {} doesn't export the symbol plugin_declaration", path),
+            PluginLoadError::PluginNotFound { path } => write!(f, "The library {} doesn't contain the plugin", path),
+            PluginLoadError::RustcVersionMismatch { path, version, expected } => {
+                write!(f, "Compiler version mismatch of {}: rustc {} (expected: {})", path, version, expected)
+            }
+            PluginLoadError::ApiVersionMismatch { path, version, expected } => {
+                write!(f, "API version mismatch of {}: inexor_rgf_core_plugins {} (expected: {})", path, version, expected)
+            }
+            PluginLoadError::UnmetDependencies(error) => write!(f, "{}", error),
+            PluginLoadError::InitFailed { message } => write!(f, "Failed to initialize the plugin: {}", message),
+            PluginLoadError::PostInitFailed { message } => write!(f, "Failed to post-initialize the plugin: {}", message),
+        }
+    }
+}
@@ -1,20 +1,45 @@
 pub mod component_manager_impl;
 pub mod config;
 pub mod context;
+pub mod contributions;
+pub mod dependencies;
 pub mod entity_instance_manager_impl;
 pub mod entity_type_manager_impl;
 pub mod flow_manager_impl;
+#[cfg(feature = "plugin-api-next")]
+pub mod graph_query_manager_impl;
+#[cfg(feature = "plugin-api-next")]
+pub mod graphql_query_executor_impl;
+pub mod load_error;
 pub mod proxy;
 pub mod registrar;
 pub mod relation_instance_manager_impl;
 pub mod relation_type_manager_impl;
+pub mod status;
+#[cfg(feature = "plugin-api-next")]
+pub mod system_event_manager_impl;
 
 pub use component_manager_impl::ComponentManagerImpl;
+pub use config::PluginConfig;
+pub use config::PluginDeployConfig;
 pub use config::PluginsConfig;
 pub use context::PluginContextImpl;
+pub use contributions::PluginContributions;
+pub use dependencies::resolve_load_order;
+pub use dependencies::PluginDependencyError;
 pub use entity_instance_manager_impl::EntityInstanceManagerImpl;
 pub use entity_type_manager_impl::EntityTypeManagerImpl;
 pub use flow_manager_impl::FlowManagerImpl;
+#[cfg(feature = "plugin-api-next")]
+pub use graph_query_manager_impl::GraphQueryManagerImpl;
+#[cfg(feature = "plugin-api-next")]
+pub use graphql_query_executor_impl::GraphQLQueryExecutorImpl;
+pub use load_error::PluginLoadError;
+pub use load_error::PluginLoadErrorKind;
 pub use proxy::PluginProxy;
 pub use relation_instance_manager_impl::RelationInstanceManagerImpl;
 pub use relation_type_manager_impl::RelationTypeManagerImpl;
+pub use status::PluginState;
+pub use status::PluginStatus;
+#[cfg(feature = "plugin-api-next")]
+pub use system_event_manager_impl::SystemEventManagerImpl;
@@ -1,4 +1,4 @@
-use crate::api::{ReactiveRelationInstanceManager, RelationTypeManager};
+use crate::api::{with_default_values, with_event_source, ReactiveRelationInstanceManager, RelationTypeManager, SystemEventSource};
 use crate::model::{ReactiveRelationInstance, RelationInstance};
 use crate::plugins::relation_instance_manager::{RelationInstanceCreationError, RelationInstanceManager};
 use indradb::EdgeKey;
@@ -54,12 +54,10 @@ impl RelationInstanceManager for RelationInstanceManagerImpl {
                     }
                 }
                 let mut relation_instance = relation_instance;
-                for property in relation_type.properties.iter() {
-                    if !relation_instance.properties.contains_key(&property.name) {
-                        relation_instance.properties.insert(property.name.clone(), property.data_type.default_value());
-                    }
-                }
-                let reactive_relation_instance = self.reactive_relation_instance_manager.create_reactive_instance(relation_instance);
+                relation_instance.properties = with_default_values(&relation_type.properties, relation_instance.properties);
+                let reactive_relation_instance = with_event_source(SystemEventSource::Plugin, || {
+                    self.reactive_relation_instance_manager.create_reactive_instance(relation_instance)
+                });
                 match reactive_relation_instance {
                     Ok(reactive_relation_instance) => Ok(reactive_relation_instance),
                     Err(_) => Err(RelationInstanceCreationError::Failed),
@@ -70,14 +68,16 @@ impl RelationInstanceManager for RelationInstanceManagerImpl {
     }
 
     fn add_component(&self, edge_key: EdgeKey, component_name: String) {
-        self.reactive_relation_instance_manager.add_component(edge_key, component_name);
+        with_event_source(SystemEventSource::Plugin, || self.reactive_relation_instance_manager.add_component(edge_key, component_name));
     }
 
     fn remove_component(&self, edge_key: EdgeKey, component_name: String) {
-        self.reactive_relation_instance_manager.remove_component(edge_key, component_name);
+        with_event_source(SystemEventSource::Plugin, || {
+            self.reactive_relation_instance_manager.remove_component(edge_key, component_name)
+        });
     }
 
     fn delete(&self, edge_key: EdgeKey) -> bool {
-        self.reactive_relation_instance_manager.delete(edge_key)
+        with_event_source(SystemEventSource::Plugin, || self.reactive_relation_instance_manager.delete(edge_key))
     }
 }
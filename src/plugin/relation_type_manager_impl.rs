@@ -1,3 +1,4 @@
+use crate::api::{with_event_source, SystemEventSource};
 use crate::model::{Extension, PropertyType, RelationType};
 use crate::plugins::relation_type_manager::RelationTypeCreationError;
 use crate::plugins::RelationTypeManager;
@@ -46,16 +47,18 @@ impl RelationTypeManager for RelationTypeManagerImpl {
         properties: Vec<PropertyType>,
         extensions: Vec<Extension>,
     ) {
-        self.relation_type_manager
-            .create(outbound_type, type_name, inbound_type, components, properties, extensions)
+        with_event_source(SystemEventSource::Plugin, || {
+            self.relation_type_manager
+                .create(outbound_type, type_name, inbound_type, components, properties, extensions)
+        })
     }
 
     fn delete(&self, type_name: String) {
-        self.relation_type_manager.delete(type_name)
+        with_event_source(SystemEventSource::Plugin, || self.relation_type_manager.delete(type_name))
     }
 
     fn import(&self, path: String) {
-        let _result = self.relation_type_manager.import(path);
+        let _result = with_event_source(SystemEventSource::Plugin, || self.relation_type_manager.import(path));
     }
 
     fn export(&self, type_name: String, path: String) {
@@ -0,0 +1,54 @@
+use strum_macros::Display;
+
+use crate::plugin::PluginLoadError;
+
+/// The state of a plugin.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Display)]
+pub enum PluginState {
+    /// The library has been loaded but the plugin hasn't been initialized yet.
+    Loaded,
+
+    /// The plugin has been initialized and its providers have been registered.
+    Initialized,
+
+    /// The plugin failed to load or to initialize.
+    Failed,
+
+    /// The plugin is not active or has been deactivated.
+    Deactivated,
+}
+
+/// The status of a configured or deployed plugin.
+#[derive(Debug, Clone)]
+pub struct PluginStatus {
+    /// The name of the plugin.
+    pub name: String,
+
+    /// The path of the library which contains the plugin.
+    pub path: String,
+
+    pub state: PluginState,
+
+    /// The reason why the plugin failed to load or to initialize.
+    pub error: Option<PluginLoadError>,
+}
+
+impl PluginStatus {
+    pub fn new(name: String, path: String, state: PluginState) -> Self {
+        PluginStatus {
+            name,
+            path,
+            state,
+            error: None,
+        }
+    }
+
+    pub fn failed(name: String, path: String, error: PluginLoadError) -> Self {
+        PluginStatus {
+            name,
+            path,
+            state: PluginState::Failed,
+            error: Some(error),
+        }
+    }
+}
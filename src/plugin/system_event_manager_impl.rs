@@ -0,0 +1,28 @@
+use crate::model::ReactiveEntityInstance;
+use crate::plugins::SystemEventManager;
+use std::sync::Arc;
+
+pub struct SystemEventManagerImpl {
+    system_event_manager: Arc<dyn crate::api::SystemEventManager>,
+}
+
+impl SystemEventManagerImpl {
+    pub fn new(system_event_manager: Arc<dyn crate::api::SystemEventManager>) -> Self {
+        Self { system_event_manager }
+    }
+}
+impl SystemEventManager for SystemEventManagerImpl {
+    fn get_system_event_instances(&self) -> Vec<Arc<ReactiveEntityInstance>> {
+        self.system_event_manager.get_system_event_instances()
+    }
+
+    fn get_system_event_instance(&self, label: String) -> Option<Arc<ReactiveEntityInstance>> {
+        self.system_event_manager.get_system_event_instances().into_iter().find(|event_instance| {
+            event_instance
+                .properties
+                .get("label")
+                .and_then(|property_instance| property_instance.as_string())
+                == Some(label.clone())
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use std::future::ready;
+use std::future::Ready;
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::error::ErrorUnauthorized;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse};
+use http::header::AUTHORIZATION;
+
+use crate::api::get_bearer_token;
+use crate::api::AccessDenied;
+use crate::api::AuthManager;
+use crate::api::Identity;
+
+/// Returns the bearer token of the `Authorization` header of the given request.
+pub fn get_credentials(request: &HttpRequest) -> Option<String> {
+    request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .map(get_bearer_token)
+}
+
+/// Extracts the identity of the caller of a REST endpoint or the GraphQL endpoint. Responds
+/// with 401 Unauthorized if the caller cannot be authenticated.
+impl FromRequest for Identity {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let auth_manager = match request.app_data::<web::Data<Arc<dyn AuthManager>>>() {
+            Some(auth_manager) => auth_manager,
+            None => return ready(Err(ErrorInternalServerError("Missing auth manager"))),
+        };
+        ready(auth_manager.authenticate(get_credentials(request)).map_err(ErrorUnauthorized))
+    }
+}
+
+/// Responds with 403 Forbidden.
+pub fn forbidden(access_denied: AccessDenied) -> HttpResponse {
+    HttpResponse::Forbidden().body(access_denied.to_string())
+}
@@ -0,0 +1,146 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use actix_web::web::Bytes;
+use actix_web::{get, web, HttpResponse};
+use futures_util::stream::select_all;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use log::debug;
+use serde_json::Value;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+use uuid::Uuid;
+
+use crate::api::entity_instance_resources;
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::SystemEventManager;
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::EntityPropertyInstanceStream;
+use crate::model::PropertyInstanceGetter;
+use crate::rest::auth::forbidden;
+
+pub const TEXT_EVENT_STREAM: &str = "text/event-stream";
+
+/// Idle connections receive a comment line in this interval. Otherwise a closed connection
+/// would never be detected and the subscription of the property would never be removed.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Formats a server-sent event. The event name is optional.
+pub fn to_server_sent_event(event: Option<&str>, data: &Value) -> Bytes {
+    match event {
+        Some(event) => Bytes::from(format!("event: {}\ndata: {}\n\n", event, data)),
+        None => Bytes::from(format!("data: {}\n\n", data)),
+    }
+}
+
+fn keep_alive() -> Bytes {
+    Bytes::from_static(b": keep-alive\n\n")
+}
+
+/// The body of a server-sent events response. Sends a comment line if no event has been sent
+/// within the keep alive interval.
+///
+/// The body owns the stream of the subscribed properties. The body is dropped as soon as the
+/// client disconnects, which drops the property streams and thereby removes their observers
+/// from the properties.
+pub struct ServerSentEventStream<S: Stream<Item = Bytes> + Unpin> {
+    events: S,
+    keep_alive: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S: Stream<Item = Bytes> + Unpin> ServerSentEventStream<S> {
+    pub fn new(events: S) -> Self {
+        ServerSentEventStream { events, keep_alive: None }
+    }
+}
+
+impl<S: Stream<Item = Bytes> + Unpin> Stream for ServerSentEventStream<S> {
+    type Item = Result<Bytes, actix_web::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let deadline = Instant::now() + KEEP_ALIVE_INTERVAL;
+        if let Poll::Ready(event) = self.events.poll_next_unpin(context) {
+            if let Some(timer) = self.keep_alive.as_mut() {
+                timer.as_mut().reset(deadline);
+            }
+            return Poll::Ready(event.map(Ok));
+        }
+        // The timer is created on the first poll, because it requires the runtime
+        let timer = self.keep_alive.get_or_insert_with(|| Box::pin(tokio::time::sleep(KEEP_ALIVE_INTERVAL)));
+        match timer.as_mut().poll(context) {
+            Poll::Ready(()) => {
+                timer.as_mut().reset(deadline);
+                Poll::Ready(Some(Ok(keep_alive())))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<S: Stream<Item = Bytes> + Unpin> Drop for ServerSentEventStream<S> {
+    fn drop(&mut self) {
+        debug!("Closed server-sent event stream");
+    }
+}
+
+/// Streams the changes of the given property stream as server-sent events.
+pub fn entity_property_events(stream: EntityPropertyInstanceStream) -> ServerSentEventStream<impl Stream<Item = Bytes> + Unpin> {
+    ServerSentEventStream::new(stream.map(|value| to_server_sent_event(None, &value)))
+}
+
+/// Streams the changes of the property of the entity instance with the given id.
+#[get("/events/entities/{id}/{property}")]
+pub async fn get_entity_property_events(
+    identity: Identity,
+    path: web::Path<(Uuid, String)>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    subscription_config: web::Data<GraphQLSubscriptionConfig>,
+) -> HttpResponse {
+    let (id, property_name) = path.into_inner();
+    let entity_instance = match entity_instance_manager.get(id) {
+        Some(entity_instance) => entity_instance,
+        None => return HttpResponse::NotFound().body(format!("Entity instance {} not found", id)),
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &entity_instance_resources(&entity_instance)) {
+        return forbidden(access_denied);
+    }
+    if !entity_instance.properties.contains_key(&property_name) {
+        return HttpResponse::NotFound().body(format!("Entity instance {} has no property {}", id, property_name));
+    }
+    let stream = EntityPropertyInstanceStream::new(entity_instance, property_name, &subscription_config);
+    HttpResponse::Ok().content_type(TEXT_EVENT_STREAM).streaming(entity_property_events(stream))
+}
+
+/// Streams the system events. The name of the server-sent event is the label of the system
+/// event instance.
+#[get("/events/system")]
+pub async fn get_system_events(
+    identity: Identity,
+    system_event_manager: web::Data<Arc<dyn SystemEventManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    subscription_config: web::Data<GraphQLSubscriptionConfig>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let streams: Vec<_> = system_event_manager
+        .get_system_event_instances()
+        .into_iter()
+        .filter(|entity_instance| access_control_manager.is_permitted(&identity, Permission::InstancesRead, &entity_instance_resources(entity_instance)))
+        .map(|entity_instance| {
+            let label = entity_instance.as_string("label").unwrap_or_default();
+            EntityPropertyInstanceStream::new(entity_instance, String::from("event"), &subscription_config).map(move |value| (label.clone(), value))
+        })
+        .collect();
+    let events = select_all(streams).map(|(label, value)| to_server_sent_event(Some(label.as_str()), &value));
+    HttpResponse::Ok().content_type(TEXT_EVENT_STREAM).streaming(ServerSentEventStream::new(events))
+}
@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use mime::APPLICATION_JSON;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::entity_instance_resources;
+use crate::api::flow_instance_resources;
+use crate::api::flow_resources;
+use crate::api::get_resources;
+use crate::api::with_default_values;
+use crate::api::AccessControlManager;
+use crate::api::EntityTypeManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::api::PropertyValidator;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveFlowManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::api::RelationTypeManager;
+use crate::model::EntityInstance;
+use crate::model::Flow;
+use crate::model::ReactiveFlow;
+use crate::model::RelationInstance;
+use crate::rest::auth::forbidden;
+use crate::rest::instances::entities::EntityInstanceCreation;
+use crate::rest::instances::relations::get_relation_instance_by_key;
+use crate::rest::instances::relations::RelationInstanceCreation;
+use crate::rest::instances::relations::RelationInstanceKey;
+
+#[derive(Deserialize)]
+pub struct FlowQuery {
+    /// Filters by the (entity-) type of the flow.
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+
+    /// Filters by the label of the flow.
+    pub label: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct FlowCreation {
+    /// The entity type of the wrapper entity instance.
+    #[serde(rename = "type")]
+    pub type_name: String,
+
+    /// The id of the flow. If none is given a random uuid will be generated.
+    pub id: Option<Uuid>,
+
+    /// The initial values of the properties of the wrapper entity instance.
+    #[serde(default = "HashMap::new")]
+    pub properties: HashMap<String, Value>,
+}
+
+/// Responds with the given flow as JSON.
+fn flow_response(flow: Arc<ReactiveFlow>) -> HttpResponse {
+    match Flow::try_from(flow) {
+        Ok(flow) => HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(flow),
+        Err(_) => HttpResponse::InternalServerError().body("Failed to export flow"),
+    }
+}
+
+/// Returns the flow with the given id if the identity is permitted to access it.
+fn get_permitted_flow(
+    identity: &Identity,
+    id: Uuid,
+    permission: Permission,
+    flow_manager: &Arc<dyn ReactiveFlowManager>,
+    access_control_manager: &Arc<dyn AccessControlManager>,
+) -> Result<Arc<ReactiveFlow>, HttpResponse> {
+    let flow = flow_manager
+        .get(id)
+        .ok_or_else(|| HttpResponse::NotFound().body(format!("Flow {} not found", id)))?;
+    access_control_manager.check(identity, permission, &flow_resources(&flow)).map_err(forbidden)?;
+    Ok(flow)
+}
+
+#[get("/flows")]
+pub async fn get_flows(
+    identity: Identity,
+    query: web::Query<FlowQuery>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::FlowsRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let query = query.into_inner();
+    let flows = match query.label {
+        Some(label) => flow_manager.get_by_label(label).into_iter().collect(),
+        None => flow_manager.get_all(),
+    };
+    let flows: Vec<Flow> = flows
+        .into_iter()
+        .filter(|flow| query.type_name.is_none() || query.type_name.clone().unwrap() == flow.type_name)
+        .filter(|flow| access_control_manager.is_permitted(&identity, Permission::FlowsRead, &flow_resources(flow)))
+        .filter_map(|flow| Flow::try_from(flow).ok())
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(flows)
+}
+
+#[get("/flows/{id}")]
+pub async fn get_flow(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsRead, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow_response(flow),
+        Err(response) => response,
+    }
+}
+
+#[post("/flows")]
+pub async fn create_flow(
+    identity: Identity,
+    creation: web::Json<FlowCreation>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let creation = creation.into_inner();
+    let resources = get_resources(&creation.type_name, creation.properties.get("label"));
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::FlowsWrite, &resources) {
+        return forbidden(access_denied);
+    }
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &resources) {
+        return forbidden(access_denied);
+    }
+    let entity_type = match entity_type_manager.get(creation.type_name.clone()) {
+        Some(entity_type) => entity_type,
+        None => return HttpResponse::BadRequest().body(format!("Entity type {} does not exist", creation.type_name)),
+    };
+    if let Some(id) = creation.id {
+        if flow_manager.has(id) || entity_instance_manager.has(id) {
+            return HttpResponse::Conflict().body(format!("Flow {} already exists", id));
+        }
+    }
+    let properties = with_default_values(&entity_type.properties, creation.properties);
+    let wrapper_entity_instance = match creation.id {
+        Some(id) => entity_instance_manager.create_with_id(creation.type_name, id, properties),
+        None => entity_instance_manager.create(creation.type_name, properties),
+    };
+    match wrapper_entity_instance {
+        Ok(wrapper_entity_instance) => {
+            let flow: Arc<ReactiveFlow> = Arc::new(wrapper_entity_instance.into());
+            flow_manager.register_flow(flow.clone());
+            flow_response(flow)
+        }
+        Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+    }
+}
+
+#[post("/flows/import")]
+pub async fn import_flow(
+    identity: Identity,
+    flow: web::Json<Flow>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let flow = flow.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::FlowsWrite, &[flow.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &flow_instance_resources(&flow)) {
+        return forbidden(access_denied);
+    }
+    match flow_manager.create(flow) {
+        Ok(flow) => flow_response(flow),
+        Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+    }
+}
+
+#[post("/flows/{id}/commit")]
+pub async fn commit_flow(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => {
+            flow_manager.commit(flow.id);
+            flow_response(flow)
+        }
+        Err(response) => response,
+    }
+}
+
+#[delete("/flows/{id}")]
+pub async fn delete_flow(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => {
+            flow_manager.delete(flow.id);
+            HttpResponse::NoContent().finish()
+        }
+        Err(response) => response,
+    }
+}
+
+#[get("/flows/{id}/entities")]
+pub async fn get_flow_entity_instances(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsRead, &flow_manager, &access_control_manager) {
+        Ok(flow) => {
+            let entity_instances: Vec<EntityInstance> = flow.entity_instances.read().unwrap().values().cloned().map(EntityInstance::from).collect();
+            HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(entity_instances)
+        }
+        Err(response) => response,
+    }
+}
+
+#[post("/flows/{id}/entities")]
+pub async fn create_flow_entity_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    creation: web::Json<EntityInstanceCreation>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let flow = match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    let creation = creation.into_inner();
+    let resources = get_resources(&creation.type_name, creation.properties.get("label"));
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &resources) {
+        return forbidden(access_denied);
+    }
+    match crate::rest::instances::entities::create(
+        creation,
+        entity_type_manager.get_ref().clone(),
+        entity_instance_manager.get_ref().clone(),
+        property_validator.get_ref().clone(),
+    ) {
+        Ok(entity_instance) => {
+            flow.add_entity(entity_instance);
+            flow_response(flow)
+        }
+        Err(response) => response,
+    }
+}
+
+#[put("/flows/{id}/entities/{entity_id}")]
+pub async fn add_flow_entity_instance(
+    identity: Identity,
+    path: web::Path<(Uuid, Uuid)>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let (id, entity_id) = path.into_inner();
+    let flow = match get_permitted_flow(&identity, id, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    match entity_instance_manager.get(entity_id) {
+        Some(entity_instance) => {
+            if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &entity_instance_resources(&entity_instance)) {
+                return forbidden(access_denied);
+            }
+            flow.add_entity(entity_instance);
+            flow_response(flow)
+        }
+        None => HttpResponse::NotFound().body(format!("Entity instance {} not found", entity_id)),
+    }
+}
+
+#[delete("/flows/{id}/entities/{entity_id}")]
+pub async fn remove_flow_entity_instance(
+    identity: Identity,
+    path: web::Path<(Uuid, Uuid)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let (id, entity_id) = path.into_inner();
+    let flow = match get_permitted_flow(&identity, id, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    if !flow.has_entity_by_id(entity_id) {
+        return HttpResponse::NotFound().body(format!("Flow {} doesn't contain entity instance {}", id, entity_id));
+    }
+    // The entity instance is removed from the flow but not deleted
+    flow.remove_entity(entity_id);
+    flow_response(flow)
+}
+
+#[get("/flows/{id}/relations")]
+pub async fn get_flow_relation_instances(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match get_permitted_flow(&identity, id.into_inner().0, Permission::FlowsRead, &flow_manager, &access_control_manager) {
+        Ok(flow) => {
+            let relation_instances: Vec<RelationInstance> = flow.relation_instances.read().unwrap().values().cloned().map(RelationInstance::from).collect();
+            HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(relation_instances)
+        }
+        Err(response) => response,
+    }
+}
+
+#[post("/flows/{id}/relations")]
+pub async fn create_flow_relation_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    creation: web::Json<RelationInstanceCreation>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let id = id.into_inner().0;
+    let flow = match get_permitted_flow(&identity, id, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    let creation = creation.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[creation.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if !flow.has_entity_by_id(creation.outbound_id) {
+        return HttpResponse::BadRequest().body(format!("Flow {} doesn't contain the outbound entity instance {}", id, creation.outbound_id));
+    }
+    if !flow.has_entity_by_id(creation.inbound_id) {
+        return HttpResponse::BadRequest().body(format!("Flow {} doesn't contain the inbound entity instance {}", id, creation.inbound_id));
+    }
+    match crate::rest::instances::relations::create(
+        creation,
+        relation_type_manager.get_ref().clone(),
+        entity_instance_manager.get_ref().clone(),
+        relation_instance_manager.get_ref().clone(),
+        property_validator.get_ref().clone(),
+    ) {
+        Ok(relation_instance) => {
+            flow.add_relation(relation_instance);
+            flow_response(flow)
+        }
+        Err(response) => response,
+    }
+}
+
+#[derive(Deserialize)]
+pub struct FlowRelationInstancePath {
+    pub id: Uuid,
+    pub outbound_id: Uuid,
+    pub type_name: String,
+    pub inbound_id: Uuid,
+}
+
+impl FlowRelationInstancePath {
+    fn key(&self) -> RelationInstanceKey {
+        RelationInstanceKey {
+            outbound_id: self.outbound_id,
+            type_name: self.type_name.clone(),
+            inbound_id: self.inbound_id,
+        }
+    }
+}
+
+#[put("/flows/{id}/relations/{outbound_id}/{type_name}/{inbound_id}")]
+pub async fn add_flow_relation_instance(
+    identity: Identity,
+    path: web::Path<FlowRelationInstancePath>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let flow = match get_permitted_flow(&identity, path.id, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[path.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    match get_relation_instance_by_key(&path.key(), relation_instance_manager.get_ref()) {
+        Ok(relation_instance) => {
+            flow.add_relation(relation_instance);
+            flow_response(flow)
+        }
+        Err(response) => response,
+    }
+}
+
+#[delete("/flows/{id}/relations/{outbound_id}/{type_name}/{inbound_id}")]
+pub async fn remove_flow_relation_instance(
+    identity: Identity,
+    path: web::Path<FlowRelationInstancePath>,
+    flow_manager: web::Data<Arc<dyn ReactiveFlowManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let flow = match get_permitted_flow(&identity, path.id, Permission::FlowsWrite, &flow_manager, &access_control_manager) {
+        Ok(flow) => flow,
+        Err(response) => return response,
+    };
+    let edge_key = match path.key().to_edge_key() {
+        Ok(edge_key) => edge_key,
+        Err(response) => return response,
+    };
+    if !flow.has_relation_by_key(edge_key.clone()) {
+        return HttpResponse::NotFound().body(format!("Flow {} doesn't contain the relation instance", path.id));
+    }
+    // The relation instance is removed from the flow but not deleted
+    flow.remove_relation(edge_key);
+    flow_response(flow)
+}
@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use mime::APPLICATION_JSON;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::entity_instance_resources;
+use crate::api::get_resources;
+use crate::api::updated_entity_instance_resources;
+use crate::api::with_default_values;
+use crate::api::AccessControlManager;
+use crate::api::EntityTypeManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::api::PropertyValidator;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::model::EntityInstance;
+use crate::model::PropertyInstanceSetter;
+use crate::model::ReactiveEntityInstance;
+use crate::rest::auth::forbidden;
+use crate::rest::instances::absolute_label;
+
+#[derive(Deserialize)]
+pub struct EntityInstanceQuery {
+    /// Filters by the entity type.
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+
+    /// Filters by the label.
+    pub label: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct EntityInstanceCreation {
+    /// The entity type.
+    #[serde(rename = "type")]
+    pub type_name: String,
+
+    /// The id of the entity instance. If none is given a random uuid will be generated.
+    pub id: Option<Uuid>,
+
+    /// The components which are added to the entity instance.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<String>,
+
+    /// The initial values of the properties. Missing properties are initialized with the
+    /// default value of the data type.
+    #[serde(default = "HashMap::new")]
+    pub properties: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+pub struct EntityInstanceUpdate {
+    #[serde(default = "Vec::new")]
+    pub add_components: Vec<String>,
+
+    #[serde(default = "Vec::new")]
+    pub remove_components: Vec<String>,
+
+    #[serde(default = "HashMap::new")]
+    pub properties: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+pub struct EntityInstanceDeletion {
+    /// If true, all relations to and from the entity instance will be deleted as well.
+    #[serde(default)]
+    pub delete_relations: bool,
+}
+
+#[get("/instances/entities")]
+pub async fn get_entity_instances(
+    identity: Identity,
+    query: web::Query<EntityInstanceQuery>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let query = query.into_inner();
+    let entity_instances = match query.label {
+        Some(label) => entity_instance_manager.get_by_label(label).into_iter().collect(),
+        None => entity_instance_manager.get_entity_instances(),
+    };
+    let entity_instances: Vec<EntityInstance> = entity_instances
+        .into_iter()
+        .filter(|entity_instance| query.type_name.is_none() || query.type_name.clone().unwrap() == entity_instance.type_name)
+        .filter(|entity_instance| access_control_manager.is_permitted(&identity, Permission::InstancesRead, &entity_instance_resources(entity_instance)))
+        .map(EntityInstance::from)
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(entity_instances)
+}
+
+#[get("/instances/entities/{id}")]
+pub async fn get_entity_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let id = id.into_inner().0;
+    respond_entity_instance(identity, entity_instance_manager.get(id), id.to_string(), access_control_manager)
+}
+
+#[get("/instances/entities/label/{label:.*}")]
+pub async fn get_entity_instance_by_label(
+    identity: Identity,
+    label: web::Path<(String,)>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let label = absolute_label(label.into_inner().0);
+    respond_entity_instance(identity, entity_instance_manager.get_by_label(label.clone()), label, access_control_manager)
+}
+
+fn respond_entity_instance(
+    identity: Identity,
+    entity_instance: Option<Arc<ReactiveEntityInstance>>,
+    key: String,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    match entity_instance {
+        Some(entity_instance) => {
+            if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &entity_instance_resources(&entity_instance)) {
+                return forbidden(access_denied);
+            }
+            HttpResponse::Ok()
+                .content_type(APPLICATION_JSON.to_string())
+                .json(EntityInstance::from(entity_instance))
+        }
+        None => HttpResponse::NotFound().body(format!("Entity instance {} not found", key)),
+    }
+}
+
+#[post("/instances/entities")]
+pub async fn create_entity_instance(
+    identity: Identity,
+    creation: web::Json<EntityInstanceCreation>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let creation = creation.into_inner();
+    let resources = get_resources(&creation.type_name, creation.properties.get("label"));
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &resources) {
+        return forbidden(access_denied);
+    }
+    match create(
+        creation,
+        entity_type_manager.get_ref().clone(),
+        entity_instance_manager.get_ref().clone(),
+        property_validator.get_ref().clone(),
+    ) {
+        Ok(entity_instance) => HttpResponse::Created()
+            .content_type(APPLICATION_JSON.to_string())
+            .json(EntityInstance::from(entity_instance)),
+        Err(response) => response,
+    }
+}
+
+/// Creates an entity instance. Also used for creating entity instances in flows.
+pub fn create(
+    creation: EntityInstanceCreation,
+    entity_type_manager: Arc<dyn EntityTypeManager>,
+    entity_instance_manager: Arc<dyn ReactiveEntityInstanceManager>,
+    property_validator: Arc<dyn PropertyValidator>,
+) -> Result<Arc<ReactiveEntityInstance>, HttpResponse> {
+    let entity_type = match entity_type_manager.get(creation.type_name.clone()) {
+        Some(entity_type) => entity_type,
+        None => return Err(HttpResponse::BadRequest().body(format!("Entity type {} does not exist", creation.type_name))),
+    };
+    let properties = with_default_values(&entity_type.properties, creation.properties);
+    if let Err(e) = property_validator.validate_new_entity_instance(creation.type_name.clone(), &creation.components, &properties) {
+        return Err(HttpResponse::BadRequest().body(e.to_string()));
+    }
+    let entity_instance = match creation.id {
+        Some(id) => entity_instance_manager.create_with_id(creation.type_name, id, properties),
+        None => entity_instance_manager.create(creation.type_name, properties),
+    };
+    match entity_instance {
+        Ok(entity_instance) => {
+            for component in creation.components {
+                entity_instance_manager.add_component(entity_instance.id, component);
+            }
+            Ok(entity_instance)
+        }
+        Err(e) => Err(HttpResponse::BadRequest().body(e.to_string())),
+    }
+}
+
+#[put("/instances/entities/{id}")]
+pub async fn update_entity_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    update: web::Json<EntityInstanceUpdate>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let id = id.into_inner().0;
+    let entity_instance = entity_instance_manager.get(id);
+    update(
+        identity,
+        entity_instance,
+        id.to_string(),
+        update.into_inner(),
+        entity_instance_manager,
+        access_control_manager,
+        property_validator,
+    )
+}
+
+#[put("/instances/entities/label/{label:.*}")]
+pub async fn update_entity_instance_by_label(
+    identity: Identity,
+    label: web::Path<(String,)>,
+    update: web::Json<EntityInstanceUpdate>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let label = absolute_label(label.into_inner().0);
+    let entity_instance = entity_instance_manager.get_by_label(label.clone());
+    update(
+        identity,
+        entity_instance,
+        label,
+        update.into_inner(),
+        entity_instance_manager,
+        access_control_manager,
+        property_validator,
+    )
+}
+
+fn update(
+    identity: Identity,
+    entity_instance: Option<Arc<ReactiveEntityInstance>>,
+    key: String,
+    update: EntityInstanceUpdate,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let entity_instance = match entity_instance {
+        Some(entity_instance) => entity_instance,
+        None => return HttpResponse::NotFound().body(format!("Entity instance {} not found", key)),
+    };
+    let resources = updated_entity_instance_resources(&entity_instance, &update.properties);
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &resources) {
+        return forbidden(access_denied);
+    }
+    if let Err(e) = property_validator.validate_entity_instance(&entity_instance, &update.add_components, &update.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    for component in update.add_components {
+        entity_instance_manager.add_component(entity_instance.id, component);
+    }
+    for component in update.remove_components {
+        entity_instance_manager.remove_component(entity_instance.id, component);
+    }
+    for (property_name, value) in update.properties {
+        entity_instance.set_no_propagate(property_name, value);
+    }
+    entity_instance.tick();
+    HttpResponse::Ok()
+        .content_type(APPLICATION_JSON.to_string())
+        .json(EntityInstance::from(entity_instance))
+}
+
+#[post("/instances/entities/{id}/tick")]
+pub async fn tick_entity_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let id = id.into_inner().0;
+    let entity_instance = match entity_instance_manager.get(id) {
+        Some(entity_instance) => entity_instance,
+        None => return HttpResponse::NotFound().body(format!("Entity instance {} not found", id)),
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &entity_instance_resources(&entity_instance)) {
+        return forbidden(access_denied);
+    }
+    entity_instance.tick();
+    HttpResponse::Ok()
+        .content_type(APPLICATION_JSON.to_string())
+        .json(EntityInstance::from(entity_instance))
+}
+
+#[delete("/instances/entities/{id}")]
+pub async fn delete_entity_instance(
+    identity: Identity,
+    id: web::Path<(Uuid,)>,
+    query: web::Query<EntityInstanceDeletion>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let id = id.into_inner().0;
+    let entity_instance = entity_instance_manager.get(id);
+    delete(
+        identity,
+        entity_instance,
+        id.to_string(),
+        query.delete_relations,
+        entity_instance_manager,
+        relation_instance_manager,
+        access_control_manager,
+    )
+}
+
+#[delete("/instances/entities/label/{label:.*}")]
+pub async fn delete_entity_instance_by_label(
+    identity: Identity,
+    label: web::Path<(String,)>,
+    query: web::Query<EntityInstanceDeletion>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let label = absolute_label(label.into_inner().0);
+    let entity_instance = entity_instance_manager.get_by_label(label.clone());
+    delete(
+        identity,
+        entity_instance,
+        label,
+        query.delete_relations,
+        entity_instance_manager,
+        relation_instance_manager,
+        access_control_manager,
+    )
+}
+
+fn delete(
+    identity: Identity,
+    entity_instance: Option<Arc<ReactiveEntityInstance>>,
+    key: String,
+    delete_relations: bool,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let entity_instance = match entity_instance {
+        Some(entity_instance) => entity_instance,
+        None => return HttpResponse::NotFound().body(format!("Entity instance {} not found", key)),
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &entity_instance_resources(&entity_instance)) {
+        return forbidden(access_denied);
+    }
+    if delete_relations {
+        let relation_instances = relation_instance_manager
+            .get_by_inbound_entity(entity_instance.id)
+            .into_iter()
+            .chain(relation_instance_manager.get_by_outbound_entity(entity_instance.id));
+        for relation_instance in relation_instances {
+            if let Some(edge_key) = relation_instance.get_key() {
+                relation_instance_manager.delete(edge_key);
+            }
+        }
+    }
+    entity_instance_manager.delete(entity_instance.id);
+    HttpResponse::NoContent().finish()
+}
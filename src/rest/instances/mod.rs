@@ -1 +1,12 @@
+pub mod entities;
+pub mod relations;
 
+/// Returns the given label as absolute path. Labels are part of the URL path without the
+/// leading slash.
+pub fn absolute_label(label: String) -> String {
+    if label.starts_with('/') {
+        label
+    } else {
+        format!("/{}", label)
+    }
+}
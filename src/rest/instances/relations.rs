@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use indradb::EdgeKey;
+use indradb::Identifier;
+use mime::APPLICATION_JSON;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::with_default_values;
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::api::PropertyValidator;
+use crate::api::ReactiveEntityInstanceManager;
+use crate::api::ReactiveRelationInstanceManager;
+use crate::api::RelationTypeManager;
+use crate::model::PropertyInstanceSetter;
+use crate::model::ReactiveRelationInstance;
+use crate::model::RelationInstance;
+use crate::rest::auth::forbidden;
+
+#[derive(Deserialize)]
+pub struct RelationInstanceQuery {
+    /// Filters by the relation type. Matches all relation types starting with the given name.
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+
+    /// Filters by the id of the outbound entity instance.
+    pub outbound_id: Option<Uuid>,
+
+    /// Filters by the id of the inbound entity instance.
+    pub inbound_id: Option<Uuid>,
+}
+
+/// The edge key of a relation instance as path parameters.
+#[derive(Deserialize)]
+pub struct RelationInstanceKey {
+    pub outbound_id: Uuid,
+    pub type_name: String,
+    pub inbound_id: Uuid,
+}
+
+impl RelationInstanceKey {
+    pub fn to_edge_key(&self) -> Result<EdgeKey, HttpResponse> {
+        match Identifier::new(self.type_name.clone()) {
+            Ok(t) => Ok(EdgeKey::new(self.outbound_id, t, self.inbound_id)),
+            Err(e) => Err(HttpResponse::BadRequest().body(format!("Invalid relation type {}: {}", self.type_name, e))),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RelationInstanceCreation {
+    pub outbound_id: Uuid,
+
+    /// The relation type. The relation type is matched by a prefix search.
+    #[serde(rename = "type")]
+    pub type_name: String,
+
+    pub inbound_id: Uuid,
+
+    /// The components which are added to the relation instance.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<String>,
+
+    /// The initial values of the properties. Missing properties are initialized with the
+    /// default value of the data type.
+    #[serde(default = "HashMap::new")]
+    pub properties: HashMap<String, Value>,
+}
+
+#[derive(Deserialize)]
+pub struct RelationInstanceUpdate {
+    #[serde(default = "Vec::new")]
+    pub add_components: Vec<String>,
+
+    #[serde(default = "Vec::new")]
+    pub remove_components: Vec<String>,
+
+    #[serde(default = "HashMap::new")]
+    pub properties: HashMap<String, Value>,
+}
+
+#[get("/instances/relations")]
+pub async fn get_relation_instances(
+    identity: Identity,
+    query: web::Query<RelationInstanceQuery>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let relation_instances: Vec<RelationInstance> = relation_instance_manager
+        .get_relation_instances()
+        .into_iter()
+        .filter(|relation_instance| match &query.type_name {
+            Some(type_name) => relation_instance.type_name.starts_with(type_name),
+            None => true,
+        })
+        .filter(|relation_instance| query.outbound_id.is_none() || query.outbound_id.unwrap() == relation_instance.outbound.id)
+        .filter(|relation_instance| query.inbound_id.is_none() || query.inbound_id.unwrap() == relation_instance.inbound.id)
+        .filter(|relation_instance| access_control_manager.is_permitted(&identity, Permission::InstancesRead, &[relation_instance.type_name.clone()]))
+        .map(RelationInstance::from)
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(relation_instances)
+}
+
+#[get("/instances/relations/{outbound_id}/{type_name}/{inbound_id}")]
+pub async fn get_relation_instance(
+    identity: Identity,
+    key: web::Path<RelationInstanceKey>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let relation_instance = match get_relation_instance_by_key(&key, relation_instance_manager.get_ref()) {
+        Ok(relation_instance) => relation_instance,
+        Err(response) => return response,
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesRead, &[relation_instance.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    HttpResponse::Ok()
+        .content_type(APPLICATION_JSON.to_string())
+        .json(RelationInstance::from(relation_instance))
+}
+
+/// Returns the relation instance with the given edge key or responds with 404 Not Found.
+pub fn get_relation_instance_by_key(
+    key: &RelationInstanceKey,
+    relation_instance_manager: &Arc<dyn ReactiveRelationInstanceManager>,
+) -> Result<Arc<ReactiveRelationInstance>, HttpResponse> {
+    let edge_key = key.to_edge_key()?;
+    relation_instance_manager
+        .get(edge_key)
+        .ok_or_else(|| HttpResponse::NotFound().body(format!("Relation instance {}--{}--{} not found", key.outbound_id, key.type_name, key.inbound_id)))
+}
+
+#[post("/instances/relations")]
+pub async fn create_relation_instance(
+    identity: Identity,
+    creation: web::Json<RelationInstanceCreation>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    entity_instance_manager: web::Data<Arc<dyn ReactiveEntityInstanceManager>>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let creation = creation.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[creation.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    match create(
+        creation,
+        relation_type_manager.get_ref().clone(),
+        entity_instance_manager.get_ref().clone(),
+        relation_instance_manager.get_ref().clone(),
+        property_validator.get_ref().clone(),
+    ) {
+        Ok(relation_instance) => HttpResponse::Created()
+            .content_type(APPLICATION_JSON.to_string())
+            .json(RelationInstance::from(relation_instance)),
+        Err(response) => response,
+    }
+}
+
+/// Creates a relation instance. Also used for creating relation instances in flows.
+pub fn create(
+    creation: RelationInstanceCreation,
+    relation_type_manager: Arc<dyn RelationTypeManager>,
+    entity_instance_manager: Arc<dyn ReactiveEntityInstanceManager>,
+    relation_instance_manager: Arc<dyn ReactiveRelationInstanceManager>,
+    property_validator: Arc<dyn PropertyValidator>,
+) -> Result<Arc<ReactiveRelationInstance>, HttpResponse> {
+    let relation_type = match relation_type_manager.get_starts_with(creation.type_name.clone()) {
+        Some(relation_type) => relation_type,
+        None => return Err(HttpResponse::BadRequest().body(format!("Relation type {} does not exist", creation.type_name))),
+    };
+    if !entity_instance_manager.has(creation.outbound_id) {
+        return Err(HttpResponse::BadRequest().body(format!("Outbound entity {} does not exist", creation.outbound_id)));
+    }
+    if !entity_instance_manager.has(creation.inbound_id) {
+        return Err(HttpResponse::BadRequest().body(format!("Inbound entity {} does not exist", creation.inbound_id)));
+    }
+    let key = RelationInstanceKey {
+        outbound_id: creation.outbound_id,
+        type_name: creation.type_name,
+        inbound_id: creation.inbound_id,
+    };
+    let edge_key = key.to_edge_key()?;
+    let properties = with_default_values(&relation_type.properties, creation.properties);
+    if let Err(e) = property_validator.validate_new_relation_instance(edge_key.t.to_string(), &creation.components, &properties) {
+        return Err(HttpResponse::BadRequest().body(e.to_string()));
+    }
+    match relation_instance_manager.create(edge_key.clone(), properties) {
+        Ok(relation_instance) => {
+            for component in creation.components {
+                relation_instance_manager.add_component(edge_key.clone(), component);
+            }
+            Ok(relation_instance)
+        }
+        Err(e) => Err(HttpResponse::BadRequest().body(e.to_string())),
+    }
+}
+
+#[put("/instances/relations/{outbound_id}/{type_name}/{inbound_id}")]
+pub async fn update_relation_instance(
+    identity: Identity,
+    key: web::Path<RelationInstanceKey>,
+    update: web::Json<RelationInstanceUpdate>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+    property_validator: web::Data<Arc<dyn PropertyValidator>>,
+) -> HttpResponse {
+    let relation_instance = match get_relation_instance_by_key(&key, relation_instance_manager.get_ref()) {
+        Ok(relation_instance) => relation_instance,
+        Err(response) => return response,
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[relation_instance.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    let update = update.into_inner();
+    let edge_key = match relation_instance.get_key() {
+        Some(edge_key) => edge_key,
+        None => return HttpResponse::InternalServerError().body("Invalid edge key"),
+    };
+    if let Err(e) = property_validator.validate_relation_instance(&relation_instance, &update.add_components, &update.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    for component in update.add_components {
+        relation_instance_manager.add_component(edge_key.clone(), component);
+    }
+    for component in update.remove_components {
+        relation_instance_manager.remove_component(edge_key.clone(), component);
+    }
+    for (property_name, value) in update.properties {
+        relation_instance.set_no_propagate(property_name, value);
+    }
+    relation_instance.tick();
+    HttpResponse::Ok()
+        .content_type(APPLICATION_JSON.to_string())
+        .json(RelationInstance::from(relation_instance))
+}
+
+#[post("/instances/relations/{outbound_id}/{type_name}/{inbound_id}/tick")]
+pub async fn tick_relation_instance(
+    identity: Identity,
+    key: web::Path<RelationInstanceKey>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let relation_instance = match get_relation_instance_by_key(&key, relation_instance_manager.get_ref()) {
+        Ok(relation_instance) => relation_instance,
+        Err(response) => return response,
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[relation_instance.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    relation_instance.tick();
+    HttpResponse::Ok()
+        .content_type(APPLICATION_JSON.to_string())
+        .json(RelationInstance::from(relation_instance))
+}
+
+#[delete("/instances/relations/{outbound_id}/{type_name}/{inbound_id}")]
+pub async fn delete_relation_instance(
+    identity: Identity,
+    key: web::Path<RelationInstanceKey>,
+    relation_instance_manager: web::Data<Arc<dyn ReactiveRelationInstanceManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let relation_instance = match get_relation_instance_by_key(&key, relation_instance_manager.get_ref()) {
+        Ok(relation_instance) => relation_instance,
+        Err(response) => return response,
+    };
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::InstancesWrite, &[relation_instance.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    match relation_instance.get_key() {
+        Some(edge_key) => {
+            relation_instance_manager.delete(edge_key);
+            HttpResponse::NoContent().finish()
+        }
+        None => HttpResponse::InternalServerError().body("Invalid edge key"),
+    }
+}
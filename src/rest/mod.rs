@@ -1,2 +1,63 @@
+use actix_web::web::ServiceConfig;
+
+pub mod auth;
+pub mod events;
+pub mod flows;
 pub mod instances;
+pub mod openapi;
 pub mod types;
+
+/// Registers the services of the REST API and the server-sent events. Every service has to be
+/// described by a route of the OpenAPI document (see [`openapi::get_rest_routes`]).
+pub fn configure_rest_services(config: &mut ServiceConfig) {
+    config
+        // REST API
+        .service(openapi::get_openapi)
+        .service(types::components::get_components)
+        .service(types::components::get_component)
+        .service(types::components::create_component)
+        .service(types::components::replace_component)
+        .service(types::components::delete_component)
+        .service(types::entities::get_entity_types)
+        .service(types::entities::get_entity_type)
+        .service(types::entities::create_entity_type)
+        .service(types::entities::replace_entity_type)
+        .service(types::entities::delete_entity_type)
+        .service(types::relations::get_relation_types)
+        .service(types::relations::get_relation_type)
+        .service(types::relations::create_relation_type)
+        .service(types::relations::replace_relation_type)
+        .service(types::relations::delete_relation_type)
+        .service(instances::entities::get_entity_instances)
+        .service(instances::entities::get_entity_instance_by_label)
+        .service(instances::entities::get_entity_instance)
+        .service(instances::entities::create_entity_instance)
+        .service(instances::entities::update_entity_instance_by_label)
+        .service(instances::entities::update_entity_instance)
+        .service(instances::entities::tick_entity_instance)
+        .service(instances::entities::delete_entity_instance_by_label)
+        .service(instances::entities::delete_entity_instance)
+        .service(instances::relations::get_relation_instances)
+        .service(instances::relations::get_relation_instance)
+        .service(instances::relations::create_relation_instance)
+        .service(instances::relations::update_relation_instance)
+        .service(instances::relations::tick_relation_instance)
+        .service(instances::relations::delete_relation_instance)
+        .service(flows::get_flows)
+        .service(flows::get_flow)
+        .service(flows::create_flow)
+        .service(flows::import_flow)
+        .service(flows::commit_flow)
+        .service(flows::delete_flow)
+        .service(flows::get_flow_entity_instances)
+        .service(flows::create_flow_entity_instance)
+        .service(flows::add_flow_entity_instance)
+        .service(flows::remove_flow_entity_instance)
+        .service(flows::get_flow_relation_instances)
+        .service(flows::create_flow_relation_instance)
+        .service(flows::add_flow_relation_instance)
+        .service(flows::remove_flow_relation_instance)
+        // Server-Sent Events
+        .service(events::get_entity_property_events)
+        .service(events::get_system_events);
+}
@@ -0,0 +1,841 @@
+use actix_web::{get, HttpResponse};
+use mime::APPLICATION_JSON;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::api::Permission;
+use crate::rest::events::TEXT_EVENT_STREAM;
+
+/// The successful response of a REST route.
+pub enum RestResponse {
+    /// Responds with a single object of the given schema.
+    Object(&'static str),
+
+    /// Responds with a newly created object of the given schema (201).
+    Created(&'static str),
+
+    /// Responds with a list of objects of the given schema.
+    Array(&'static str),
+
+    /// Responds without a body (204).
+    NoContent,
+
+    /// Responds with a stream of server-sent events.
+    EventStream,
+}
+
+/// A route of the REST API as described in the OpenAPI document.
+pub struct RestRoute {
+    pub method: &'static str,
+    pub path: &'static str,
+    pub operation_id: &'static str,
+    pub tag: &'static str,
+    pub summary: &'static str,
+    pub permission: Permission,
+    /// The query parameters as tuples of name, schema type and description.
+    pub query: &'static [(&'static str, &'static str, &'static str)],
+    /// The schema of the request body.
+    pub request: Option<&'static str>,
+    pub response: RestResponse,
+}
+
+const TYPE_QUERY: (&str, &str, &str) = ("type", "string", "Filters by the type name.");
+const LABEL_QUERY: (&str, &str, &str) = ("label", "string", "Filters by the label.");
+
+/// Returns all routes of the REST API.
+pub fn get_rest_routes() -> Vec<RestRoute> {
+    use Permission::*;
+    use RestResponse::*;
+    let route = |method, path, operation_id, tag, summary, permission, request, response| RestRoute {
+        method,
+        path,
+        operation_id,
+        tag,
+        summary,
+        permission,
+        query: &[],
+        request,
+        response,
+    };
+    vec![
+        // Types
+        route(
+            "get",
+            "/types/components",
+            "getComponents",
+            "Types",
+            "Returns all components",
+            TypesRead,
+            None,
+            Array("Component"),
+        ),
+        route(
+            "get",
+            "/types/components/{name}",
+            "getComponent",
+            "Types",
+            "Returns the component with the given name",
+            TypesRead,
+            None,
+            Object("Component"),
+        ),
+        route(
+            "post",
+            "/types/components",
+            "createComponent",
+            "Types",
+            "Creates a component",
+            TypesWrite,
+            Some("Component"),
+            Created("Component"),
+        ),
+        route(
+            "put",
+            "/types/components/{name}",
+            "replaceComponent",
+            "Types",
+            "Replaces the component with the given name",
+            TypesWrite,
+            Some("Component"),
+            Object("Component"),
+        ),
+        route(
+            "delete",
+            "/types/components/{name}",
+            "deleteComponent",
+            "Types",
+            "Deletes the component with the given name",
+            TypesWrite,
+            None,
+            NoContent,
+        ),
+        route(
+            "get",
+            "/types/entities",
+            "getEntityTypes",
+            "Types",
+            "Returns all entity types",
+            TypesRead,
+            None,
+            Array("EntityType"),
+        ),
+        route(
+            "get",
+            "/types/entities/{name}",
+            "getEntityType",
+            "Types",
+            "Returns the entity type with the given name",
+            TypesRead,
+            None,
+            Object("EntityType"),
+        ),
+        route(
+            "post",
+            "/types/entities",
+            "createEntityType",
+            "Types",
+            "Creates an entity type",
+            TypesWrite,
+            Some("EntityType"),
+            Created("EntityType"),
+        ),
+        route(
+            "put",
+            "/types/entities/{name}",
+            "replaceEntityType",
+            "Types",
+            "Replaces the entity type with the given name",
+            TypesWrite,
+            Some("EntityType"),
+            Object("EntityType"),
+        ),
+        route(
+            "delete",
+            "/types/entities/{name}",
+            "deleteEntityType",
+            "Types",
+            "Deletes the entity type with the given name",
+            TypesWrite,
+            None,
+            NoContent,
+        ),
+        route(
+            "get",
+            "/types/relations",
+            "getRelationTypes",
+            "Types",
+            "Returns all relation types",
+            TypesRead,
+            None,
+            Array("RelationType"),
+        ),
+        route(
+            "get",
+            "/types/relations/{name}",
+            "getRelationType",
+            "Types",
+            "Returns the relation type with the given name",
+            TypesRead,
+            None,
+            Object("RelationType"),
+        ),
+        route(
+            "post",
+            "/types/relations",
+            "createRelationType",
+            "Types",
+            "Creates a relation type",
+            TypesWrite,
+            Some("RelationType"),
+            Created("RelationType"),
+        ),
+        route(
+            "put",
+            "/types/relations/{name}",
+            "replaceRelationType",
+            "Types",
+            "Replaces the relation type with the given name",
+            TypesWrite,
+            Some("RelationType"),
+            Object("RelationType"),
+        ),
+        route(
+            "delete",
+            "/types/relations/{name}",
+            "deleteRelationType",
+            "Types",
+            "Deletes the relation type with the given name",
+            TypesWrite,
+            None,
+            NoContent,
+        ),
+        // Entity Instances
+        RestRoute {
+            query: &[TYPE_QUERY, LABEL_QUERY],
+            ..route(
+                "get",
+                "/instances/entities",
+                "getEntityInstances",
+                "Entity Instances",
+                "Returns the entity instances",
+                InstancesRead,
+                None,
+                Array("EntityInstance"),
+            )
+        },
+        route(
+            "get",
+            "/instances/entities/{id}",
+            "getEntityInstance",
+            "Entity Instances",
+            "Returns the entity instance with the given id",
+            InstancesRead,
+            None,
+            Object("EntityInstance"),
+        ),
+        route(
+            "get",
+            "/instances/entities/label/{label:.*}",
+            "getEntityInstanceByLabel",
+            "Entity Instances",
+            "Returns the entity instance with the given label",
+            InstancesRead,
+            None,
+            Object("EntityInstance"),
+        ),
+        route(
+            "post",
+            "/instances/entities",
+            "createEntityInstance",
+            "Entity Instances",
+            "Creates an entity instance",
+            InstancesWrite,
+            Some("EntityInstanceCreation"),
+            Created("EntityInstance"),
+        ),
+        route(
+            "put",
+            "/instances/entities/{id}",
+            "updateEntityInstance",
+            "Entity Instances",
+            "Updates the entity instance with the given id",
+            InstancesWrite,
+            Some("EntityInstanceUpdate"),
+            Object("EntityInstance"),
+        ),
+        route(
+            "put",
+            "/instances/entities/label/{label:.*}",
+            "updateEntityInstanceByLabel",
+            "Entity Instances",
+            "Updates the entity instance with the given label",
+            InstancesWrite,
+            Some("EntityInstanceUpdate"),
+            Object("EntityInstance"),
+        ),
+        route(
+            "post",
+            "/instances/entities/{id}/tick",
+            "tickEntityInstance",
+            "Entity Instances",
+            "Ticks the entity instance with the given id",
+            InstancesWrite,
+            None,
+            Object("EntityInstance"),
+        ),
+        RestRoute {
+            query: &[(
+                "delete_relations",
+                "boolean",
+                "If true, the relations from and to the entity instance will be deleted as well.",
+            )],
+            ..route(
+                "delete",
+                "/instances/entities/{id}",
+                "deleteEntityInstance",
+                "Entity Instances",
+                "Deletes the entity instance with the given id",
+                InstancesWrite,
+                None,
+                NoContent,
+            )
+        },
+        RestRoute {
+            query: &[(
+                "delete_relations",
+                "boolean",
+                "If true, the relations from and to the entity instance will be deleted as well.",
+            )],
+            ..route(
+                "delete",
+                "/instances/entities/label/{label:.*}",
+                "deleteEntityInstanceByLabel",
+                "Entity Instances",
+                "Deletes the entity instance with the given label",
+                InstancesWrite,
+                None,
+                NoContent,
+            )
+        },
+        // Relation Instances
+        RestRoute {
+            query: &[
+                TYPE_QUERY,
+                ("outbound_id", "uuid", "Filters by the id of the outbound entity instance."),
+                ("inbound_id", "uuid", "Filters by the id of the inbound entity instance."),
+            ],
+            ..route(
+                "get",
+                "/instances/relations",
+                "getRelationInstances",
+                "Relation Instances",
+                "Returns the relation instances",
+                InstancesRead,
+                None,
+                Array("RelationInstance"),
+            )
+        },
+        route(
+            "get",
+            "/instances/relations/{outbound_id}/{type_name}/{inbound_id}",
+            "getRelationInstance",
+            "Relation Instances",
+            "Returns the relation instance with the given edge key",
+            InstancesRead,
+            None,
+            Object("RelationInstance"),
+        ),
+        route(
+            "post",
+            "/instances/relations",
+            "createRelationInstance",
+            "Relation Instances",
+            "Creates a relation instance",
+            InstancesWrite,
+            Some("RelationInstanceCreation"),
+            Created("RelationInstance"),
+        ),
+        route(
+            "put",
+            "/instances/relations/{outbound_id}/{type_name}/{inbound_id}",
+            "updateRelationInstance",
+            "Relation Instances",
+            "Updates the relation instance with the given edge key",
+            InstancesWrite,
+            Some("RelationInstanceUpdate"),
+            Object("RelationInstance"),
+        ),
+        route(
+            "post",
+            "/instances/relations/{outbound_id}/{type_name}/{inbound_id}/tick",
+            "tickRelationInstance",
+            "Relation Instances",
+            "Ticks the relation instance with the given edge key",
+            InstancesWrite,
+            None,
+            Object("RelationInstance"),
+        ),
+        route(
+            "delete",
+            "/instances/relations/{outbound_id}/{type_name}/{inbound_id}",
+            "deleteRelationInstance",
+            "Relation Instances",
+            "Deletes the relation instance with the given edge key",
+            InstancesWrite,
+            None,
+            NoContent,
+        ),
+        // Flows
+        RestRoute {
+            query: &[TYPE_QUERY, LABEL_QUERY],
+            ..route("get", "/flows", "getFlows", "Flows", "Returns the flows", FlowsRead, None, Array("Flow"))
+        },
+        route(
+            "get",
+            "/flows/{id}",
+            "getFlow",
+            "Flows",
+            "Returns the flow with the given id",
+            FlowsRead,
+            None,
+            Object("Flow"),
+        ),
+        route(
+            "post",
+            "/flows",
+            "createFlow",
+            "Flows",
+            "Creates a flow and its wrapper entity instance",
+            FlowsWrite,
+            Some("FlowCreation"),
+            Object("Flow"),
+        ),
+        route(
+            "post",
+            "/flows/import",
+            "importFlow",
+            "Flows",
+            "Imports a flow with its entity instances and relation instances",
+            FlowsWrite,
+            Some("Flow"),
+            Object("Flow"),
+        ),
+        route(
+            "post",
+            "/flows/{id}/commit",
+            "commitFlow",
+            "Flows",
+            "Ticks all instances of the flow",
+            FlowsWrite,
+            None,
+            Object("Flow"),
+        ),
+        route(
+            "delete",
+            "/flows/{id}",
+            "deleteFlow",
+            "Flows",
+            "Deletes the flow with the given id",
+            FlowsWrite,
+            None,
+            NoContent,
+        ),
+        route(
+            "get",
+            "/flows/{id}/entities",
+            "getFlowEntityInstances",
+            "Flows",
+            "Returns the entity instances of the flow",
+            FlowsRead,
+            None,
+            Array("EntityInstance"),
+        ),
+        route(
+            "post",
+            "/flows/{id}/entities",
+            "createFlowEntityInstance",
+            "Flows",
+            "Creates an entity instance and adds it to the flow",
+            FlowsWrite,
+            Some("EntityInstanceCreation"),
+            Object("Flow"),
+        ),
+        route(
+            "put",
+            "/flows/{id}/entities/{entity_id}",
+            "addFlowEntityInstance",
+            "Flows",
+            "Adds an existing entity instance to the flow",
+            FlowsWrite,
+            None,
+            Object("Flow"),
+        ),
+        route(
+            "delete",
+            "/flows/{id}/entities/{entity_id}",
+            "removeFlowEntityInstance",
+            "Flows",
+            "Removes an entity instance from the flow without deleting it",
+            FlowsWrite,
+            None,
+            Object("Flow"),
+        ),
+        route(
+            "get",
+            "/flows/{id}/relations",
+            "getFlowRelationInstances",
+            "Flows",
+            "Returns the relation instances of the flow",
+            FlowsRead,
+            None,
+            Array("RelationInstance"),
+        ),
+        route(
+            "post",
+            "/flows/{id}/relations",
+            "createFlowRelationInstance",
+            "Flows",
+            "Creates a relation instance and adds it to the flow",
+            FlowsWrite,
+            Some("RelationInstanceCreation"),
+            Object("Flow"),
+        ),
+        route(
+            "put",
+            "/flows/{id}/relations/{outbound_id}/{type_name}/{inbound_id}",
+            "addFlowRelationInstance",
+            "Flows",
+            "Adds an existing relation instance to the flow",
+            FlowsWrite,
+            None,
+            Object("Flow"),
+        ),
+        route(
+            "delete",
+            "/flows/{id}/relations/{outbound_id}/{type_name}/{inbound_id}",
+            "removeFlowRelationInstance",
+            "Flows",
+            "Removes a relation instance from the flow without deleting it",
+            FlowsWrite,
+            None,
+            Object("Flow"),
+        ),
+        // Server-Sent Events
+        route(
+            "get",
+            "/events/entities/{id}/{property}",
+            "getEntityPropertyEvents",
+            "Events",
+            "Streams the changes of the property of the entity instance",
+            InstancesRead,
+            None,
+            EventStream,
+        ),
+        route(
+            "get",
+            "/events/system",
+            "getSystemEvents",
+            "Events",
+            "Streams the system events",
+            InstancesRead,
+            None,
+            EventStream,
+        ),
+    ]
+}
+
+/// Returns the names of the path parameters of the given actix path. Regular expressions
+/// like in `{label:.*}` are stripped.
+pub fn get_path_parameters(path: &str) -> Vec<String> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split('}').next())
+        .map(|parameter| parameter.split(':').next().unwrap_or(parameter).to_string())
+        .collect()
+}
+
+/// Converts the actix path into an OpenAPI path template.
+pub fn to_openapi_path(path: &str) -> String {
+    get_path_parameters(path).iter().fold(path.to_string(), |path, parameter| {
+        let start = path.find(&format!("{{{}", parameter)).unwrap_or(0);
+        let end = path[start..].find('}').map(|end| start + end + 1).unwrap_or(path.len());
+        format!("{}{{{}}}{}", &path[..start], parameter, &path[end..])
+    })
+}
+
+fn parameter_schema(schema_type: &str) -> Value {
+    match schema_type {
+        "uuid" => json!({ "type": "string", "format": "uuid" }),
+        _ => json!({ "type": schema_type }),
+    }
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn json_content(schema: Value) -> Value {
+    json!({ "application/json": { "schema": schema } })
+}
+
+fn error_response(description: &str) -> Value {
+    json!({ "description": description, "content": { "text/plain": { "schema": { "type": "string" } } } })
+}
+
+fn to_operation(route: &RestRoute) -> Value {
+    let mut parameters: Vec<Value> = get_path_parameters(route.path)
+        .into_iter()
+        .map(|name| {
+            let schema_type = if name == "id" || name.ends_with("_id") { "uuid" } else { "string" };
+            json!({ "name": name, "in": "path", "required": true, "schema": parameter_schema(schema_type) })
+        })
+        .collect();
+    parameters.extend(route.query.iter().map(|(name, schema_type, description)| {
+        json!({ "name": name, "in": "query", "required": false, "description": description, "schema": parameter_schema(schema_type) })
+    }));
+
+    let mut responses = Map::new();
+    match route.response {
+        RestResponse::Object(schema) => {
+            responses.insert("200".to_string(), json!({ "description": "OK", "content": json_content(schema_ref(schema)) }));
+        }
+        RestResponse::Created(schema) => {
+            responses.insert("201".to_string(), json!({ "description": "Created", "content": json_content(schema_ref(schema)) }));
+        }
+        RestResponse::Array(schema) => {
+            responses.insert(
+                "200".to_string(),
+                json!({ "description": "OK", "content": json_content(json!({ "type": "array", "items": schema_ref(schema) })) }),
+            );
+        }
+        RestResponse::NoContent => {
+            responses.insert("204".to_string(), json!({ "description": "No Content" }));
+        }
+        RestResponse::EventStream => {
+            responses.insert(
+                "200".to_string(),
+                json!({ "description": "Stream of server-sent events", "content": { TEXT_EVENT_STREAM: { "schema": { "type": "string" } } } }),
+            );
+        }
+    }
+    if route.request.is_some() || route.path.contains("{type_name}") {
+        responses.insert("400".to_string(), error_response("Bad Request"));
+    }
+    responses.insert("401".to_string(), error_response("Missing or invalid credentials"));
+    responses.insert("403".to_string(), error_response(&format!("Permission {} is not granted", route.permission)));
+    if route.path.contains('{') {
+        responses.insert("404".to_string(), error_response("Not Found"));
+    }
+    if matches!(route.response, RestResponse::Created(_)) {
+        responses.insert("409".to_string(), error_response("Conflict"));
+    }
+
+    let mut operation = json!({
+        "operationId": route.operation_id,
+        "tags": [route.tag],
+        "summary": route.summary,
+        "description": format!("Requires the permission `{}`.", route.permission),
+        "parameters": parameters,
+        "responses": responses,
+    });
+    if let Some(request) = route.request {
+        operation["requestBody"] = json!({ "required": true, "content": json_content(schema_ref(request)) });
+    }
+    operation
+}
+
+/// Returns the JSON schemas of the model types and of the request bodies of the REST API.
+pub fn get_schemas() -> Value {
+    let properties = json!({
+        "type": "object",
+        "description": "The property values by property name.",
+        "additionalProperties": true,
+    });
+    let components = json!({ "type": "array", "items": { "type": "string" }, "description": "The names of the components." });
+    let uuid = json!({ "type": "string", "format": "uuid" });
+    json!({
+        "DataType": {
+            "type": "string",
+            "enum": ["null", "bool", "number", "string", "array", "object", "any"],
+        },
+        "SocketType": {
+            "type": "string",
+            "enum": ["none", "input", "output"],
+        },
+        "Extension": {
+            "type": "object",
+            "required": ["name", "extension"],
+            "properties": {
+                "name": { "type": "string" },
+                "extension": { "description": "Schema-less additional information." },
+            },
+        },
+        "PropertyType": {
+            "type": "object",
+            "required": ["name", "data_type"],
+            "properties": {
+                "name": { "type": "string" },
+                "description": { "type": "string" },
+                "data_type": schema_ref("DataType"),
+                "socket_type": schema_ref("SocketType"),
+                "extensions": { "type": "array", "items": schema_ref("Extension") },
+            },
+        },
+        "Component": {
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string" },
+                "description": { "type": "string" },
+                "properties": { "type": "array", "items": schema_ref("PropertyType") },
+            },
+        },
+        "EntityType": {
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string" },
+                "group": { "type": "string" },
+                "description": { "type": "string" },
+                "components": components,
+                "behaviours": { "type": "array", "items": { "type": "string" } },
+                "properties": { "type": "array", "items": schema_ref("PropertyType") },
+                "extensions": { "type": "array", "items": schema_ref("Extension") },
+            },
+        },
+        "RelationType": {
+            "type": "object",
+            "required": ["outbound_type", "type_name", "inbound_type"],
+            "properties": {
+                "outbound_type": { "type": "string" },
+                "type_name": { "type": "string" },
+                "inbound_type": { "type": "string" },
+                "group": { "type": "string" },
+                "description": { "type": "string" },
+                "components": components,
+                "behaviours": { "type": "array", "items": { "type": "string" } },
+                "properties": { "type": "array", "items": schema_ref("PropertyType") },
+                "extensions": { "type": "array", "items": schema_ref("Extension") },
+            },
+        },
+        "EntityInstance": {
+            "type": "object",
+            "required": ["type_name", "id"],
+            "properties": {
+                "type_name": { "type": "string" },
+                "id": uuid,
+                "description": { "type": "string" },
+                "properties": properties,
+            },
+        },
+        "RelationInstance": {
+            "type": "object",
+            "required": ["outbound_id", "type_name", "inbound_id"],
+            "properties": {
+                "outbound_id": uuid,
+                "type_name": { "type": "string" },
+                "inbound_id": uuid,
+                "description": { "type": "string" },
+                "properties": properties,
+            },
+        },
+        "Flow": {
+            "type": "object",
+            "required": ["id", "type_name"],
+            "properties": {
+                "id": uuid,
+                "type_name": { "type": "string" },
+                "name": { "type": "string" },
+                "description": { "type": "string" },
+                "entity_instances": { "type": "array", "items": schema_ref("EntityInstance") },
+                "relation_instances": { "type": "array", "items": schema_ref("RelationInstance") },
+            },
+        },
+        "EntityInstanceCreation": {
+            "type": "object",
+            "required": ["type"],
+            "properties": {
+                "type": { "type": "string" },
+                "id": uuid,
+                "components": components,
+                "properties": properties,
+            },
+        },
+        "EntityInstanceUpdate": {
+            "type": "object",
+            "properties": {
+                "add_components": components,
+                "remove_components": components,
+                "properties": properties,
+            },
+        },
+        "RelationInstanceCreation": {
+            "type": "object",
+            "required": ["outbound_id", "type", "inbound_id"],
+            "properties": {
+                "outbound_id": uuid,
+                "type": { "type": "string" },
+                "inbound_id": uuid,
+                "components": components,
+                "properties": properties,
+            },
+        },
+        "RelationInstanceUpdate": {
+            "type": "object",
+            "properties": {
+                "add_components": components,
+                "remove_components": components,
+                "properties": properties,
+            },
+        },
+        "FlowCreation": {
+            "type": "object",
+            "required": ["type"],
+            "properties": {
+                "type": { "type": "string" },
+                "id": uuid,
+                "properties": properties,
+            },
+        },
+    })
+}
+
+/// Returns the OpenAPI 3 document which describes the REST API.
+pub fn get_openapi_document() -> Value {
+    let mut paths = Map::new();
+    for route in get_rest_routes() {
+        let path_item = paths.entry(to_openapi_path(route.path)).or_insert_with(|| json!({}));
+        path_item[route.method] = to_operation(&route);
+    }
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Inexor Reactive Graph Flow",
+            "description": "REST API of the Inexor Reactive Graph Flow",
+            "version": env!("CARGO_PKG_VERSION"),
+            "license": { "name": "MIT" },
+        },
+        "tags": [
+            { "name": "Types" },
+            { "name": "Entity Instances" },
+            { "name": "Relation Instances" },
+            { "name": "Flows" },
+            { "name": "Events" },
+        ],
+        "paths": paths,
+        "components": {
+            "schemas": get_schemas(),
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer", "description": "API token or JSON Web Token" },
+            },
+        },
+        "security": [{ "bearerAuth": [] }],
+    })
+}
+
+/// Serves the OpenAPI document. The document itself doesn't require authentication.
+#[get("/openapi.json")]
+pub async fn get_openapi() -> HttpResponse {
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(get_openapi_document())
+}
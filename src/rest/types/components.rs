@@ -1,13 +1,114 @@
 use std::sync::Arc;
 
-use actix_web::{get, web, HttpResponse};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use mime::APPLICATION_JSON;
 
+use crate::api::compile_patterns;
+use crate::api::AccessControlManager;
 use crate::api::ComponentManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::model::Component;
+use crate::rest::auth::forbidden;
 
 #[get("/types/components")]
-pub async fn get_components(component_manager: web::Data<Arc<dyn ComponentManager>>) -> HttpResponse {
-    HttpResponse::Ok()
-        .content_type(APPLICATION_JSON.to_string())
-        .json(component_manager.get_components())
+pub async fn get_components(
+    identity: Identity,
+    component_manager: web::Data<Arc<dyn ComponentManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let components: Vec<Component> = component_manager
+        .get_components()
+        .into_iter()
+        .filter(|component| access_control_manager.is_permitted(&identity, Permission::TypesRead, &[component.name.clone()]))
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(components)
+}
+
+#[get("/types/components/{name}")]
+pub async fn get_component(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    component_manager: web::Data<Arc<dyn ComponentManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    match component_manager.get(name.clone()) {
+        Some(component) => HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(component),
+        None => HttpResponse::NotFound()
+            .content_type(APPLICATION_JSON.to_string())
+            .body(format!("Component {} not found", name)),
+    }
+}
+
+#[post("/types/components")]
+pub async fn create_component(
+    identity: Identity,
+    component: web::Json<Component>,
+    component_manager: web::Data<Arc<dyn ComponentManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let component = component.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[component.name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if let Err(e) = compile_patterns(&component.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    if component_manager.has(component.name.clone()) {
+        return HttpResponse::Conflict().body(format!("Component {} already exists", component.name));
+    }
+    component_manager.register(component.clone());
+    HttpResponse::Created().content_type(APPLICATION_JSON.to_string()).json(component)
+}
+
+/// Replaces the component with the given name.
+#[put("/types/components/{name}")]
+pub async fn replace_component(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    component: web::Json<Component>,
+    component_manager: web::Data<Arc<dyn ComponentManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    let component = component.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if name != component.name {
+        return HttpResponse::BadRequest().body(format!("The name {} doesn't match the name of the component {}", name, component.name));
+    }
+    if let Err(e) = compile_patterns(&component.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    if component_manager.has(name.clone()) {
+        component_manager.delete(name);
+    }
+    component_manager.register(component.clone());
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(component)
+}
+
+#[delete("/types/components/{name}")]
+pub async fn delete_component(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    component_manager: web::Data<Arc<dyn ComponentManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if !component_manager.has(name.clone()) {
+        return HttpResponse::NotFound().body(format!("Component {} not found", name));
+    }
+    component_manager.delete(name);
+    HttpResponse::NoContent().finish()
 }
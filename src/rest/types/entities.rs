@@ -1,20 +1,44 @@
 use std::sync::Arc;
 
-use actix_web::{get, web, HttpResponse};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use mime::APPLICATION_JSON;
 
+use crate::api::compile_patterns;
+use crate::api::AccessControlManager;
 use crate::api::EntityTypeManager;
+use crate::api::Identity;
+use crate::api::Permission;
+use crate::model::EntityType;
+use crate::rest::auth::forbidden;
 
 #[get("/types/entities")]
-pub async fn get_entity_types(entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>) -> HttpResponse {
-    HttpResponse::Ok()
-        .content_type(APPLICATION_JSON.to_string())
-        .json(entity_type_manager.get_entity_types())
+pub async fn get_entity_types(
+    identity: Identity,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let entity_types: Vec<EntityType> = entity_type_manager
+        .get_entity_types()
+        .into_iter()
+        .filter(|entity_type| access_control_manager.is_permitted(&identity, Permission::TypesRead, &[entity_type.name.clone()]))
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(entity_types)
 }
 
 #[get("/types/entities/{name}")]
-pub async fn get_entity_type(name: web::Path<(String,)>, entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>) -> HttpResponse {
+pub async fn get_entity_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
     let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
     let entity_type = entity_type_manager.get(name.clone());
     if entity_type.is_some() {
         HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(entity_type)
@@ -24,3 +48,66 @@ pub async fn get_entity_type(name: web::Path<(String,)>, entity_type_manager: we
             .body(format!("Entity Type {} not found", name))
     }
 }
+
+#[post("/types/entities")]
+pub async fn create_entity_type(
+    identity: Identity,
+    entity_type: web::Json<EntityType>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let entity_type = entity_type.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[entity_type.name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if let Err(e) = compile_patterns(&entity_type.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    if entity_type_manager.has(entity_type.name.clone()) {
+        return HttpResponse::Conflict().body(format!("Entity Type {} already exists", entity_type.name));
+    }
+    let entity_type = entity_type_manager.register(entity_type);
+    HttpResponse::Created().content_type(APPLICATION_JSON.to_string()).json(entity_type)
+}
+
+/// Replaces the entity type with the given name.
+#[put("/types/entities/{name}")]
+pub async fn replace_entity_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    entity_type: web::Json<EntityType>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    let entity_type = entity_type.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if name != entity_type.name {
+        return HttpResponse::BadRequest().body(format!("The name {} doesn't match the name of the entity type {}", name, entity_type.name));
+    }
+    if let Err(e) = compile_patterns(&entity_type.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    let entity_type = entity_type_manager.replace(entity_type);
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(entity_type)
+}
+
+#[delete("/types/entities/{name}")]
+pub async fn delete_entity_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    entity_type_manager: web::Data<Arc<dyn EntityTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if !entity_type_manager.has(name.clone()) {
+        return HttpResponse::NotFound().body(format!("Entity Type {} not found", name));
+    }
+    entity_type_manager.delete(name);
+    HttpResponse::NoContent().finish()
+}
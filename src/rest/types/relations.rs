@@ -1,20 +1,44 @@
 use std::sync::Arc;
 
-use actix_web::{get, web, HttpResponse};
+use actix_web::{delete, get, post, put, web, HttpResponse};
 use mime::APPLICATION_JSON;
 
+use crate::api::compile_patterns;
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Permission;
 use crate::api::RelationTypeManager;
+use crate::model::RelationType;
+use crate::rest::auth::forbidden;
 
 #[get("/types/relations")]
-pub async fn get_relation_types(relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>) -> HttpResponse {
-    HttpResponse::Ok()
-        .content_type(APPLICATION_JSON.to_string())
-        .json(relation_type_manager.get_relation_types())
+pub async fn get_relation_types(
+    identity: Identity,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[]) {
+        return forbidden(access_denied);
+    }
+    let relation_types: Vec<RelationType> = relation_type_manager
+        .get_relation_types()
+        .into_iter()
+        .filter(|relation_type| access_control_manager.is_permitted(&identity, Permission::TypesRead, &[relation_type.type_name.clone()]))
+        .collect();
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(relation_types)
 }
 
 #[get("/types/relations/{name}")]
-pub async fn get_relation_type(name: web::Path<(String,)>, relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>) -> HttpResponse {
+pub async fn get_relation_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
     let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesRead, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
     let relation_type = relation_type_manager.get(name.clone());
     if relation_type.is_some() {
         HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(relation_type)
@@ -24,3 +48,69 @@ pub async fn get_relation_type(name: web::Path<(String,)>, relation_type_manager
             .body(format!("Relation Type {} not found", name))
     }
 }
+
+#[post("/types/relations")]
+pub async fn create_relation_type(
+    identity: Identity,
+    relation_type: web::Json<RelationType>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let relation_type = relation_type.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[relation_type.type_name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if let Err(e) = compile_patterns(&relation_type.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    if relation_type_manager.has(relation_type.type_name.clone()) {
+        return HttpResponse::Conflict().body(format!("Relation Type {} already exists", relation_type.type_name));
+    }
+    relation_type_manager.register(relation_type.clone());
+    HttpResponse::Created().content_type(APPLICATION_JSON.to_string()).json(relation_type)
+}
+
+/// Replaces the relation type with the given name.
+#[put("/types/relations/{name}")]
+pub async fn replace_relation_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    relation_type: web::Json<RelationType>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    let relation_type = relation_type.into_inner();
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if name != relation_type.type_name {
+        return HttpResponse::BadRequest().body(format!("The name {} doesn't match the name of the relation type {}", name, relation_type.type_name));
+    }
+    if let Err(e) = compile_patterns(&relation_type.properties) {
+        return HttpResponse::BadRequest().body(e.to_string());
+    }
+    if relation_type_manager.has(name.clone()) {
+        relation_type_manager.delete(name);
+    }
+    relation_type_manager.register(relation_type.clone());
+    HttpResponse::Ok().content_type(APPLICATION_JSON.to_string()).json(relation_type)
+}
+
+#[delete("/types/relations/{name}")]
+pub async fn delete_relation_type(
+    identity: Identity,
+    name: web::Path<(String,)>,
+    relation_type_manager: web::Data<Arc<dyn RelationTypeManager>>,
+    access_control_manager: web::Data<Arc<dyn AccessControlManager>>,
+) -> HttpResponse {
+    let name = name.into_inner().0;
+    if let Err(access_denied) = access_control_manager.check(&identity, Permission::TypesWrite, &[name.clone()]) {
+        return forbidden(access_denied);
+    }
+    if !relation_type_manager.has(name.clone()) {
+        return HttpResponse::NotFound().body(format!("Relation Type {} not found", name));
+    }
+    relation_type_manager.delete(name);
+    HttpResponse::NoContent().finish()
+}
@@ -0,0 +1,104 @@
+use std::env;
+
+use async_graphql::Request;
+use futures::executor::block_on;
+
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::api::Permission;
+use crate::builder::EntityTypeBuilder;
+use crate::config::load_required_config;
+use crate::config::ConfigError;
+use crate::config::PolicyConfig;
+use crate::config::RoleConfig;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+fn operator() -> RoleConfig {
+    RoleConfig {
+        name: String::from("operator"),
+        permissions: vec![Permission::InstancesRead, Permission::InstancesWrite],
+        read: vec![String::from("*")],
+        write: vec![String::from("*"), String::from("!/org/inexor/system/*")],
+    }
+}
+
+#[test]
+fn test_role_permissions() {
+    let role = operator();
+    assert!(role.permits(Permission::InstancesRead, &[]));
+    assert!(role.permits(Permission::InstancesWrite, &[String::from("value"), String::from("/org/inexor/input/key")]));
+    assert!(role.permits(Permission::InstancesRead, &[String::from("shutdown"), String::from("/org/inexor/system/shutdown")]));
+    assert!(!role.permits(Permission::InstancesWrite, &[String::from("shutdown"), String::from("/org/inexor/system/shutdown")]));
+    assert!(!role.permits(Permission::TypesWrite, &[]));
+    assert!(!role.permits(Permission::FlowsWrite, &[String::from("value")]));
+}
+
+#[test]
+fn test_access_control_disabled() {
+    let application = init_application();
+    let access_control_manager = application.get_access_control_manager();
+    access_control_manager.init();
+    assert!(!access_control_manager.is_enabled());
+    assert!(access_control_manager.is_permitted(&Identity::anonymous(), Permission::TypesWrite, &[String::from("value")]));
+    assert!(access_control_manager.check(&Identity::anonymous(), Permission::SnapshotsWrite, &[]).is_ok());
+}
+
+#[test]
+fn test_queries_without_identity_are_denied() {
+    let application = init_application();
+    application.get_access_control_manager().init();
+    let type_name = r_string();
+    application
+        .get_entity_type_manager()
+        .register(EntityTypeBuilder::new(type_name.clone()).build());
+    let schema = application.get_graphql_query_service().get_schema();
+    let query = format!("{{ types {{ entities(name: \"{}\") {{ name }} }} }}", type_name);
+    let get_entity_types = |request: Request| {
+        let response = serde_json::to_value(block_on(schema.execute(request))).unwrap();
+        response["data"]["types"]["entities"]
+            .as_array()
+            .map(|entity_types| entity_types.len())
+            .unwrap_or(0)
+    };
+
+    // A request without identity is denied even if the access control is disabled
+    assert_eq!(0, get_entity_types(Request::new(query.clone())));
+    assert_eq!(1, get_entity_types(Request::new(query.clone()).data(Identity::anonymous())));
+    assert_eq!(1, get_entity_types(Request::new(query).data(Identity::system())));
+}
+
+#[test]
+fn test_system_identity() {
+    let system = Identity::system();
+    assert!(system.is_system());
+    // Authenticated identities are never the system identity, even if they have the same name
+    assert!(!Identity::new(system.name.clone(), Vec::new()).is_system());
+    assert!(!Identity::anonymous().is_system());
+}
+
+#[test]
+fn test_invalid_policy_config_is_an_error() {
+    let mut path = env::temp_dir();
+    path.push(format!("{}.toml", r_string()));
+    let path = path.into_os_string().into_string().unwrap();
+
+    let result: Result<PolicyConfig, ConfigError> = load_required_config(&path);
+    assert!(matches!(result, Err(ConfigError::NotFound(_))));
+
+    let policy_config: PolicyConfig = load_required_config("./config/policy.toml").unwrap();
+    assert!(!policy_config.role.is_empty());
+
+    // A typo must not drop the authorization rules
+    for toml_string in [
+        "enabled = true\n[[role]\n",
+        "enabled = true\ndefault_role = [ \"viewer\" ]\n",
+        "[[role]]\nname = \"admin\"\npermisions = [ \"*\" ]\n",
+    ] {
+        std::fs::write(&path, toml_string).unwrap();
+        let result: Result<PolicyConfig, ConfigError> = load_required_config(&path);
+        assert!(matches!(result, Err(ConfigError::InvalidToml(_, _))), "{} must be rejected", toml_string);
+    }
+
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,78 @@
+use std::env;
+
+use crate::api::get_bearer_token;
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::config::load_required_config;
+use crate::config::AuthConfig;
+use crate::config::ConfigError;
+use crate::config::JwtConfig;
+use crate::implementation::JwtVerifier;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+#[test]
+fn test_authentication_disabled() {
+    let application = init_application();
+    let auth_manager = application.get_auth_manager();
+    auth_manager.init();
+    assert!(!auth_manager.is_enabled());
+    assert_eq!(Identity::anonymous(), auth_manager.authenticate(None).unwrap());
+    assert_eq!(Identity::anonymous(), auth_manager.authenticate(Some(String::from("invalid"))).unwrap());
+}
+
+#[test]
+fn test_get_bearer_token() {
+    assert_eq!("abc", get_bearer_token("Bearer abc"));
+    assert_eq!("abc", get_bearer_token(" abc "));
+}
+
+#[test]
+fn test_invalid_auth_config_is_an_error() {
+    let mut path = env::temp_dir();
+    path.push(format!("{}.toml", r_string()));
+    let path = path.into_os_string().into_string().unwrap();
+
+    // The auth configuration is required
+    let result: Result<AuthConfig, ConfigError> = load_required_config(&path);
+    assert!(matches!(result, Err(ConfigError::NotFound(_))));
+
+    std::fs::write(&path, "enabled = true\n\n[[token]]\nname = \"admin\"\ntoken = \"secret\"\n").unwrap();
+    let auth_config: AuthConfig = load_required_config(&path).unwrap();
+    assert!(auth_config.enabled);
+
+    // A typo must not disable the authentication
+    for toml_string in [
+        "enabled = tru\n",
+        "enabeld = true\n",
+        "enabled = true\n\n[[token]]\nname = \"admin\"\ntokn = \"secret\"\n",
+    ] {
+        std::fs::write(&path, toml_string).unwrap();
+        let result: Result<AuthConfig, ConfigError> = load_required_config(&path);
+        assert!(matches!(result, Err(ConfigError::InvalidToml(_, _))), "{} must be rejected", toml_string);
+    }
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_invalid_jwt_config_is_an_error() {
+    let jwt_config = |algorithm: &str, secret: Option<&str>, public_key: Option<String>| JwtConfig {
+        algorithm: String::from(algorithm),
+        secret: secret.map(String::from),
+        public_key,
+        issuer: None,
+        audience: None,
+    };
+    assert!(JwtVerifier::new(&jwt_config("HS256", Some("secret"), None)).is_ok());
+    // Unknown algorithm
+    assert!(JwtVerifier::new(&jwt_config("HS257", Some("secret"), None)).is_err());
+    // Missing secret
+    assert!(JwtVerifier::new(&jwt_config("HS256", None, None)).is_err());
+    // Missing or unreadable public key
+    assert!(JwtVerifier::new(&jwt_config("RS256", None, None)).is_err());
+    let mut path = env::temp_dir();
+    path.push(format!("{}.pem", r_string()));
+    let path = path.into_os_string().into_string().unwrap();
+    assert!(JwtVerifier::new(&jwt_config("RS256", None, Some(path))).is_err());
+}
@@ -3,7 +3,6 @@ use uuid::Uuid;
 use crate::builder::{EntityInstanceBuilder, EntityTypeBuilder};
 use crate::tests::utils::application::init_application;
 use crate::tests::utils::{r_json_string, r_string};
-use indradb::Datastore;
 use std::env;
 
 #[test]
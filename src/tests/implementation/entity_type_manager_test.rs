@@ -1,5 +1,8 @@
 use std::env;
 
+use serde_json::json;
+
+use crate::api::SystemEventTypes;
 use crate::model::{DataType, EntityType, PropertyType};
 use crate::tests::utils::application::init_application;
 use crate::tests::utils::r_string;
@@ -120,6 +123,41 @@ fn test_register_entity_type_has_property() {
     assert!(entity_type.unwrap().has_own_property(property_name.clone()));
 }
 
+#[test]
+fn test_replace_entity_type() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let event_manager = application.get_event_manager();
+
+    let type_name = r_string();
+    let group_name = r_string();
+
+    entity_type_manager.register(EntityType::new(type_name.clone(), group_name.clone(), String::new(), vec![], vec![], vec![]));
+    let entity_type = entity_type_manager.replace(EntityType::new(
+        type_name.clone(),
+        group_name.clone(),
+        String::new(),
+        vec![],
+        vec![PropertyType::new(String::from("x"), DataType::String)],
+        vec![],
+    ));
+    assert!(entity_type.has_own_property(String::from("x")));
+    assert_eq!(
+        1,
+        entity_type_manager
+            .get_entity_types()
+            .iter()
+            .filter(|entity_type| entity_type.name == type_name)
+            .count()
+    );
+    assert!(entity_type_manager.get(type_name.clone()).unwrap().has_own_property(String::from("x")));
+
+    let events = event_manager.get_system_events(None, &[SystemEventTypes::EntityTypeUpdated]);
+    let event = events.iter().find(|record| record.subject == json!(type_name)).unwrap();
+    assert!(event.before.is_some());
+    assert!(event.after.is_some());
+}
+
 #[test]
 fn test_export_import_entity_type() {
     let application = init_application();
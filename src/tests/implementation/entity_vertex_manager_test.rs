@@ -1,7 +1,6 @@
 use crate::builder::EntityTypeBuilder;
 use crate::tests::utils::application::init_application;
 use crate::tests::utils::r_string;
-use indradb::Datastore;
 use serde_json::json;
 use std::collections::HashMap;
 use uuid::Uuid;
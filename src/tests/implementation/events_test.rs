@@ -0,0 +1,42 @@
+use actix_web::HttpResponse;
+use futures_util::StreamExt;
+use serde_json::json;
+
+use crate::builder::ReactiveEntityInstanceBuilder;
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::EntityPropertyInstanceStream;
+use crate::model::PropertyInstanceSetter;
+use crate::rest::events::entity_property_events;
+use crate::rest::events::to_server_sent_event;
+
+#[test]
+fn test_server_sent_event() {
+    assert_eq!("data: 42\n\n", to_server_sent_event(None, &json!(42)));
+    assert_eq!(
+        "event: /org/inexor/event/flow/created\ndata: \"b0c7e1d6-5c7f-4a9e-8c59-1f5a8a0e9e2d\"\n\n",
+        to_server_sent_event(Some("/org/inexor/event/flow/created"), &json!("b0c7e1d6-5c7f-4a9e-8c59-1f5a8a0e9e2d"))
+    );
+}
+
+#[test]
+fn test_server_sent_events_remove_observer_on_disconnect() {
+    let entity_instance = ReactiveEntityInstanceBuilder::new("test").property("x", json!(0)).get();
+    let stream = EntityPropertyInstanceStream::new(entity_instance.clone(), String::from("x"), &GraphQLSubscriptionConfig::default());
+    // The subscription, the observer of the property and the handle of the test
+    let buffer = stream.get_buffer();
+    assert_eq!(3, buffer.handles());
+
+    actix_web::rt::System::new().block_on(async {
+        let mut events = entity_property_events(stream);
+        entity_instance.set("x", json!(1));
+        assert_eq!(to_server_sent_event(None, &json!(1)), events.next().await.unwrap().unwrap());
+        let response = HttpResponse::Ok().streaming(events);
+        // The client disconnects
+        drop(response);
+    });
+    assert_eq!(1, buffer.handles());
+
+    // Changes of the property are not buffered anymore
+    entity_instance.set("x", json!(2));
+    assert!(buffer.is_empty());
+}
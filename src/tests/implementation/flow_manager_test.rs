@@ -0,0 +1,105 @@
+use uuid::Uuid;
+
+use crate::api::FLOW_MEMBERS;
+use crate::builder::{EntityInstanceBuilder, EntityTypeBuilder};
+use crate::model::Flow;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::{r_json_string, r_string};
+
+#[test]
+fn test_flow_manager() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let entity_instance_manager = application.get_entity_instance_manager();
+    let flow_manager = application.get_flow_manager();
+
+    let type_name = r_string();
+    let property_name = r_string();
+    let property_value = r_json_string();
+
+    let entity_type = EntityTypeBuilder::new(type_name.clone()).string_property(property_name.clone()).build();
+    entity_type_manager.register(entity_type.clone());
+
+    let flow_id = Uuid::new_v4();
+    let wrapper_entity_instance = EntityInstanceBuilder::new(type_name.clone())
+        .id(flow_id)
+        .property(property_name.clone(), property_value.clone())
+        .get();
+    let entity_instance = EntityInstanceBuilder::new(type_name.clone())
+        .property(property_name.clone(), property_value.clone())
+        .get();
+    let flow = Flow {
+        id: flow_id,
+        type_name: type_name.clone(),
+        name: r_string(),
+        description: r_string(),
+        entity_instances: vec![wrapper_entity_instance, entity_instance.clone()],
+        relation_instances: Vec::new(),
+    };
+
+    // Check if get returns none for a non-existent flow
+    assert!(flow_manager.get(flow_id).is_none());
+
+    let result = flow_manager.create(flow.clone());
+    assert!(result.is_ok());
+
+    // Check if the flow can be read from the graph database
+    let stored_flow = flow_manager.get(flow_id);
+    assert!(stored_flow.is_some());
+    let stored_flow = stored_flow.unwrap();
+    assert_eq!(flow_id, stored_flow.id);
+    assert_eq!(type_name, stored_flow.type_name);
+    assert_eq!(flow.name, stored_flow.name);
+    assert_eq!(flow.description, stored_flow.description);
+    assert_eq!(2, stored_flow.entity_instances.len());
+    assert!(stored_flow.entity_instances.iter().any(|e| e.id == entity_instance.id));
+
+    // Check that only the wrapper entity instance is a flow
+    let flows = flow_manager.get_all();
+    assert_eq!(1, flows.len());
+    assert_eq!(flow_id, flows.first().unwrap().id);
+
+    // Check that the members of the flow are not part of the wrapper entity instance
+    let wrapper_entity_instance = entity_instance_manager.get(flow_id).unwrap();
+    assert!(!wrapper_entity_instance.properties.contains_key(FLOW_MEMBERS));
+    assert!(entity_instance_manager
+        .get_all()
+        .iter()
+        .all(|entity_instance| !entity_instance.properties.contains_key(FLOW_MEMBERS)));
+}
+
+#[test]
+fn test_create_reactive_flow_which_cannot_be_stored() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let entity_instance_manager = application.get_entity_instance_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let reactive_flow_manager = application.get_reactive_flow_manager();
+
+    let type_name = r_string();
+    let entity_type = EntityTypeBuilder::new(type_name.clone()).build();
+    entity_type_manager.register(entity_type.clone());
+
+    let flow_id = Uuid::new_v4();
+    let wrapper_entity_instance = EntityInstanceBuilder::new(type_name.clone()).id(flow_id).get();
+    // The entity type of the second entity instance doesn't exist
+    let entity_instance = EntityInstanceBuilder::new(r_string()).get();
+    let flow = Flow {
+        id: flow_id,
+        type_name: type_name.clone(),
+        name: r_string(),
+        description: r_string(),
+        entity_instances: vec![wrapper_entity_instance, entity_instance.clone()],
+        relation_instances: Vec::new(),
+    };
+
+    let result = reactive_flow_manager.create(flow);
+    assert!(result.is_err());
+
+    // Check that neither the flow nor its members have been left behind
+    assert!(!reactive_flow_manager.has(flow_id));
+    assert!(!reactive_entity_instance_manager.has(flow_id));
+    assert!(!reactive_entity_instance_manager.has(entity_instance.id));
+    assert!(!entity_instance_manager.has(flow_id));
+    assert!(!entity_instance_manager.has(entity_instance.id));
+}
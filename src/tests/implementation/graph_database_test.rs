@@ -0,0 +1,116 @@
+use std::env;
+
+use indradb::{Identifier, SpecificVertexQuery, Vertex};
+use uuid::Uuid;
+
+use crate::api::GraphDatabaseError;
+use crate::config::load_config;
+use crate::config::ConfigError;
+use crate::config::GraphDatabaseBackend;
+use crate::config::GraphDatabaseConfig;
+use crate::implementation::create_datastore_from_config;
+use crate::tests::utils::r_string;
+
+fn temp_path() -> String {
+    let mut path = env::temp_dir();
+    path.push(r_string());
+    path.into_os_string().into_string().unwrap()
+}
+
+#[test]
+fn test_default_graph_database_config_is_persistent() {
+    let graph_database_config = GraphDatabaseConfig::default();
+    assert_eq!(GraphDatabaseBackend::Rocksdb, graph_database_config.backend);
+    assert!(graph_database_config.path.is_some());
+
+    // The shipped configuration agrees with the defaults
+    let shipped_config: GraphDatabaseConfig = load_config("./config/graph_database.toml").unwrap();
+    assert_eq!(graph_database_config.backend, shipped_config.backend);
+    assert_eq!(graph_database_config.path, shipped_config.path);
+}
+
+#[test]
+fn test_volatile_memory_backend() {
+    let graph_database_config = GraphDatabaseConfig {
+        backend: GraphDatabaseBackend::Memory,
+        path: None,
+        max_open_files: None,
+    };
+    let datastore = create_datastore_from_config(&graph_database_config).unwrap();
+    assert_eq!(0, datastore.get_vertex_count().unwrap());
+}
+
+#[test]
+fn test_persistent_backends() {
+    for backend in [GraphDatabaseBackend::Memory, GraphDatabaseBackend::Rocksdb] {
+        let path = temp_path();
+        let graph_database_config = GraphDatabaseConfig {
+            backend,
+            path: Some(path.clone()),
+            max_open_files: None,
+        };
+        let id = Uuid::new_v4();
+        {
+            let datastore = create_datastore_from_config(&graph_database_config).unwrap();
+            assert!(datastore.create_vertex(&Vertex::with_id(id, Identifier::new(r_string()).unwrap())).unwrap());
+            datastore.sync().unwrap();
+        }
+
+        // Reopening the datastore restores the vertex
+        let datastore = create_datastore_from_config(&graph_database_config).unwrap();
+        assert_eq!(1, datastore.get_vertices(SpecificVertexQuery::single(id).into()).unwrap().len());
+        drop(datastore);
+
+        let _ = std::fs::remove_file(path.clone());
+        let _ = std::fs::remove_dir_all(path);
+    }
+}
+
+#[test]
+fn test_rocksdb_backend_requires_path() {
+    let graph_database_config = GraphDatabaseConfig {
+        backend: GraphDatabaseBackend::Rocksdb,
+        path: None,
+        max_open_files: None,
+    };
+    assert!(matches!(create_datastore_from_config(&graph_database_config), Err(GraphDatabaseError::MissingPath)));
+}
+
+#[test]
+fn test_unreadable_datastore_is_an_error() {
+    // A directory is not a valid in-memory datastore file
+    let path = temp_path();
+    std::fs::create_dir_all(path.clone()).unwrap();
+    let graph_database_config = GraphDatabaseConfig {
+        backend: GraphDatabaseBackend::Memory,
+        path: Some(path.clone()),
+        max_open_files: None,
+    };
+    assert!(matches!(create_datastore_from_config(&graph_database_config), Err(GraphDatabaseError::OpenError(_, _))));
+    let _ = std::fs::remove_dir_all(path);
+}
+
+#[test]
+fn test_load_config() {
+    // A missing file results in the default configuration
+    let graph_database_config: GraphDatabaseConfig = load_config(temp_path().as_str()).unwrap();
+    assert_eq!(GraphDatabaseBackend::Rocksdb, graph_database_config.backend);
+
+    let path = temp_path();
+    std::fs::write(path.clone(), "backend = \"memory\"\n").unwrap();
+    let graph_database_config: GraphDatabaseConfig = load_config(path.as_str()).unwrap();
+    assert_eq!(GraphDatabaseBackend::Memory, graph_database_config.backend);
+    assert!(graph_database_config.path.is_none());
+
+    // Invalid TOML is an error instead of the default configuration
+    std::fs::write(path.clone(), "backend = \"rocks\n").unwrap();
+    let result: Result<GraphDatabaseConfig, ConfigError> = load_config(path.as_str());
+    assert!(matches!(result, Err(ConfigError::InvalidToml(_, _))));
+
+    // Unknown backends are rejected, too
+    std::fs::write(path.clone(), "backend = \"sqlite\"\n").unwrap();
+    let result: Result<GraphDatabaseConfig, ConfigError> = load_config(path.as_str());
+    assert!(matches!(result, Err(ConfigError::InvalidToml(_, _))));
+
+    let _ = std::fs::remove_file(path);
+}
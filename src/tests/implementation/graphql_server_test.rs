@@ -0,0 +1,94 @@
+use actix_web::http::header;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use actix_web::web;
+use actix_web::App;
+use actix_web::HttpResponse;
+
+use crate::config::GraphQLCorsConfig;
+use crate::config::GraphQLCorsConfigError;
+
+fn cors_config(allowed_origins: Vec<&str>, allowed_methods: Vec<&str>, allowed_headers: Vec<&str>) -> GraphQLCorsConfig {
+    GraphQLCorsConfig {
+        allowed_origins: allowed_origins.into_iter().map(String::from).collect(),
+        allowed_methods: allowed_methods.into_iter().map(String::from).collect(),
+        allowed_headers: allowed_headers.into_iter().map(String::from).collect(),
+        supports_credentials: false,
+        max_age: None,
+    }
+}
+
+/// Sends a preflight request for the given origin and returns the allowed origin.
+fn preflight(cors_config: GraphQLCorsConfig, origin: &str) -> Option<String> {
+    let cors_policy = cors_config.to_cors_policy().unwrap();
+    actix_web::rt::System::new().block_on(async move {
+        let app = test::init_service(App::new().wrap(cors_policy.to_cors()).route("/graphql", web::post().to(HttpResponse::Ok))).await;
+        let request = test::TestRequest::default()
+            .method(actix_web::http::Method::OPTIONS)
+            .uri("/graphql")
+            .insert_header((header::ORIGIN, origin))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, "POST"))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        match response.status() {
+            StatusCode::OK => response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|value| value.to_str().unwrap().to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Sends a request with the given origin to the server at `localhost:31415` and returns the
+/// status code.
+fn request(cors_config: GraphQLCorsConfig, origin: &str) -> StatusCode {
+    let cors_policy = cors_config.to_cors_policy().unwrap();
+    actix_web::rt::System::new().block_on(async move {
+        let app = test::init_service(App::new().wrap(cors_policy.to_cors()).route("/graphql", web::post().to(HttpResponse::Ok))).await;
+        let request = test::TestRequest::post()
+            .uri("/graphql")
+            .insert_header((header::HOST, "localhost:31415"))
+            .insert_header((header::ORIGIN, origin))
+            .to_request();
+        match test::try_call_service(&app, request).await {
+            Ok(response) => response.status(),
+            Err(e) => e.as_response_error().status_code(),
+        }
+    })
+}
+
+#[test]
+fn test_cors_default_policy() {
+    // Without a CORS policy, only same-origin requests are permitted
+    let cors_config = GraphQLCorsConfig::default();
+    assert_eq!(Some(vec![]), cors_config.to_cors_policy().unwrap().allowed_origins);
+    assert_eq!(None, preflight(cors_config.clone(), "https://example.com"));
+    assert_eq!(StatusCode::OK, request(cors_config.clone(), "http://localhost:31415"));
+    assert_ne!(StatusCode::OK, request(cors_config, "https://example.com"));
+}
+
+#[test]
+fn test_cors_wildcard_origin() {
+    // The wildcard doesn't fail in the workers, but allows any origin
+    let cors_config = cors_config(vec!["*"], vec![], vec![]);
+    assert!(cors_config.to_cors_policy().unwrap().allowed_origins.is_none());
+    assert_eq!(Some(String::from("https://example.com")), preflight(cors_config, "https://example.com"));
+}
+
+#[test]
+fn test_cors_allowed_origins() {
+    let cors_config = cors_config(vec!["https://example.com"], vec!["GET", "POST"], vec!["Authorization", "Content-Type"]);
+    assert_eq!(Some(String::from("https://example.com")), preflight(cors_config.clone(), "https://example.com"));
+    assert_eq!(None, preflight(cors_config, "https://example.org"));
+}
+
+#[test]
+fn test_invalid_cors_config_is_an_error() {
+    let result = cors_config(vec!["example com"], vec![], vec![]).to_cors_policy();
+    assert!(matches!(result, Err(GraphQLCorsConfigError::InvalidOrigin(_))));
+    let result = cors_config(vec![], vec!["GET POST"], vec![]).to_cors_policy();
+    assert!(matches!(result, Err(GraphQLCorsConfigError::InvalidMethod(_))));
+    let result = cors_config(vec![], vec![], vec!["Content Type"]).to_cors_policy();
+    assert!(matches!(result, Err(GraphQLCorsConfigError::InvalidHeader(_))));
+}
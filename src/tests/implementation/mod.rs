@@ -1,13 +1,30 @@
+mod access_control_manager_test;
 mod application_test;
+mod auth_manager_test;
 mod component_manager_test;
 mod entity_instance_manager_test;
 mod entity_type_manager_test;
 mod entity_vertex_manager_test;
+mod events_test;
+mod flow_manager_test;
+mod graph_database_test;
+mod graphql_server_test;
+mod openapi_test;
 // needs work
 mod reactive_entity_instance_manager_bench;
 mod reactive_entity_instance_manager_test;
 // needs work
 // mod reactive_relation_instance_manager_test;
+#[cfg(feature = "plugin-api-next")]
+mod plugin_context_test;
+mod plugin_registry_test;
+mod property_journal_test;
+mod property_validator_test;
 mod relation_edge_manager_test;
 mod relation_instance_manager_test;
 mod relation_type_manager_test;
+mod rest_test;
+mod snapshot_manager_test;
+mod subscription_test;
+mod system_event_manager_test;
+mod transaction_manager_test;
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use actix_web::http::Method;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use actix_web::web;
+use actix_web::App;
+use actix_web::HttpResponse;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::config::GraphQLSubscriptionConfig;
+use crate::rest::configure_rest_services;
+use crate::rest::openapi::get_openapi_document;
+use crate::rest::openapi::get_path_parameters;
+use crate::rest::openapi::get_rest_routes;
+use crate::rest::openapi::to_openapi_path;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+fn collect_refs(value: &Value, refs: &mut HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                refs.insert(reference.trim_start_matches("#/components/schemas/").to_string());
+            }
+            map.values().for_each(|value| collect_refs(value, refs));
+        }
+        Value::Array(values) => values.iter().for_each(|value| collect_refs(value, refs)),
+        _ => {}
+    }
+}
+
+#[test]
+fn test_path_parameters() {
+    assert_eq!(vec!["id", "entity_id"], get_path_parameters("/flows/{id}/entities/{entity_id}"));
+    assert_eq!(vec!["label"], get_path_parameters("/instances/entities/label/{label:.*}"));
+    assert!(get_path_parameters("/flows").is_empty());
+    assert_eq!("/instances/entities/label/{label}", to_openapi_path("/instances/entities/label/{label:.*}"));
+    assert_eq!(
+        "/instances/relations/{outbound_id}/{type_name}/{inbound_id}/tick",
+        to_openapi_path("/instances/relations/{outbound_id}/{type_name}/{inbound_id}/tick")
+    );
+}
+
+#[test]
+fn test_openapi_document() {
+    let routes = get_rest_routes();
+    let operation_ids: HashSet<&str> = routes.iter().map(|route| route.operation_id).collect();
+    assert_eq!(routes.len(), operation_ids.len());
+
+    let document = get_openapi_document();
+    assert_eq!("3.0.3", document["openapi"]);
+    for route in routes.iter() {
+        let operation = &document["paths"][to_openapi_path(route.path)][route.method];
+        assert_eq!(route.operation_id, operation["operationId"]);
+    }
+    let operation = &document["paths"]["/instances/entities/label/{label}"]["put"];
+    assert_eq!("label", operation["parameters"][0]["name"]);
+    assert_eq!(
+        "#/components/schemas/EntityInstanceUpdate",
+        operation["requestBody"]["content"]["application/json"]["schema"]["$ref"]
+    );
+
+    // All referenced schemas have to be defined
+    let mut refs = HashSet::new();
+    collect_refs(&document, &mut refs);
+    for reference in refs {
+        assert!(document["components"]["schemas"].get(&reference).is_some(), "Schema {} is not defined", reference);
+    }
+}
+
+/// Collects the methods and paths of the route attributes (`#[get("/path")]`) of the handlers
+/// in the given directory.
+fn collect_route_attributes(directory: &Path, route_attributes: &mut HashSet<(String, String)>) {
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_route_attributes(&path, route_attributes);
+            continue;
+        }
+        for line in std::fs::read_to_string(&path).unwrap().lines() {
+            for method in ["get", "post", "put", "delete"] {
+                if let Some(route_path) = line.trim().strip_prefix(&format!("#[{}(\"", method)).and_then(|rest| rest.strip_suffix("\")]")) {
+                    route_attributes.insert((method.to_string(), route_path.to_string()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rest_handlers_are_documented() {
+    let mut route_attributes = HashSet::new();
+    collect_route_attributes(Path::new("./src/rest"), &mut route_attributes);
+    // The OpenAPI document doesn't describe itself
+    assert!(route_attributes.remove(&(String::from("get"), String::from("/openapi.json"))));
+
+    let routes: HashSet<(String, String)> = get_rest_routes()
+        .iter()
+        .map(|route| (route.method.to_string(), route.path.to_string()))
+        .collect();
+    for route_attribute in route_attributes.iter() {
+        assert!(
+            routes.contains(route_attribute),
+            "The handler of {} {} is not described in the OpenAPI document",
+            route_attribute.0,
+            route_attribute.1
+        );
+    }
+    for route in routes.iter() {
+        assert!(route_attributes.contains(route), "The route {} {} has no handler", route.0, route.1);
+    }
+}
+
+#[test]
+fn test_rest_routes_are_registered() {
+    let application = init_application();
+    let requests: Vec<(String, String, String)> = get_rest_routes()
+        .iter()
+        .map(|route| {
+            // Replace the path parameters with valid values
+            let mut path = route.path.to_string();
+            for parameter in get_path_parameters(route.path) {
+                let value = if parameter == "id" || parameter.ends_with("_id") {
+                    Uuid::new_v4().to_string()
+                } else if parameter == "label" {
+                    format!("org/inexor/test/{}", r_string())
+                } else {
+                    r_string()
+                };
+                let start = path.find(&format!("{{{}", parameter)).unwrap();
+                let end = start + path[start..].find('}').unwrap() + 1;
+                path.replace_range(start..end, &value);
+            }
+            (route.method.to_uppercase(), path, route.path.to_string())
+        })
+        .collect();
+
+    actix_web::rt::System::new().block_on(async move {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(application.get_component_manager()))
+                .app_data(web::Data::new(application.get_entity_type_manager()))
+                .app_data(web::Data::new(application.get_relation_type_manager()))
+                .app_data(web::Data::new(application.get_reactive_entity_instance_manager()))
+                .app_data(web::Data::new(application.get_reactive_relation_instance_manager()))
+                .app_data(web::Data::new(application.get_reactive_flow_manager()))
+                .app_data(web::Data::new(application.get_auth_manager()))
+                .app_data(web::Data::new(application.get_access_control_manager()))
+                .app_data(web::Data::new(application.get_event_manager()))
+                .app_data(web::Data::new(application.get_property_validator()))
+                .app_data(web::Data::new(GraphQLSubscriptionConfig::default()))
+                .configure(configure_rest_services)
+                // Requests which don't match a registered service
+                .default_service(web::to(|| async { HttpResponse::build(StatusCode::IM_A_TEAPOT).finish() })),
+        )
+        .await;
+        for (method, path, route_path) in requests {
+            let request = test::TestRequest::default()
+                .method(Method::from_bytes(method.as_bytes()).unwrap())
+                .uri(&path)
+                .to_request();
+            let response = test::call_service(&app, request).await;
+            assert_ne!(StatusCode::IM_A_TEAPOT, response.status(), "No service is registered for {} {}", method, route_path);
+        }
+    });
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+use async_trait::async_trait;
+use indradb::{EdgeKey, Identifier};
+use serde_json::{json, Value};
+
+use crate::api::GraphQLQueryService;
+use crate::builder::{EntityTypeBuilder, RelationTypeBuilder};
+use crate::graphql::InexorSchema;
+use crate::graphql::InexorSchemaBuilder;
+use crate::plugin::GraphQLQueryExecutorImpl;
+use crate::plugin::GraphQueryManagerImpl;
+use crate::plugins::GraphQLQueryExecutor;
+use crate::plugins::GraphQueryManager;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+#[test]
+fn test_graph_query_manager() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let relation_type_manager = application.get_relation_type_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let reactive_relation_instance_manager = application.get_reactive_relation_instance_manager();
+    let graph_query_manager = GraphQueryManagerImpl::new(
+        reactive_entity_instance_manager.clone(),
+        reactive_relation_instance_manager.clone(),
+        application.get_reactive_flow_manager(),
+    );
+
+    let entity_type_name = r_string();
+    let relation_type_name = r_string();
+    let other_relation_type_name = r_string();
+    entity_type_manager.register(EntityTypeBuilder::new(entity_type_name.clone()).string_property("label").build());
+    for type_name in [relation_type_name.clone(), other_relation_type_name.clone()] {
+        relation_type_manager.register(RelationTypeBuilder::new(entity_type_name.clone(), type_name, entity_type_name.clone()).build());
+    }
+
+    let create_entity_instance = |label: &str| {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("label"), json!(label));
+        reactive_entity_instance_manager.create(entity_type_name.clone(), properties).unwrap()
+    };
+    let label = format!("/org/inexor/test/{}", r_string());
+    let source = create_entity_instance(label.as_str());
+    let target = create_entity_instance(format!("/org/inexor/test/{}", r_string()).as_str());
+    let other_target = create_entity_instance(format!("/org/inexor/test/{}", r_string()).as_str());
+
+    let edge_key = EdgeKey::new(source.id, Identifier::new(relation_type_name.clone()).unwrap(), target.id);
+    reactive_relation_instance_manager.create(edge_key, HashMap::new()).unwrap();
+    let edge_key = EdgeKey::new(source.id, Identifier::new(other_relation_type_name.clone()).unwrap(), other_target.id);
+    reactive_relation_instance_manager.create(edge_key, HashMap::new()).unwrap();
+
+    // Label lookup
+    assert_eq!(source.id, graph_query_manager.get_entity_instance_by_label(label).unwrap().id);
+    assert!(graph_query_manager
+        .get_entity_instance_by_label(format!("/org/inexor/test/{}", r_string()))
+        .is_none());
+
+    // Outbound relations with and without type filter
+    assert_eq!(2, graph_query_manager.get_outbound_relations(source.id, None).len());
+    let outbound_entities = graph_query_manager.get_outbound_entities(source.id, Some(relation_type_name.clone()));
+    assert_eq!(1, outbound_entities.len());
+    assert_eq!(target.id, outbound_entities.first().unwrap().id);
+    assert!(graph_query_manager.get_outbound_relations(target.id, None).is_empty());
+
+    // Inbound relations with and without type filter
+    let inbound_entities = graph_query_manager.get_inbound_entities(other_target.id, None);
+    assert_eq!(1, inbound_entities.len());
+    assert_eq!(source.id, inbound_entities.first().unwrap().id);
+    assert!(graph_query_manager.get_inbound_relations(other_target.id, Some(relation_type_name)).is_empty());
+
+    // Flows
+    assert!(graph_query_manager.get_flows_by_type(r_string()).is_empty());
+    assert!(graph_query_manager.get_flow_by_label(format!("/org/inexor/test/{}", r_string())).is_none());
+}
+
+#[test]
+fn test_graphql_query_executor() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let type_name = r_string();
+    entity_type_manager.register(EntityTypeBuilder::new(type_name.clone()).build());
+
+    let graphql_query_executor = GraphQLQueryExecutorImpl::new(application.get_graphql_query_service());
+    let query = format!("{{ types {{ entities(name: \"{}\") {{ name }} }} }}", type_name);
+    let response: Value = serde_json::from_str(graphql_query_executor.execute(query).unwrap().as_str()).unwrap();
+    assert_eq!(json!(type_name), response["data"]["types"]["entities"][0]["name"]);
+}
+
+/// Answers every request with the request after giving up control once.
+struct EchoQueryService;
+
+#[async_trait]
+impl GraphQLQueryService for EchoQueryService {
+    fn get_schema_builder(&self) -> InexorSchemaBuilder {
+        unimplemented!()
+    }
+
+    fn get_schema(&self) -> InexorSchema {
+        unimplemented!()
+    }
+
+    async fn query(&self, request: String) -> Result<String, serde_json::Error> {
+        async_std::task::yield_now().await;
+        Ok(request)
+    }
+}
+
+#[test]
+fn test_graphql_query_executor_within_async_runtime() {
+    let graphql_query_executor = Arc::new(GraphQLQueryExecutorImpl::new(Arc::new(EchoQueryService)));
+
+    // A plugin calls the executor from a task of the async runtime
+    let executor = graphql_query_executor.clone();
+    let response = async_std::task::block_on(async_std::task::spawn(async move { executor.execute(String::from("query")) }));
+    assert_eq!(String::from("query"), response.unwrap());
+
+    // Concurrent plugins share the executor
+    let handles: Vec<thread::JoinHandle<String>> = (0..8)
+        .map(|i| {
+            let executor = graphql_query_executor.clone();
+            thread::spawn(move || executor.execute(format!("query {}", i)).unwrap())
+        })
+        .collect();
+    for (i, handle) in handles.into_iter().enumerate() {
+        assert_eq!(format!("query {}", i), handle.join().unwrap());
+    }
+}
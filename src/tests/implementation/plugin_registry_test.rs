@@ -0,0 +1,86 @@
+use crate::plugin::config::PluginConfig;
+use crate::plugin::resolve_load_order;
+use crate::plugin::PluginDependencyError;
+use crate::plugin::PluginLoadError;
+use crate::plugin::PluginState;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+fn plugin_config(name: &str, dependencies: Vec<&str>) -> PluginConfig {
+    PluginConfig {
+        name: String::from(name),
+        active: true,
+        path: format!("./{}.so", name),
+        dependencies: dependencies.into_iter().map(String::from).collect(),
+        config: None,
+    }
+}
+
+#[test]
+fn test_plugin_load_order() {
+    let plugins = vec![
+        plugin_config("connector", vec!["base"]),
+        plugin_config("logical", vec!["base", "connector"]),
+        plugin_config("base", vec![]),
+        plugin_config("metadata", vec![]),
+    ];
+    let (load_order, errors) = resolve_load_order(&plugins);
+    assert!(errors.is_empty());
+    let names: Vec<&str> = load_order.iter().map(|plugin| plugin.name.as_str()).collect();
+    assert_eq!(vec!["base", "connector", "logical", "metadata"], names);
+}
+
+#[test]
+fn test_plugin_load_order_unmet_dependencies() {
+    let mut inactive = plugin_config("base", vec![]);
+    inactive.active = false;
+    let plugins = vec![
+        inactive,
+        plugin_config("connector", vec!["base"]),
+        plugin_config("logical", vec!["connector"]),
+        plugin_config("metadata", vec![]),
+    ];
+    let (load_order, errors) = resolve_load_order(&plugins);
+    let names: Vec<&str> = load_order.iter().map(|plugin| plugin.name.as_str()).collect();
+    assert_eq!(vec!["metadata"], names);
+    assert!(errors.contains(&PluginDependencyError::UnmetDependencies {
+        name: String::from("connector"),
+        dependencies: vec![String::from("base")],
+    }));
+    assert!(errors.contains(&PluginDependencyError::UnmetDependencies {
+        name: String::from("logical"),
+        dependencies: vec![String::from("connector")],
+    }));
+}
+
+#[test]
+fn test_plugin_load_order_cyclic_dependency() {
+    let plugins = vec![plugin_config("a", vec!["b"]), plugin_config("b", vec!["a"]), plugin_config("c", vec![])];
+    let (load_order, errors) = resolve_load_order(&plugins);
+    let names: Vec<&str> = load_order.iter().map(|plugin| plugin.name.as_str()).collect();
+    assert_eq!(vec!["c"], names);
+    assert_eq!(2, errors.len());
+    assert!(errors.contains(&PluginDependencyError::CyclicDependency { name: String::from("a") }));
+}
+
+#[test]
+fn test_plugin_load_error_missing_file() {
+    let application = init_application();
+    let plugin_registry = application.get_plugin_registry();
+    let path = format!("./{}.so", r_string());
+    let result = unsafe { plugin_registry.load(path.clone()) };
+    assert_eq!(Err(PluginLoadError::MissingFile { path }), result);
+}
+
+#[test]
+fn test_activate_plugin_which_failed_to_load() {
+    let application = init_application();
+    let plugin_registry = application.get_plugin_registry();
+    let name = r_string();
+    plugin_registry.load_plugin(name.clone(), format!("./{}.so", name));
+    assert_eq!(PluginState::Failed, plugin_registry.get_plugin_status(name.clone()).unwrap().state);
+    // The plugin is still not running, so activating it must fail
+    assert!(plugin_registry.activate_plugin(name.clone()).is_err());
+    assert_eq!(PluginState::Failed, plugin_registry.get_plugin_status(name.clone()).unwrap().state);
+    assert!(!plugin_registry.has(name));
+}
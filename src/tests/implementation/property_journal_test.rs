@@ -0,0 +1,176 @@
+use std::env;
+use std::io::Write;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::api::PropertyJournalEntry;
+use crate::builder::EntityTypeBuilder;
+use crate::builder::ReactiveEntityInstanceBuilder;
+use crate::config::PropertyJournalConfig;
+use crate::implementation::read_journal;
+use crate::implementation::replay_journal;
+use crate::implementation::PropertyJournalState;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_json_string;
+use crate::tests::utils::r_string;
+
+fn create_journal() -> (PropertyJournalState, String) {
+    let mut path = env::temp_dir();
+    path.push(format!("{}.jsonl", r_string()));
+    let path = path.into_os_string().into_string().unwrap();
+    let journal = PropertyJournalState::new(PropertyJournalConfig {
+        enabled: true,
+        path: path.clone(),
+        ..Default::default()
+    });
+    journal.open();
+    (journal, path)
+}
+
+fn count_lines(path: &str) -> usize {
+    std::fs::read_to_string(path).unwrap().lines().count()
+}
+
+#[test]
+fn test_append_is_buffered() {
+    let (journal, path) = create_journal();
+    let id = Uuid::new_v4();
+    let property_name = r_string();
+
+    journal.append(id, property_name.clone(), json!(1));
+    journal.append(id, property_name.clone(), json!(2));
+    assert_eq!(1, journal.get_entries().len());
+    assert_eq!(0, count_lines(&path));
+
+    // Flushing writes every change, but only the latest value is relevant
+    journal.flush();
+    assert_eq!(2, count_lines(&path));
+    let latest_values = read_journal(&path);
+    assert_eq!(1, latest_values.len());
+    assert_eq!(json!(2), latest_values.get(&(id, property_name)).unwrap().value);
+
+    journal.close();
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_compaction() {
+    let (journal, path) = create_journal();
+    let committed_id = Uuid::new_v4();
+    let uncommitted_id = Uuid::new_v4();
+    let property_name = r_string();
+
+    journal.append(committed_id, property_name.clone(), json!(1));
+    journal.append(committed_id, property_name.clone(), json!(2));
+    journal.append(uncommitted_id, property_name.clone(), json!(3));
+    journal.flush();
+    assert_eq!(3, count_lines(&path));
+
+    // Removing the entries of a committed instance doesn't touch the journal file
+    journal.remove(committed_id);
+    assert_eq!(1, journal.get_entries().len());
+    assert_eq!(3, count_lines(&path));
+
+    // The compaction keeps the uncommitted changes only
+    journal.compact();
+    assert_eq!(1, count_lines(&path));
+    let latest_values = read_journal(&path);
+    assert!(latest_values.contains_key(&(uncommitted_id, property_name.clone())));
+    assert!(!latest_values.contains_key(&(committed_id, property_name.clone())));
+
+    // Appending continues in the compacted journal file
+    journal.append(uncommitted_id, property_name.clone(), json!(4));
+    journal.flush();
+    assert_eq!(2, count_lines(&path));
+    assert_eq!(json!(4), read_journal(&path).get(&(uncommitted_id, property_name)).unwrap().value);
+    assert!(!std::path::Path::new(&format!("{}.compact", path)).exists());
+
+    journal.close();
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_compaction_after_reopen() {
+    let (journal, path) = create_journal();
+    journal.append(Uuid::new_v4(), r_string(), json!(1));
+    journal.close();
+    assert_eq!(1, count_lines(&path));
+
+    // The entries of the previous run are removed after they have been replayed
+    let journal = PropertyJournalState::new(PropertyJournalConfig {
+        enabled: true,
+        path: path.clone(),
+        ..Default::default()
+    });
+    journal.open();
+    journal.clear();
+    journal.compact();
+    assert_eq!(0, count_lines(&path));
+
+    journal.close();
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_read_journal_with_torn_last_line() {
+    let (journal, path) = create_journal();
+    let id = Uuid::new_v4();
+    let property_name = r_string();
+    journal.append(id, property_name.clone(), json!(1));
+    journal.append(id, property_name.clone(), json!(2));
+    journal.close();
+
+    // The process crashed while writing the last entry
+    let entry = PropertyJournalEntry {
+        id,
+        property: property_name.clone(),
+        value: json!(3),
+        timestamp: 0,
+    };
+    let line = serde_json::to_string(&entry).unwrap();
+    let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&line.as_bytes()[..line.len() / 2]).unwrap();
+    drop(file);
+
+    let latest_values = read_journal(&path);
+    assert_eq!(1, latest_values.len());
+    assert_eq!(json!(2), latest_values.get(&(id, property_name)).unwrap().value);
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_replay() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let entity_instance_manager = application.get_entity_instance_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+
+    let type_name = r_string();
+    let property_name = r_string();
+    let committed_value = r_json_string();
+    let uncommitted_value = r_json_string();
+
+    let entity_type = EntityTypeBuilder::new(type_name.clone()).string_property(property_name.clone()).build();
+    entity_type_manager.register(entity_type);
+    let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name.clone())
+        .property(property_name.clone(), committed_value.clone())
+        .get();
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
+    let id = reactive_entity_instance.id;
+
+    // The journal of the previous run contains an uncommitted change and a change of an entity
+    // instance which doesn't exist anymore
+    let (journal, path) = create_journal();
+    journal.append(id, property_name.clone(), uncommitted_value.clone());
+    journal.append(Uuid::new_v4(), property_name.clone(), r_json_string());
+    journal.close();
+
+    replay_journal(&path, &*application.get_entity_vertex_manager());
+    assert_eq!(&uncommitted_value, entity_instance_manager.get(id).unwrap().properties.get(&property_name).unwrap());
+
+    let _ = std::fs::remove_file(path);
+}
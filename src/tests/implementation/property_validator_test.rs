@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::api::compile_patterns;
+use crate::api::get_default_value;
+use crate::api::matches_data_type;
+use crate::api::validate_new_properties;
+use crate::api::validate_properties;
+use crate::api::with_default_values;
+use crate::api::InvalidProperty;
+use crate::api::PropertyConstraints;
+use crate::api::PROPERTY_CONSTRAINTS_EXTENSION;
+use crate::builder::ComponentBuilder;
+use crate::builder::EntityTypeBuilder;
+use crate::builder::RelationTypeBuilder;
+use crate::model::DataType;
+use crate::model::Extension;
+use crate::model::PropertyType;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+#[test]
+fn test_validate_properties() {
+    assert!(matches_data_type(DataType::Number, &json!(1.5)));
+    assert!(!matches_data_type(DataType::Number, &json!("1.5")));
+    assert!(matches_data_type(DataType::Any, &json!({ "x": 1 })));
+
+    let property_types = vec![
+        PropertyType::new(String::from("count"), DataType::Number),
+        PropertyType::new(String::from("name"), DataType::String),
+    ];
+    let properties = HashMap::from([(String::from("count"), json!(1)), (String::from("name"), json!("x"))]);
+    assert!(validate_properties(&property_types, &properties, true).is_ok());
+
+    let properties = HashMap::from([
+        (String::from("count"), json!("1")),
+        (String::from("name"), json!(false)),
+        (String::from("unknown"), json!(1)),
+    ]);
+    // Unknown properties are only rejected in strict mode
+    let invalid_properties = validate_properties(&property_types, &properties, false).unwrap_err().0;
+    assert_eq!(2, invalid_properties.len());
+    assert!(matches!(&invalid_properties[0], InvalidProperty::DataTypeMismatch { name, data_type: DataType::Number, .. } if name == "count"));
+    assert!(matches!(&invalid_properties[1], InvalidProperty::DataTypeMismatch { name, data_type: DataType::String, .. } if name == "name"));
+    let invalid_properties = validate_properties(&property_types, &properties, true).unwrap_err().0;
+    assert_eq!(3, invalid_properties.len());
+    assert!(matches!(&invalid_properties[2], InvalidProperty::UnknownProperty(name) if name == "unknown"));
+}
+
+#[test]
+fn test_property_validator() {
+    let application = init_application();
+    let component_manager = application.get_component_manager();
+    let entity_type_manager = application.get_entity_type_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let property_validator = application.get_property_validator();
+
+    let component_name = r_string();
+    let type_name = r_string();
+    component_manager.create(component_name.clone(), vec![PropertyType::new(String::from("enabled"), DataType::Bool)]);
+    entity_type_manager.create(
+        type_name.clone(),
+        r_string(),
+        Vec::new(),
+        vec![PropertyType::new(String::from("count"), DataType::Number)],
+        Vec::new(),
+    );
+
+    // The graph database rejects values which don't match the data type
+    let result = reactive_entity_instance_manager.create(type_name.clone(), HashMap::from([(String::from("count"), json!("one"))]));
+    assert!(result.is_err());
+    let entity_instance = reactive_entity_instance_manager
+        .create(type_name.clone(), HashMap::from([(String::from("count"), json!(1))]))
+        .unwrap();
+
+    // Component properties are validated if the component is added
+    let properties = HashMap::from([(String::from("enabled"), json!("yes"))]);
+    assert!(property_validator.validate_entity_instance(&entity_instance, &[], &properties).is_ok());
+    assert!(property_validator
+        .validate_entity_instance(&entity_instance, &[component_name.clone()], &properties)
+        .is_err());
+    let properties = HashMap::from([(String::from("enabled"), json!(true))]);
+    assert!(property_validator
+        .validate_entity_instance(&entity_instance, &[component_name], &properties)
+        .is_ok());
+}
+
+#[test]
+fn test_property_constraints() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+
+    let type_name = r_string();
+    let mut count = PropertyType::new(String::from("count"), DataType::Number);
+    count.extensions.push(Extension {
+        name: String::from(PROPERTY_CONSTRAINTS_EXTENSION),
+        extension: json!({ "required": true, "minimum": 0, "maximum": 10 }),
+    });
+    let mut name = PropertyType::new(String::from("name"), DataType::String);
+    name.extensions.push(Extension {
+        name: String::from(PROPERTY_CONSTRAINTS_EXTENSION),
+        extension: json!({ "default": "a", "pattern": "^[a-z]+$", "enum": ["a", "b"] }),
+    });
+    assert!(PropertyConstraints::of(&count).required);
+    assert_eq!(json!("a"), get_default_value(&name));
+
+    let property_types = vec![count, name];
+    let properties = with_default_values(&property_types, HashMap::new());
+    assert!(!properties.contains_key("count"));
+    assert_eq!(Some(&json!("a")), properties.get("name"));
+
+    let invalid_properties = validate_new_properties(&property_types, &properties, false).unwrap_err().0;
+    assert!(matches!(&invalid_properties[0], InvalidProperty::MissingRequiredProperty(name) if name == "count"));
+
+    let properties = HashMap::from([(String::from("count"), json!(11)), (String::from("name"), json!("c"))]);
+    let invalid_properties = validate_properties(&property_types, &properties, false).unwrap_err().0;
+    assert!(matches!(&invalid_properties[0], InvalidProperty::AboveMaximum { .. }));
+    assert!(matches!(&invalid_properties[1], InvalidProperty::NotAllowed { .. }));
+
+    let properties = HashMap::from([(String::from("count"), json!(-1)), (String::from("name"), json!("B"))]);
+    let invalid_properties = validate_properties(&property_types, &properties, false).unwrap_err().0;
+    assert!(matches!(&invalid_properties[0], InvalidProperty::BelowMinimum { .. }));
+    assert!(matches!(&invalid_properties[1], InvalidProperty::PatternMismatch { .. }));
+
+    // The entity instance manager rejects entity instances without the required properties
+    entity_type_manager.create(type_name.clone(), r_string(), Vec::new(), property_types, Vec::new());
+    let result = reactive_entity_instance_manager.create(type_name.clone(), HashMap::from([(String::from("name"), json!("b"))]));
+    assert!(result.is_err());
+    let result = reactive_entity_instance_manager.create(type_name, HashMap::from([(String::from("count"), json!(5)), (String::from("name"), json!("b"))]));
+    assert!(result.is_ok());
+}
+
+fn property_with_pattern(pattern: &str) -> PropertyType {
+    let mut property_type = PropertyType::new(String::from("name"), DataType::String);
+    property_type.extensions.push(Extension {
+        name: String::from(PROPERTY_CONSTRAINTS_EXTENSION),
+        extension: json!({ "pattern": pattern }),
+    });
+    property_type
+}
+
+#[test]
+fn test_invalid_patterns_are_rejected_at_registration() {
+    let application = init_application();
+    let component_manager = application.get_component_manager();
+    let entity_type_manager = application.get_entity_type_manager();
+    let relation_type_manager = application.get_relation_type_manager();
+
+    assert!(compile_patterns(&[property_with_pattern("^[a-z]+$")]).is_ok());
+    let e = compile_patterns(&[PropertyType::new(String::from("count"), DataType::Number), property_with_pattern("^[a-z+$")]).unwrap_err();
+    assert_eq!("name", e.property);
+    assert_eq!("^[a-z+$", e.pattern);
+
+    let component_name = r_string();
+    let mut component = ComponentBuilder::new(component_name.clone()).build();
+    component.properties.push(property_with_pattern("(unclosed"));
+    component_manager.register(component);
+    assert!(!component_manager.has(component_name));
+
+    let entity_type_name = r_string();
+    let entity_type = EntityTypeBuilder::new(entity_type_name.clone())
+        .property_from(property_with_pattern("(unclosed"))
+        .build();
+    entity_type_manager.register(entity_type);
+    assert!(!entity_type_manager.has(entity_type_name.clone()));
+
+    // Replacing a valid entity type with an invalid one keeps the valid entity type
+    let entity_type = EntityTypeBuilder::new(entity_type_name.clone())
+        .property_from(property_with_pattern("^[a-z]+$"))
+        .build();
+    entity_type_manager.register(entity_type);
+    let entity_type = EntityTypeBuilder::new(entity_type_name.clone())
+        .property_from(property_with_pattern("(unclosed"))
+        .build();
+    entity_type_manager.replace(entity_type);
+    let entity_type = entity_type_manager.get(entity_type_name.clone()).unwrap();
+    assert_eq!(Some(String::from("^[a-z]+$")), PropertyConstraints::of(&entity_type.properties[0]).pattern);
+
+    let relation_type_name = r_string();
+    let relation_type = RelationTypeBuilder::new(entity_type_name.clone(), relation_type_name.clone(), entity_type_name)
+        .property_from(property_with_pattern("(unclosed"))
+        .build();
+    relation_type_manager.register(relation_type);
+    assert!(!relation_type_manager.has(relation_type_name));
+}
+
+#[test]
+fn test_pattern_of_registered_type() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let property_validator = application.get_property_validator();
+
+    let type_name = r_string();
+    let entity_type = EntityTypeBuilder::new(type_name.clone())
+        .property_from(property_with_pattern("^[a-z]+$"))
+        .build();
+    entity_type_manager.register(entity_type);
+    for _ in 0..3 {
+        let properties = HashMap::from([(String::from("name"), json!("abc"))]);
+        assert!(property_validator.validate_new_entity_instance(type_name.clone(), &[], &properties).is_ok());
+        let properties = HashMap::from([(String::from("name"), json!("ABC"))]);
+        let invalid_properties = property_validator
+            .validate_new_entity_instance(type_name.clone(), &[], &properties)
+            .unwrap_err()
+            .0;
+        assert!(matches!(&invalid_properties[0], InvalidProperty::PatternMismatch { .. }));
+    }
+}
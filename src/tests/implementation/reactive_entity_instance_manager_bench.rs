@@ -24,11 +24,13 @@ fn creation_benchmark(bencher: &mut Bencher) -> impl Termination {
     entity_type_manager.register(entity_type);
 
     bencher.iter(move || {
-        reactive_entity_instance_manager.register_reactive_instance(
-            ReactiveEntityInstanceBuilder::new(type_name.clone())
-                .property(property_name.clone(), property_value.clone())
-                .get(),
-        );
+        reactive_entity_instance_manager
+            .register_reactive_instance(
+                ReactiveEntityInstanceBuilder::new(type_name.clone())
+                    .property(property_name.clone(), property_value.clone())
+                    .get(),
+            )
+            .unwrap();
     })
 }
 
@@ -47,7 +49,7 @@ fn get_by_id_benchmark(bencher: &mut Bencher) -> impl Termination {
 
     let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name).property(property_name, property_value).get();
     let id = reactive_entity_instance.id;
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance);
+    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance).unwrap();
 
     bencher.iter(|| reactive_entity_instance_manager.get(id))
 }
@@ -73,7 +75,7 @@ fn get_by_label_benchmark(bencher: &mut Bencher) -> impl Termination {
         .property(property_name, property_value)
         .property("label", json!(label.clone()))
         .get();
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance);
+    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance).unwrap();
 
     bencher.iter(|| reactive_entity_instance_manager.get_by_label(label.clone()))
 }
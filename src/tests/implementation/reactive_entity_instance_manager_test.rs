@@ -1,6 +1,5 @@
 // TODO: fix these unit test
 
-use indradb::Datastore;
 use serde_json::json;
 
 use crate::builder::EntityTypeBuilder;
@@ -29,7 +28,9 @@ fn test_register_reactive_entity_instance() {
     let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name.clone())
         .property(property_name.clone(), property_value.clone())
         .get();
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance.clone());
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
     assert_eq!(1, datastore.get_vertex_count().unwrap());
     assert!(reactive_entity_instance_manager.has(reactive_entity_instance.id));
 
@@ -62,7 +63,9 @@ fn test_unregister_reactive_entity_instance() {
     let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name.clone())
         .property(property_name.clone(), property_value.clone())
         .get();
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance.clone());
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
     assert_eq!(1, datastore.get_vertex_count().unwrap());
     assert!(reactive_entity_instance_manager.has(reactive_entity_instance.id));
     reactive_entity_instance_manager.unregister_reactive_instance(reactive_entity_instance.id);
@@ -90,8 +93,29 @@ fn test_not_register_twice_reactive_entity_instance() {
         .property(property_name.clone(), property_value.clone())
         .get();
     assert_eq!(0, datastore.get_vertex_count().unwrap());
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance.clone());
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
     assert_eq!(1, datastore.get_vertex_count().unwrap());
-    reactive_entity_instance_manager.register_reactive_instance(reactive_entity_instance.clone());
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
     assert_eq!(1, datastore.get_vertex_count().unwrap());
 }
+
+#[test]
+fn test_register_reactive_entity_instance_of_unknown_type() {
+    let application = init_application();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let graph_database = application.get_graph_database();
+    let datastore = graph_database.get_datastore();
+
+    // The entity instance cannot be stored because the entity type doesn't exist
+    let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(r_string()).property(r_string(), r_json_string()).get();
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_err());
+    assert_eq!(0, datastore.get_vertex_count().unwrap());
+    assert!(!reactive_entity_instance_manager.has(reactive_entity_instance.id));
+    assert!(reactive_entity_instance_manager.get(reactive_entity_instance.id).is_none());
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use actix_web::web;
+use actix_web::web::ServiceConfig;
+use actix_web::App;
+use indradb::{EdgeKey, Identifier};
+use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::api::AccessControlManager;
+use crate::api::Identity;
+use crate::api::Lifecycle;
+use crate::api::Permission;
+use crate::application::Application;
+use crate::builder::EntityTypeBuilder;
+use crate::builder::RelationTypeBuilder;
+use crate::model::PropertyInstanceGetter;
+use crate::model::ReactiveFlow;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_string;
+
+/// Permits reading anything and denies every write access.
+struct ReadOnlyAccessControlManager;
+
+impl Lifecycle for ReadOnlyAccessControlManager {
+    fn init(&self) {}
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
+
+impl AccessControlManager for ReadOnlyAccessControlManager {
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn is_permitted(&self, _identity: &Identity, permission: Permission, _resources: &[String]) -> bool {
+        permission.is_read()
+    }
+}
+
+/// Permits anything but writing instances with the protected label.
+struct ProtectedLabelAccessControlManager;
+
+const PROTECTED_LABEL: &str = "/org/inexor/test/protected";
+
+impl Lifecycle for ProtectedLabelAccessControlManager {
+    fn init(&self) {}
+
+    fn post_init(&self) {}
+
+    fn pre_shutdown(&self) {}
+
+    fn shutdown(&self) {}
+}
+
+impl AccessControlManager for ProtectedLabelAccessControlManager {
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn is_permitted(&self, _identity: &Identity, permission: Permission, resources: &[String]) -> bool {
+        permission != Permission::InstancesWrite || !resources.iter().any(|resource| resource == PROTECTED_LABEL)
+    }
+}
+
+/// Registers the REST services with the managers of the given application which are used by
+/// the tests.
+fn rest_services(config: &mut ServiceConfig, application: &Arc<dyn Application>, access_control_manager: Arc<dyn AccessControlManager>) {
+    config
+        .app_data(web::Data::new(application.get_entity_type_manager()))
+        .app_data(web::Data::new(application.get_relation_type_manager()))
+        .app_data(web::Data::new(application.get_reactive_entity_instance_manager()))
+        .app_data(web::Data::new(application.get_reactive_relation_instance_manager()))
+        .app_data(web::Data::new(application.get_reactive_flow_manager()))
+        .app_data(web::Data::new(application.get_auth_manager()))
+        .app_data(web::Data::new(access_control_manager))
+        .app_data(web::Data::new(application.get_property_validator()));
+    crate::rest::configure_rest_services(config);
+}
+
+/// Sends the given requests and returns the status codes and the response bodies.
+fn call(
+    application: Arc<dyn Application>,
+    access_control_manager: Arc<dyn AccessControlManager>,
+    requests: Vec<test::TestRequest>,
+) -> Vec<(StatusCode, String)> {
+    actix_web::rt::System::new().block_on(async move {
+        let app = test::init_service(App::new().configure(|config| rest_services(config, &application, access_control_manager))).await;
+        let mut responses = Vec::new();
+        for request in requests {
+            let response = test::call_service(&app, request.to_request()).await;
+            let status = response.status();
+            let body = test::read_body(response).await;
+            responses.push((status, String::from_utf8_lossy(&body).to_string()));
+        }
+        responses
+    })
+}
+
+struct Fixture {
+    application: Arc<dyn Application>,
+    entity_type_name: String,
+    relation_type_name: String,
+    label: String,
+    outbound_id: Uuid,
+    inbound_id: Uuid,
+    flow_id: Uuid,
+}
+
+fn create_fixture() -> Fixture {
+    let application = init_application();
+    let entity_type_name = r_string();
+    let relation_type_name = r_string();
+    application
+        .get_entity_type_manager()
+        .register(EntityTypeBuilder::new(entity_type_name.clone()).string_property("label").build());
+    application
+        .get_relation_type_manager()
+        .register(RelationTypeBuilder::new(entity_type_name.clone(), relation_type_name.clone(), entity_type_name.clone()).build());
+
+    let entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let label = format!("/org/inexor/test/{}", r_string());
+    let mut properties = HashMap::new();
+    properties.insert(String::from("label"), json!(label.clone()));
+    let outbound_id = entity_instance_manager.create(entity_type_name.clone(), properties).unwrap().id;
+    let inbound_id = entity_instance_manager.create(entity_type_name.clone(), HashMap::new()).unwrap().id;
+    let edge_key = EdgeKey::new(outbound_id, Identifier::new(relation_type_name.clone()).unwrap(), inbound_id);
+    application.get_reactive_relation_instance_manager().create(edge_key, HashMap::new()).unwrap();
+
+    let wrapper_entity_instance = entity_instance_manager.create(entity_type_name.clone(), HashMap::new()).unwrap();
+    let flow: Arc<ReactiveFlow> = Arc::new(wrapper_entity_instance.into());
+    application.get_reactive_flow_manager().register_flow(flow.clone());
+
+    Fixture {
+        application,
+        entity_type_name,
+        relation_type_name,
+        label,
+        outbound_id,
+        inbound_id,
+        flow_id: flow.id,
+    }
+}
+
+#[test]
+fn test_rest_entity_instances() {
+    let fixture = create_fixture();
+    let access_control_manager = fixture.application.get_access_control_manager();
+    // Labels are part of the path without the leading slash
+    let path = format!("/instances/entities/label{}", fixture.label);
+    let unknown_path = format!("/instances/entities/label/org/inexor/test/{}", r_string());
+    let responses = call(
+        fixture.application.clone(),
+        access_control_manager,
+        vec![
+            test::TestRequest::get().uri(&path),
+            test::TestRequest::get().uri(&unknown_path),
+            test::TestRequest::get().uri(&format!("/instances/entities/{}", Uuid::new_v4())),
+            test::TestRequest::post().uri("/instances/entities").set_json(&json!({ "type": r_string() })),
+            test::TestRequest::post()
+                .uri("/instances/entities")
+                .set_json(&json!({ "type": fixture.entity_type_name, "properties": { "label": 1 } })),
+            test::TestRequest::post()
+                .uri("/instances/entities")
+                .set_json(&json!({ "type": fixture.entity_type_name })),
+            test::TestRequest::put().uri(&unknown_path).set_json(&json!({})),
+            test::TestRequest::delete().uri(&unknown_path),
+        ],
+    );
+    let statuses: Vec<StatusCode> = responses.iter().map(|(status, _)| *status).collect();
+    assert_eq!(
+        vec![
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            StatusCode::NOT_FOUND,
+            StatusCode::BAD_REQUEST,
+            StatusCode::BAD_REQUEST,
+            StatusCode::CREATED,
+            StatusCode::NOT_FOUND,
+            StatusCode::NOT_FOUND,
+        ],
+        statuses
+    );
+
+    // The label lookup responds with the entity instance with the label
+    let entity_instance: Value = serde_json::from_str(&responses[0].1).unwrap();
+    assert_eq!(json!(fixture.outbound_id), entity_instance["id"]);
+    assert_eq!(json!(fixture.label), entity_instance["properties"]["label"]);
+}
+
+#[test]
+fn test_rest_relation_instances() {
+    let fixture = create_fixture();
+    let access_control_manager = fixture.application.get_access_control_manager();
+    let path = format!("/instances/relations/{}/{}/{}", fixture.outbound_id, fixture.relation_type_name, fixture.inbound_id);
+    let responses = call(
+        fixture.application.clone(),
+        access_control_manager,
+        vec![
+            test::TestRequest::get().uri(&path),
+            test::TestRequest::get().uri(&format!("/instances/relations/{}/{}/{}", fixture.inbound_id, fixture.relation_type_name, fixture.outbound_id)),
+            test::TestRequest::post().uri("/instances/relations").set_json(&json!({
+                "outbound_id": fixture.outbound_id,
+                "type": fixture.relation_type_name,
+                "inbound_id": Uuid::new_v4(),
+            })),
+            test::TestRequest::post().uri("/instances/relations").set_json(&json!({
+                "outbound_id": fixture.outbound_id,
+                "type": r_string(),
+                "inbound_id": fixture.inbound_id,
+            })),
+        ],
+    );
+    let statuses: Vec<StatusCode> = responses.iter().map(|(status, _)| *status).collect();
+    assert_eq!(vec![StatusCode::OK, StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST, StatusCode::BAD_REQUEST], statuses);
+}
+
+#[test]
+fn test_rest_flows() {
+    let fixture = create_fixture();
+    let access_control_manager = fixture.application.get_access_control_manager();
+    let responses = call(
+        fixture.application.clone(),
+        access_control_manager,
+        vec![
+            test::TestRequest::get().uri(&format!("/flows/{}", fixture.flow_id)),
+            test::TestRequest::get().uri(&format!("/flows/{}", Uuid::new_v4())),
+            test::TestRequest::post().uri("/flows").set_json(&json!({ "type": r_string() })),
+            // The id of an existing entity instance cannot be used for a new flow
+            test::TestRequest::post()
+                .uri("/flows")
+                .set_json(&json!({ "type": fixture.entity_type_name, "id": fixture.outbound_id })),
+            test::TestRequest::delete().uri(&format!("/flows/{}", Uuid::new_v4())),
+        ],
+    );
+    let statuses: Vec<StatusCode> = responses.iter().map(|(status, _)| *status).collect();
+    assert_eq!(
+        vec![
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            StatusCode::BAD_REQUEST,
+            StatusCode::CONFLICT,
+            StatusCode::NOT_FOUND
+        ],
+        statuses
+    );
+}
+
+#[test]
+fn test_rest_write_access_denied() {
+    let fixture = create_fixture();
+    let entity_path = format!("/instances/entities/label{}", fixture.label);
+    let relation_path = format!("/instances/relations/{}/{}/{}", fixture.outbound_id, fixture.relation_type_name, fixture.inbound_id);
+    let flow_path = format!("/flows/{}", fixture.flow_id);
+    let responses = call(
+        fixture.application.clone(),
+        Arc::new(ReadOnlyAccessControlManager),
+        vec![
+            test::TestRequest::get().uri(&entity_path),
+            test::TestRequest::put()
+                .uri(&entity_path)
+                .set_json(&json!({ "properties": { "label": "/org/inexor/test" } })),
+            test::TestRequest::delete().uri(&entity_path),
+            test::TestRequest::post()
+                .uri("/instances/entities")
+                .set_json(&json!({ "type": fixture.entity_type_name })),
+            test::TestRequest::get().uri(&relation_path),
+            test::TestRequest::delete().uri(&relation_path),
+            test::TestRequest::get().uri(&flow_path),
+            test::TestRequest::delete().uri(&flow_path),
+            test::TestRequest::post().uri("/flows").set_json(&json!({ "type": fixture.entity_type_name })),
+        ],
+    );
+    let statuses: Vec<StatusCode> = responses.iter().map(|(status, _)| *status).collect();
+    assert_eq!(
+        vec![
+            StatusCode::OK,
+            StatusCode::FORBIDDEN,
+            StatusCode::FORBIDDEN,
+            StatusCode::FORBIDDEN,
+            StatusCode::OK,
+            StatusCode::FORBIDDEN,
+            StatusCode::OK,
+            StatusCode::FORBIDDEN,
+            StatusCode::FORBIDDEN,
+        ],
+        statuses
+    );
+
+    // Denied requests don't modify anything
+    let entity_instance = fixture.application.get_reactive_entity_instance_manager().get(fixture.outbound_id).unwrap();
+    assert_eq!(Some(fixture.label), entity_instance.as_string("label"));
+    assert!(fixture.application.get_reactive_flow_manager().has(fixture.flow_id));
+}
+
+#[test]
+fn test_rest_protected_label() {
+    let fixture = create_fixture();
+    let entity_path = format!("/instances/entities/label{}", fixture.label);
+    let responses = call(
+        fixture.application.clone(),
+        Arc::new(ProtectedLabelAccessControlManager),
+        vec![
+            // The new label is checked as well as the current label
+            test::TestRequest::put()
+                .uri(&entity_path)
+                .set_json(&json!({ "properties": { "label": PROTECTED_LABEL } })),
+            // Creating a flow creates the wrapper entity instance
+            test::TestRequest::post()
+                .uri("/flows")
+                .set_json(&json!({ "type": fixture.entity_type_name, "properties": { "label": PROTECTED_LABEL } })),
+            test::TestRequest::put().uri(&entity_path).set_json(&json!({})),
+        ],
+    );
+    let statuses: Vec<StatusCode> = responses.iter().map(|(status, _)| *status).collect();
+    assert_eq!(vec![StatusCode::FORBIDDEN, StatusCode::FORBIDDEN, StatusCode::OK], statuses);
+
+    let entity_instance = fixture.application.get_reactive_entity_instance_manager().get(fixture.outbound_id).unwrap();
+    assert_eq!(Some(fixture.label), entity_instance.as_string("label"));
+    assert!(fixture
+        .application
+        .get_reactive_entity_instance_manager()
+        .get_by_label(String::from(PROTECTED_LABEL))
+        .is_none());
+}
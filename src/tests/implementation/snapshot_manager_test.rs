@@ -0,0 +1,121 @@
+use std::env;
+use std::path::Path;
+
+use serde_json::json;
+
+use crate::api::SnapshotError;
+use crate::builder::EntityTypeBuilder;
+use crate::builder::ReactiveEntityInstanceBuilder;
+use crate::model::PropertyInstanceGetter;
+use crate::model::PropertyInstanceSetter;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::r_json_string;
+use crate::tests::utils::r_string;
+
+#[test]
+fn test_snapshot_export_and_import() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let snapshot_manager = application.get_snapshot_manager();
+
+    let type_name = r_string();
+    let property_name = r_string();
+    let property_value = r_json_string();
+
+    let entity_type = EntityTypeBuilder::new(type_name.clone()).string_property(property_name.clone()).build();
+    entity_type_manager.register(entity_type.clone());
+
+    let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name.clone())
+        .property(property_name.clone(), property_value.clone())
+        .get();
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
+    let id = reactive_entity_instance.id;
+
+    // Check that the snapshot contains the entity type and the entity instance
+    let snapshot = snapshot_manager.create_snapshot();
+    assert!(snapshot.entity_types.iter().any(|entity_type| entity_type.name == type_name));
+    assert!(snapshot.entity_instances.iter().any(|entity_instance| entity_instance.id == id));
+
+    for extension in ["json", "cbor"] {
+        let mut path = env::temp_dir();
+        path.push(format!("{}.{}", r_string(), extension));
+        let path = path.into_os_string().into_string().unwrap();
+
+        let result = snapshot_manager.export(path.clone());
+        assert!(result.is_ok());
+
+        // Restore the snapshot into an empty application
+        let restored_application = init_application();
+        let restored_snapshot = restored_application.get_snapshot_manager().import(path.clone());
+        assert!(restored_snapshot.is_ok());
+        assert!(restored_application.get_entity_type_manager().has(type_name.clone()));
+        let restored_entity_instance = restored_application.get_reactive_entity_instance_manager().get(id);
+        assert!(restored_entity_instance.is_some());
+        assert_eq!(json!(property_value.clone()), restored_entity_instance.unwrap().get(property_name.as_str()).unwrap());
+
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[test]
+fn test_snapshot_export_does_not_commit() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let entity_instance_manager = application.get_entity_instance_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let snapshot_manager = application.get_snapshot_manager();
+
+    let type_name = r_string();
+    let property_name = r_string();
+    let committed_value = r_json_string();
+    let uncommitted_value = r_json_string();
+
+    let entity_type = EntityTypeBuilder::new(type_name.clone()).string_property(property_name.clone()).build();
+    entity_type_manager.register(entity_type);
+
+    let reactive_entity_instance = ReactiveEntityInstanceBuilder::new(type_name.clone())
+        .property(property_name.clone(), committed_value.clone())
+        .get();
+    assert!(reactive_entity_instance_manager
+        .register_reactive_instance(reactive_entity_instance.clone())
+        .is_ok());
+    let id = reactive_entity_instance.id;
+    reactive_entity_instance.set(property_name.clone(), uncommitted_value.clone());
+
+    let mut path = env::temp_dir();
+    path.push(format!("{}.json", r_string()));
+    let path = path.into_os_string().into_string().unwrap();
+    let snapshot = snapshot_manager.export(path.clone()).unwrap();
+    let _ = std::fs::remove_file(path);
+
+    // The snapshot contains the current value, but the graph database still contains the
+    // committed value
+    let snapshot_entity_instance = snapshot.entity_instances.iter().find(|entity_instance| entity_instance.id == id).unwrap();
+    assert_eq!(&uncommitted_value, snapshot_entity_instance.properties.get(&property_name).unwrap());
+    assert_eq!(&committed_value, entity_instance_manager.get(id).unwrap().properties.get(&property_name).unwrap());
+}
+
+#[test]
+fn test_snapshot_names() {
+    let application = init_application();
+    let snapshot_manager = application.get_snapshot_manager();
+
+    let configured_path = snapshot_manager.get_snapshot_path(None).unwrap();
+    let directory = Path::new(&configured_path).parent().unwrap();
+    assert_eq!(
+        directory.join("backup.cbor"),
+        Path::new(&snapshot_manager.get_snapshot_path(Some(String::from("backup.cbor"))).unwrap())
+    );
+
+    // Only plain file names are accepted
+    for name in ["", ".", "..", "../backup.json", "backups/backup.json", "/etc/passwd", "/tmp/backup.json"] {
+        assert!(
+            matches!(snapshot_manager.get_snapshot_path(Some(String::from(name))), Err(SnapshotError::InvalidName(_))),
+            "{} must be rejected",
+            name
+        );
+    }
+}
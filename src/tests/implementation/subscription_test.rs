@@ -0,0 +1,68 @@
+use futures::executor::block_on;
+use futures::FutureExt;
+use futures_util::StreamExt;
+use serde_json::json;
+
+use crate::builder::ReactiveEntityInstanceBuilder;
+use crate::config::BackpressurePolicy;
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::EntityPropertiesInstanceStream;
+use crate::graphql::subscription::EntityPropertyInstanceStream;
+use crate::model::PropertyInstanceSetter;
+
+fn config(backpressure: BackpressurePolicy, capacity: usize) -> GraphQLSubscriptionConfig {
+    GraphQLSubscriptionConfig { backpressure, capacity }
+}
+
+#[test]
+fn test_entity_properties_stream() {
+    let entity_instance = ReactiveEntityInstanceBuilder::new("test")
+        .property("x", json!(0))
+        .property("y", json!(0))
+        .property("z", json!(0))
+        .get();
+    let mut stream = EntityPropertiesInstanceStream::new(
+        entity_instance.clone(),
+        Some(vec![String::from("x"), String::from("y")]),
+        &GraphQLSubscriptionConfig::default(),
+    );
+    entity_instance.set("x", json!(1));
+    entity_instance.set("z", json!(2));
+    entity_instance.set("y", json!(3));
+    assert_eq!(Some((String::from("x"), json!(1))), block_on(stream.next()));
+    assert_eq!(Some((String::from("y"), json!(3))), block_on(stream.next()));
+    // The stream doesn't end but waits for the next value
+    assert!(stream.next().now_or_never().is_none());
+
+    let mut stream = EntityPropertiesInstanceStream::new(entity_instance.clone(), None, &GraphQLSubscriptionConfig::default());
+    entity_instance.set("z", json!(4));
+    assert_eq!(Some((String::from("z"), json!(4))), block_on(stream.next()));
+}
+
+#[test]
+fn test_backpressure() {
+    let entity_instance = ReactiveEntityInstanceBuilder::new("test").property("x", json!(0)).property("y", json!(0)).get();
+
+    let mut stream = EntityPropertyInstanceStream::new(entity_instance.clone(), String::from("x"), &config(BackpressurePolicy::Buffer, 2));
+    (1..=4).for_each(|value| entity_instance.set("x", json!(value)));
+    assert_eq!(Some(json!(1)), block_on(stream.next()));
+    assert_eq!(Some(json!(2)), block_on(stream.next()));
+    assert!(stream.next().now_or_never().is_none());
+    drop(stream);
+
+    let mut stream = EntityPropertyInstanceStream::new(entity_instance.clone(), String::from("x"), &config(BackpressurePolicy::DropOldest, 2));
+    (1..=4).for_each(|value| entity_instance.set("x", json!(value)));
+    assert_eq!(Some(json!(3)), block_on(stream.next()));
+    assert_eq!(Some(json!(4)), block_on(stream.next()));
+    assert!(stream.next().now_or_never().is_none());
+    drop(stream);
+
+    let mut stream = EntityPropertiesInstanceStream::new(entity_instance.clone(), None, &config(BackpressurePolicy::CoalesceLatest, 1));
+    (1..=4).for_each(|value| {
+        entity_instance.set("x", json!(value));
+        entity_instance.set("y", json!(value * 10));
+    });
+    assert_eq!(Some((String::from("x"), json!(4))), block_on(stream.next()));
+    assert_eq!(Some((String::from("y"), json!(40))), block_on(stream.next()));
+    assert!(stream.next().now_or_never().is_none());
+}
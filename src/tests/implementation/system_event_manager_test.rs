@@ -0,0 +1,105 @@
+use futures::executor::block_on;
+use futures_util::StreamExt;
+use indradb::{EdgeKey, Identifier};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::api::with_event_source;
+use crate::api::Lifecycle;
+use crate::api::SystemEvent;
+use crate::api::SystemEventSource;
+use crate::api::SystemEventTypes;
+use crate::api::SYSTEM_EVENT_HISTORY_CAPACITY;
+use crate::config::GraphQLSubscriptionConfig;
+use crate::graphql::subscription::event_as_edge_key;
+use crate::graphql::subscription::event_as_uuid;
+use crate::graphql::subscription::matches_name;
+use crate::graphql::subscription::matches_relation_type_name;
+use crate::graphql::subscription::EntityPropertyInstanceStream;
+use crate::model::PropertyInstanceGetter;
+use crate::tests::utils::application::init_application;
+
+#[test]
+fn test_system_event_stream() {
+    let application = init_application();
+    let event_manager = application.get_event_manager();
+    event_manager.post_init();
+
+    let entity_instance = event_manager.get_system_event_instance(SystemEventTypes::ComponentCreated).unwrap();
+    let mut stream = EntityPropertyInstanceStream::new(entity_instance, String::from("event"), &GraphQLSubscriptionConfig::default());
+    event_manager.emit_event(SystemEvent::ComponentCreated(String::from("labeled")));
+    assert_eq!(Some(json!("labeled")), block_on(stream.next()));
+}
+
+#[test]
+fn test_system_event_instances() {
+    let application = init_application();
+    let event_manager = application.get_event_manager();
+    event_manager.post_init();
+
+    let entity_instance = event_manager.get_system_event_instance(SystemEventTypes::EntityComponentAdded).unwrap();
+    assert_eq!(Some(json!("/org/inexor/event/instance/entity/component/added")), entity_instance.get("label"));
+    assert!(event_manager.get_system_event_instance(SystemEventTypes::ShutdownRequested).is_some());
+
+    let id = Uuid::new_v4();
+    event_manager.emit_event(SystemEvent::EntityComponentAdded(id, String::from("labeled")));
+    assert_eq!(Some(json!({ "id": id, "component": "labeled" })), entity_instance.get("event"));
+}
+
+#[test]
+fn test_system_event_history() {
+    let application = init_application();
+    let event_manager = application.get_event_manager();
+
+    let component = json!({ "name": "history" });
+    with_event_source(SystemEventSource::Plugin, || {
+        event_manager.emit_event_with_data(SystemEvent::ComponentCreated(String::from("history")), None, Some(component.clone()))
+    });
+    event_manager.emit_event(SystemEvent::ComponentDeleted(String::from("history")));
+
+    let events = event_manager.get_system_events(None, &[SystemEventTypes::ComponentCreated, SystemEventTypes::ComponentDeleted]);
+    let created = events
+        .iter()
+        .find(|record| record.event_type == SystemEventTypes::ComponentCreated && record.subject == json!("history"))
+        .unwrap();
+    assert_eq!(SystemEventSource::Plugin, created.source);
+    assert_eq!(Some(component), created.after);
+    assert_eq!(None, created.before);
+    let deleted = events
+        .iter()
+        .find(|record| record.event_type == SystemEventTypes::ComponentDeleted && record.subject == json!("history"))
+        .unwrap();
+    assert_eq!(SystemEventSource::Core, deleted.source);
+    assert!(deleted.sequence > created.sequence);
+    assert!(deleted.timestamp >= created.timestamp);
+
+    assert!(event_manager
+        .get_system_events(None, &[SystemEventTypes::FlowDeleted])
+        .iter()
+        .all(|record| record.event_type == SystemEventTypes::FlowDeleted));
+    assert!(event_manager.get_system_events(Some(deleted.timestamp + 60000), &[]).is_empty());
+
+    // The history is a ring buffer
+    for _ in 0..SYSTEM_EVENT_HISTORY_CAPACITY {
+        event_manager.emit_event(SystemEvent::FlowDeleted(Uuid::new_v4()));
+    }
+    let events = event_manager.get_system_events(None, &[]);
+    assert_eq!(SYSTEM_EVENT_HISTORY_CAPACITY, events.len());
+    assert!(events.iter().all(|record| record.event_type == SystemEventTypes::FlowDeleted));
+}
+
+#[test]
+fn test_system_event_values() {
+    let id = Uuid::new_v4();
+    assert_eq!(Some(id), event_as_uuid(&json!(id)));
+    assert_eq!(None, event_as_uuid(&json!(false)));
+
+    let edge_key = EdgeKey::new(id, Identifier::new("default_connector--value--lhs").unwrap(), id);
+    assert_eq!(Some(edge_key.clone()), event_as_edge_key(&json!(edge_key)));
+
+    assert!(matches_name(&None, "value"));
+    assert!(matches_name(&Some(String::from("value")), "value"));
+    assert!(!matches_name(&Some(String::from("value")), "value2"));
+    assert!(matches_relation_type_name(&Some(String::from("default_connector")), "default_connector--value--lhs"));
+    assert!(!matches_relation_type_name(&Some(String::from("connector")), "default_connector--value--lhs"));
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use indradb::{EdgeKey, Identifier};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::api::TransactionError;
+use crate::api::TransactionOperation;
+use crate::builder::{EntityTypeBuilder, RelationTypeBuilder};
+use crate::model::PropertyInstanceGetter;
+use crate::tests::utils::application::init_application;
+use crate::tests::utils::{r_json_string, r_string};
+
+#[test]
+fn test_transaction_manager() {
+    let application = init_application();
+    let entity_type_manager = application.get_entity_type_manager();
+    let relation_type_manager = application.get_relation_type_manager();
+    let reactive_entity_instance_manager = application.get_reactive_entity_instance_manager();
+    let reactive_relation_instance_manager = application.get_reactive_relation_instance_manager();
+    let transaction_manager = application.get_transaction_manager();
+
+    let entity_type_name = r_string();
+    let relation_type_name = r_string();
+    let property_name = r_string();
+
+    let entity_type = EntityTypeBuilder::new(entity_type_name.clone()).string_property(property_name.clone()).build();
+    entity_type_manager.register(entity_type);
+    let relation_type = RelationTypeBuilder::new(entity_type_name.clone(), relation_type_name.clone(), entity_type_name.clone())
+        .string_property(property_name.clone())
+        .build();
+    relation_type_manager.register(relation_type);
+
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let edge_key = EdgeKey::new(outbound_id, Identifier::new(relation_type_name.clone()).unwrap(), inbound_id);
+    let property_value = r_json_string();
+
+    // Create two entity instances and a relation instance between them
+    let result = transaction_manager.execute(vec![
+        TransactionOperation::CreateEntityInstance {
+            type_name: entity_type_name.clone(),
+            id: Some(outbound_id),
+            components: Vec::new(),
+            properties: HashMap::from([(property_name.clone(), property_value.clone())]),
+        },
+        TransactionOperation::CreateEntityInstance {
+            type_name: entity_type_name.clone(),
+            id: Some(inbound_id),
+            components: Vec::new(),
+            properties: HashMap::new(),
+        },
+        TransactionOperation::CreateRelationInstance {
+            edge_key: edge_key.clone(),
+            components: Vec::new(),
+            properties: HashMap::new(),
+        },
+    ]);
+    assert!(result.is_ok());
+    let result = result.unwrap();
+    assert_eq!(2, result.entity_instances.len());
+    assert_eq!(1, result.relation_instances.len());
+    assert!(reactive_relation_instance_manager.has(edge_key.clone()));
+    // Missing properties are initialized with the default value
+    assert_eq!(Some(json!("")), reactive_entity_instance_manager.get(inbound_id).unwrap().get(property_name.clone()));
+
+    // The update is not applied because the second operation is invalid
+    let result = transaction_manager.execute(vec![
+        TransactionOperation::UpdateEntityInstance {
+            id: outbound_id,
+            add_components: Vec::new(),
+            remove_components: Vec::new(),
+            properties: HashMap::from([(property_name.clone(), r_json_string())]),
+        },
+        TransactionOperation::CreateRelationInstance {
+            edge_key: EdgeKey::new(outbound_id, Identifier::new(relation_type_name.clone()).unwrap(), Uuid::new_v4()),
+            components: Vec::new(),
+            properties: HashMap::new(),
+        },
+    ]);
+    assert!(matches!(result, Err(TransactionError::Invalid { index: 1, .. })));
+    assert_eq!(Some(property_value.clone()), reactive_entity_instance_manager.get(outbound_id).unwrap().get(property_name.clone()));
+
+    // Unknown properties are rejected instead of being skipped
+    let result = transaction_manager.execute(vec![
+        TransactionOperation::UpdateEntityInstance {
+            id: outbound_id,
+            add_components: Vec::new(),
+            remove_components: Vec::new(),
+            properties: HashMap::from([(property_name.clone(), r_json_string())]),
+        },
+        TransactionOperation::UpdateRelationInstance {
+            edge_key: edge_key.clone(),
+            add_components: Vec::new(),
+            remove_components: Vec::new(),
+            properties: HashMap::from([(r_string(), r_json_string())]),
+        },
+    ]);
+    assert!(matches!(result, Err(TransactionError::Invalid { index: 1, .. })));
+    let result = transaction_manager.execute(vec![TransactionOperation::UpdateEntityInstance {
+        id: outbound_id,
+        add_components: Vec::new(),
+        remove_components: Vec::new(),
+        properties: HashMap::from([(r_string(), r_json_string())]),
+    }]);
+    assert!(matches!(result, Err(TransactionError::Invalid { index: 0, .. })));
+    assert_eq!(Some(property_value), reactive_entity_instance_manager.get(outbound_id).unwrap().get(property_name.clone()));
+
+    // An entity instance can't be deleted as long as it is connected by a relation instance
+    let result = transaction_manager.execute(vec![TransactionOperation::DeleteEntityInstance { id: inbound_id }]);
+    assert!(matches!(result, Err(TransactionError::Invalid { index: 0, .. })));
+    assert!(reactive_entity_instance_manager.has(inbound_id));
+
+    // Delete the relation instance before the entity instances
+    let result = transaction_manager.execute(vec![
+        TransactionOperation::DeleteRelationInstance { edge_key: edge_key.clone() },
+        TransactionOperation::DeleteEntityInstance { id: outbound_id },
+        TransactionOperation::DeleteEntityInstance { id: inbound_id },
+    ]);
+    assert!(result.is_ok());
+    assert!(!reactive_relation_instance_manager.has(edge_key));
+    assert!(!reactive_entity_instance_manager.has(outbound_id));
+    assert!(!reactive_entity_instance_manager.has(inbound_id));
+}